@@ -0,0 +1,62 @@
+import {
+  AccountMeta,
+  Address,
+  Instruction,
+  InstructionWithAccounts,
+  InstructionWithData,
+  ReadonlyUint8Array,
+} from '@solana/kit';
+import {
+  BATCH_DISCRIMINATOR,
+  BatchInstruction,
+  getBatchInstruction,
+  TOKEN_PROGRAM_ADDRESS,
+} from './generated';
+
+type BatchableInstruction = Instruction &
+  InstructionWithAccounts<readonly AccountMeta[]> &
+  InstructionWithData<ReadonlyUint8Array>;
+
+type BatchInstructionConfig = {
+  programAddress?: Address;
+};
+
+/**
+ * Combines token instructions into a single `Batch` instruction.
+ *
+ * The accounts of each instruction are appended in order, so signers of the
+ * original instructions remain signers of the batch.
+ * Note: The target token program must support the `Batch` instruction.
+ */
+export function getBatchInstructionFromInstructions(
+  instructions: Array<BatchableInstruction>,
+  config?: BatchInstructionConfig
+): BatchInstruction<Address, AccountMeta[]> {
+  const programAddress = config?.programAddress ?? TOKEN_PROGRAM_ADDRESS;
+
+  instructions.forEach((instruction) => {
+    if (instruction.programAddress !== programAddress) {
+      throw new Error(
+        `Cannot batch an instruction of program ${instruction.programAddress}.`
+      );
+    }
+    if (instruction.data[0] === BATCH_DISCRIMINATOR) {
+      throw new Error('Cannot batch a batch instruction.');
+    }
+  });
+
+  const batch = getBatchInstruction(
+    {
+      instructions: instructions.map((instruction) => ({
+        numAccounts: instruction.accounts.length,
+        data: instruction.data,
+      })),
+    },
+    { programAddress }
+  );
+
+  return Object.freeze({
+    ...batch,
+    accounts: instructions.flatMap((instruction) => instruction.accounts),
+  });
+}
@@ -6,6 +6,9 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './delegation';
 export * from './mint';
+export * from './mintPause';
 export * from './multisig';
+export * from './spendingLimit';
 export * from './token';
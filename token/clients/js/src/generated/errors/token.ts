@@ -54,11 +54,18 @@ export const TOKEN_ERROR__ACCOUNT_FROZEN = 0x11; // 17
 export const TOKEN_ERROR__MINT_DECIMALS_MISMATCH = 0x12; // 18
 /** NonNativeNotSupported: Instruction does not support non-native tokens */
 export const TOKEN_ERROR__NON_NATIVE_NOT_SUPPORTED = 0x13; // 19
+/** DelegationExpired: Delegation has expired */
+export const TOKEN_ERROR__DELEGATION_EXPIRED = 0x14; // 20
+/** NoMemo: Transfer into the account requires a memo in the previous instruction */
+export const TOKEN_ERROR__NO_MEMO = 0x15; // 21
+/** MintPaused: Mint is paused */
+export const TOKEN_ERROR__MINT_PAUSED = 0x16; // 22
 
 export type TokenError =
   | typeof TOKEN_ERROR__ACCOUNT_FROZEN
   | typeof TOKEN_ERROR__ALREADY_IN_USE
   | typeof TOKEN_ERROR__AUTHORITY_TYPE_NOT_SUPPORTED
+  | typeof TOKEN_ERROR__DELEGATION_EXPIRED
   | typeof TOKEN_ERROR__FIXED_SUPPLY
   | typeof TOKEN_ERROR__INSUFFICIENT_FUNDS
   | typeof TOKEN_ERROR__INVALID_INSTRUCTION
@@ -69,7 +76,9 @@ export type TokenError =
   | typeof TOKEN_ERROR__MINT_CANNOT_FREEZE
   | typeof TOKEN_ERROR__MINT_DECIMALS_MISMATCH
   | typeof TOKEN_ERROR__MINT_MISMATCH
+  | typeof TOKEN_ERROR__MINT_PAUSED
   | typeof TOKEN_ERROR__NATIVE_NOT_SUPPORTED
+  | typeof TOKEN_ERROR__NO_MEMO
   | typeof TOKEN_ERROR__NON_NATIVE_HAS_BALANCE
   | typeof TOKEN_ERROR__NON_NATIVE_NOT_SUPPORTED
   | typeof TOKEN_ERROR__NOT_RENT_EXEMPT
@@ -83,6 +92,7 @@ if (process.env.NODE_ENV !== 'production') {
     [TOKEN_ERROR__ACCOUNT_FROZEN]: `Account is frozen`,
     [TOKEN_ERROR__ALREADY_IN_USE]: `Already in use`,
     [TOKEN_ERROR__AUTHORITY_TYPE_NOT_SUPPORTED]: `Account does not support specified authority type`,
+    [TOKEN_ERROR__DELEGATION_EXPIRED]: `Delegation has expired`,
     [TOKEN_ERROR__FIXED_SUPPLY]: `Fixed supply`,
     [TOKEN_ERROR__INSUFFICIENT_FUNDS]: `Insufficient funds`,
     [TOKEN_ERROR__INVALID_INSTRUCTION]: `Invalid instruction`,
@@ -93,7 +103,9 @@ if (process.env.NODE_ENV !== 'production') {
     [TOKEN_ERROR__MINT_CANNOT_FREEZE]: `This token mint cannot freeze accounts`,
     [TOKEN_ERROR__MINT_DECIMALS_MISMATCH]: `The provided decimals value different from the Mint decimals`,
     [TOKEN_ERROR__MINT_MISMATCH]: `Account not associated with this Mint`,
+    [TOKEN_ERROR__MINT_PAUSED]: `Mint is paused`,
     [TOKEN_ERROR__NATIVE_NOT_SUPPORTED]: `Instruction does not support native tokens`,
+    [TOKEN_ERROR__NO_MEMO]: `Transfer into the account requires a memo in the previous instruction`,
     [TOKEN_ERROR__NON_NATIVE_HAS_BALANCE]: `Non-native account can only be closed if its balance is zero`,
     [TOKEN_ERROR__NON_NATIVE_NOT_SUPPORTED]: `Instruction does not support non-native tokens`,
     [TOKEN_ERROR__NOT_RENT_EXEMPT]: `Lamport balance below rent-exempt threshold`,
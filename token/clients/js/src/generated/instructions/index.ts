@@ -9,9 +9,14 @@
 export * from './amountToUiAmount';
 export * from './approve';
 export * from './approveChecked';
+export * from './approveWithExpiry';
+export * from './approveWithSpendingLimit';
+export * from './batch';
 export * from './burn';
+export * from './burnAndClose';
 export * from './burnChecked';
 export * from './closeAccount';
+export * from './closeDelegation';
 export * from './createAssociatedToken';
 export * from './createAssociatedTokenIdempotent';
 export * from './freezeAccount';
@@ -26,11 +31,18 @@ export * from './initializeMultisig';
 export * from './initializeMultisig2';
 export * from './mintTo';
 export * from './mintToChecked';
+export * from './pauseMint';
 export * from './recoverNestedAssociatedToken';
+export * from './resumeMint';
 export * from './revoke';
 export * from './setAuthority';
+export * from './setMemoRequired';
+export * from './sweepAndClose';
 export * from './syncNative';
 export * from './thawAccount';
 export * from './transfer';
 export * from './transferChecked';
+export * from './transferMany';
 export * from './uiAmountToAmount';
+export * from './unwrapLamports';
+export * from './withdrawExcessLamports';
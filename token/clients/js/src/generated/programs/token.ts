@@ -16,9 +16,14 @@ import {
   type ParsedAmountToUiAmountInstruction,
   type ParsedApproveCheckedInstruction,
   type ParsedApproveInstruction,
+  type ParsedApproveWithExpiryInstruction,
+  type ParsedApproveWithSpendingLimitInstruction,
+  type ParsedBatchInstruction,
+  type ParsedBurnAndCloseInstruction,
   type ParsedBurnCheckedInstruction,
   type ParsedBurnInstruction,
   type ParsedCloseAccountInstruction,
+  type ParsedCloseDelegationInstruction,
   type ParsedFreezeAccountInstruction,
   type ParsedGetAccountDataSizeInstruction,
   type ParsedInitializeAccount2Instruction,
@@ -31,13 +36,20 @@ import {
   type ParsedInitializeMultisigInstruction,
   type ParsedMintToCheckedInstruction,
   type ParsedMintToInstruction,
+  type ParsedPauseMintInstruction,
+  type ParsedResumeMintInstruction,
   type ParsedRevokeInstruction,
   type ParsedSetAuthorityInstruction,
+  type ParsedSetMemoRequiredInstruction,
+  type ParsedSweepAndCloseInstruction,
   type ParsedSyncNativeInstruction,
   type ParsedThawAccountInstruction,
   type ParsedTransferCheckedInstruction,
   type ParsedTransferInstruction,
+  type ParsedTransferManyInstruction,
   type ParsedUiAmountToAmountInstruction,
+  type ParsedUnwrapLamportsInstruction,
+  type ParsedWithdrawExcessLamportsInstruction,
 } from '../instructions';
 
 export const TOKEN_PROGRAM_ADDRESS =
@@ -47,6 +59,8 @@ export enum TokenAccount {
   Mint,
   Token,
   Multisig,
+  Delegation,
+  SpendingLimit,
 }
 
 export function identifyTokenAccount(
@@ -62,6 +76,12 @@ export function identifyTokenAccount(
   if (data.length === 355) {
     return TokenAccount.Multisig;
   }
+  if (data.length === 74) {
+    return TokenAccount.Delegation;
+  }
+  if (data.length === 89) {
+    return TokenAccount.SpendingLimit;
+  }
   throw new Error(
     'The provided account could not be identified as a token account.'
   );
@@ -93,6 +113,18 @@ export enum TokenInstruction {
   InitializeImmutableOwner,
   AmountToUiAmount,
   UiAmountToAmount,
+  WithdrawExcessLamports,
+  UnwrapLamports,
+  TransferMany,
+  BurnAndClose,
+  SweepAndClose,
+  ApproveWithExpiry,
+  ApproveWithSpendingLimit,
+  SetMemoRequired,
+  PauseMint,
+  ResumeMint,
+  CloseDelegation,
+  Batch,
 }
 
 export function identifyTokenInstruction(
@@ -174,6 +206,42 @@ export function identifyTokenInstruction(
   if (containsBytes(data, getU8Encoder().encode(24), 0)) {
     return TokenInstruction.UiAmountToAmount;
   }
+  if (containsBytes(data, getU8Encoder().encode(38), 0)) {
+    return TokenInstruction.WithdrawExcessLamports;
+  }
+  if (containsBytes(data, getU8Encoder().encode(45), 0)) {
+    return TokenInstruction.UnwrapLamports;
+  }
+  if (containsBytes(data, getU8Encoder().encode(46), 0)) {
+    return TokenInstruction.TransferMany;
+  }
+  if (containsBytes(data, getU8Encoder().encode(47), 0)) {
+    return TokenInstruction.BurnAndClose;
+  }
+  if (containsBytes(data, getU8Encoder().encode(48), 0)) {
+    return TokenInstruction.SweepAndClose;
+  }
+  if (containsBytes(data, getU8Encoder().encode(49), 0)) {
+    return TokenInstruction.ApproveWithExpiry;
+  }
+  if (containsBytes(data, getU8Encoder().encode(50), 0)) {
+    return TokenInstruction.ApproveWithSpendingLimit;
+  }
+  if (containsBytes(data, getU8Encoder().encode(51), 0)) {
+    return TokenInstruction.SetMemoRequired;
+  }
+  if (containsBytes(data, getU8Encoder().encode(52), 0)) {
+    return TokenInstruction.PauseMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(53), 0)) {
+    return TokenInstruction.ResumeMint;
+  }
+  if (containsBytes(data, getU8Encoder().encode(54), 0)) {
+    return TokenInstruction.CloseDelegation;
+  }
+  if (containsBytes(data, getU8Encoder().encode(255), 0)) {
+    return TokenInstruction.Batch;
+  }
   throw new Error(
     'The provided instruction could not be identified as a token instruction.'
   );
@@ -256,4 +324,40 @@ export type ParsedTokenInstruction<
     } & ParsedAmountToUiAmountInstruction<TProgram>)
   | ({
       instructionType: TokenInstruction.UiAmountToAmount;
-    } & ParsedUiAmountToAmountInstruction<TProgram>);
+    } & ParsedUiAmountToAmountInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.WithdrawExcessLamports;
+    } & ParsedWithdrawExcessLamportsInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.UnwrapLamports;
+    } & ParsedUnwrapLamportsInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.TransferMany;
+    } & ParsedTransferManyInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.BurnAndClose;
+    } & ParsedBurnAndCloseInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.SweepAndClose;
+    } & ParsedSweepAndCloseInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.ApproveWithExpiry;
+    } & ParsedApproveWithExpiryInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.ApproveWithSpendingLimit;
+    } & ParsedApproveWithSpendingLimitInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.SetMemoRequired;
+    } & ParsedSetMemoRequiredInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.PauseMint;
+    } & ParsedPauseMintInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.ResumeMint;
+    } & ParsedResumeMintInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.CloseDelegation;
+    } & ParsedCloseDelegationInstruction<TProgram>)
+  | ({
+      instructionType: TokenInstruction.Batch;
+    } & ParsedBatchInstruction<TProgram>);
@@ -8,3 +8,5 @@
 
 export * from './accountState';
 export * from './authorityType';
+export * from './batchedInstruction';
+export * from './expiry';
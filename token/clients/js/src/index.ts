@@ -1,4 +1,5 @@
 export * from './generated';
+export * from './batch';
 export * from './createMint';
 export * from './mintToATA';
 export * from './transferToATA';
@@ -0,0 +1,200 @@
+import { getCreateAccountInstruction } from '@solana-program/system';
+import {
+  appendTransactionMessageInstructions,
+  fetchEncodedAccount,
+  generateKeyPairSigner,
+  none,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import {
+  Delegation,
+  TOKEN_PROGRAM_ADDRESS,
+  Token,
+  expiry,
+  fetchDelegation,
+  fetchToken,
+  getApproveWithExpiryInstruction,
+  getCloseDelegationInstruction,
+  getDelegationSize,
+  getTransferInstruction,
+} from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  createToken,
+  createTokenWithAmount,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it approves a delegate until a given slot', async (t) => {
+  // Given a mint account and two token accounts.
+  // One with 100 tokens and the other with 0 tokens.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, ownerA, ownerB, delegate, delegation] =
+    await Promise.all([
+      generateKeyPairSignerWithSol(client),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+    ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const [tokenA, tokenB] = await Promise.all([
+    createTokenWithAmount(
+      client,
+      payer,
+      mintAuthority,
+      mint,
+      ownerA.address,
+      100n
+    ),
+    createToken(client, payer, mint, ownerB.address),
+  ]);
+  const space = BigInt(getDelegationSize());
+  const [rent, slot] = await Promise.all([
+    client.rpc.getMinimumBalanceForRentExemption(space).send(),
+    client.rpc.getSlot().send(),
+  ]);
+
+  // When owner A approves the delegate for 50 tokens until a future slot
+  // and the delegate transfers 30 tokens to token account B.
+  const instructions = [
+    getCreateAccountInstruction({
+      payer,
+      newAccount: delegation,
+      lamports: rent,
+      space,
+      programAddress: TOKEN_PROGRAM_ADDRESS,
+    }),
+    getApproveWithExpiryInstruction({
+      source: tokenA,
+      delegation: delegation.address,
+      delegate: delegate.address,
+      owner: ownerA,
+      amount: 50n,
+      expiry: expiry('Slot', [slot + 1_000n]),
+    }),
+    getTransferInstruction({
+      source: tokenA,
+      destination: tokenB,
+      authority: delegation.address,
+      amount: 30n,
+      multiSigners: [delegate],
+    }),
+  ];
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstructions(instructions, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the delegation account to record the delegate and the
+  // transfer to have used the delegated amount.
+  const [{ data: delegationData }, { data: tokenDataA }, { data: tokenDataB }] =
+    await Promise.all([
+      fetchDelegation(client.rpc, delegation.address),
+      fetchToken(client.rpc, tokenA),
+      fetchToken(client.rpc, tokenB),
+    ]);
+  t.like(delegationData, <Delegation>{
+    isInitialized: true,
+    account: tokenA,
+    delegate: delegate.address,
+  });
+  t.like(tokenDataA, <Token>{
+    amount: 70n,
+    delegatedAmount: 20n,
+  });
+  t.like(tokenDataB, <Token>{ amount: 30n });
+});
+
+test('it closes a delegation account and revokes it', async (t) => {
+  // Given a token account with 100 tokens approved through a delegation
+  // account.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, owner, delegate, delegation, recipient] =
+    await Promise.all([
+      generateKeyPairSignerWithSol(client),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+    ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const token = await createTokenWithAmount(
+    client,
+    payer,
+    mintAuthority,
+    mint,
+    owner.address,
+    100n
+  );
+  const space = BigInt(getDelegationSize());
+  const [rent, slot] = await Promise.all([
+    client.rpc.getMinimumBalanceForRentExemption(space).send(),
+    client.rpc.getSlot().send(),
+  ]);
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getCreateAccountInstruction({
+            payer,
+            newAccount: delegation,
+            lamports: rent,
+            space,
+            programAddress: TOKEN_PROGRAM_ADDRESS,
+          }),
+          getApproveWithExpiryInstruction({
+            source: token,
+            delegation: delegation.address,
+            delegate: delegate.address,
+            owner,
+            amount: 50n,
+            expiry: expiry('Slot', [slot + 1_000n]),
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // When the owner closes the delegation account.
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getCloseDelegationInstruction({
+            source: token,
+            delegation: delegation.address,
+            destination: recipient.address,
+            owner,
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the delegation account to be closed, its lamports to be
+  // reclaimed and the delegation to be revoked.
+  const [delegationAccount, { data: tokenData }] = await Promise.all([
+    fetchEncodedAccount(client.rpc, delegation.address),
+    fetchToken(client.rpc, token),
+  ]);
+  t.false(delegationAccount.exists);
+  t.is(await getBalance(client, recipient.address), rent);
+  t.like(tokenData, <Token>{
+    amount: 100n,
+    delegate: none(),
+    delegatedAmount: 0n,
+  });
+});
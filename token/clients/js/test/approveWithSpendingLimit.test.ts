@@ -0,0 +1,205 @@
+import { getCreateAccountInstruction } from '@solana-program/system';
+import {
+  AccountRole,
+  appendTransactionMessageInstructions,
+  fetchEncodedAccount,
+  generateKeyPairSigner,
+  none,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import {
+  SpendingLimit,
+  TOKEN_PROGRAM_ADDRESS,
+  Token,
+  fetchSpendingLimit,
+  fetchToken,
+  getApproveWithSpendingLimitInstruction,
+  getCloseDelegationInstruction,
+  getSpendingLimitSize,
+  getTransferInstruction,
+} from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  createToken,
+  createTokenWithAmount,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it approves a delegate with a spending limit per epoch', async (t) => {
+  // Given a mint account and two token accounts.
+  // One with 100 tokens and the other with 0 tokens.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, ownerA, ownerB, delegate, spendingLimit] =
+    await Promise.all([
+      generateKeyPairSignerWithSol(client),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+    ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const [tokenA, tokenB] = await Promise.all([
+    createTokenWithAmount(
+      client,
+      payer,
+      mintAuthority,
+      mint,
+      ownerA.address,
+      100n
+    ),
+    createToken(client, payer, mint, ownerB.address),
+  ]);
+  const space = BigInt(getSpendingLimitSize());
+  const rent = await client.rpc.getMinimumBalanceForRentExemption(space).send();
+
+  const transfer = getTransferInstruction({
+    source: tokenA,
+    destination: tokenB,
+    authority: spendingLimit.address,
+    amount: 30n,
+    multiSigners: [delegate],
+  });
+
+  // When owner A approves the delegate to spend 50 tokens per epoch
+  // and the delegate transfers 30 tokens to token account B.
+  const instructions = [
+    getCreateAccountInstruction({
+      payer,
+      newAccount: spendingLimit,
+      lamports: rent,
+      space,
+      programAddress: TOKEN_PROGRAM_ADDRESS,
+    }),
+    getApproveWithSpendingLimitInstruction({
+      source: tokenA,
+      spendingLimit: spendingLimit.address,
+      delegate: delegate.address,
+      owner: ownerA,
+      limit: 50n,
+    }),
+    {
+      ...transfer,
+      // The spending limit account is updated by the transfer.
+      accounts: transfer.accounts.map((account, index) =>
+        index === 2 ? { ...account, role: AccountRole.WRITABLE } : account
+      ),
+    },
+  ];
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstructions(instructions, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the spending limit account to record the amount spent
+  // and the delegated amount to be left untouched.
+  const [
+    { data: spendingLimitData },
+    { data: tokenDataA },
+    { data: tokenDataB },
+  ] = await Promise.all([
+    fetchSpendingLimit(client.rpc, spendingLimit.address),
+    fetchToken(client.rpc, tokenA),
+    fetchToken(client.rpc, tokenB),
+  ]);
+  t.like(spendingLimitData, <SpendingLimit>{
+    isInitialized: true,
+    account: tokenA,
+    delegate: delegate.address,
+    limit: 50n,
+    spent: 30n,
+  });
+  t.like(tokenDataA, <Token>{
+    amount: 70n,
+    delegatedAmount: 50n,
+  });
+  t.like(tokenDataB, <Token>{ amount: 30n });
+});
+
+test('it closes a spending limit account and revokes it', async (t) => {
+  // Given a token account with 100 tokens approved through a spending limit
+  // account.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, owner, delegate, spendingLimit, recipient] =
+    await Promise.all([
+      generateKeyPairSignerWithSol(client),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+    ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const token = await createTokenWithAmount(
+    client,
+    payer,
+    mintAuthority,
+    mint,
+    owner.address,
+    100n
+  );
+  const space = BigInt(getSpendingLimitSize());
+  const rent = await client.rpc.getMinimumBalanceForRentExemption(space).send();
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getCreateAccountInstruction({
+            payer,
+            newAccount: spendingLimit,
+            lamports: rent,
+            space,
+            programAddress: TOKEN_PROGRAM_ADDRESS,
+          }),
+          getApproveWithSpendingLimitInstruction({
+            source: token,
+            spendingLimit: spendingLimit.address,
+            delegate: delegate.address,
+            owner,
+            limit: 50n,
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // When the owner closes the spending limit account.
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getCloseDelegationInstruction({
+            source: token,
+            delegation: spendingLimit.address,
+            destination: recipient.address,
+            owner,
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the spending limit account to be closed, its lamports to
+  // be reclaimed and the delegation to be revoked.
+  const [spendingLimitAccount, { data: tokenData }] = await Promise.all([
+    fetchEncodedAccount(client.rpc, spendingLimit.address),
+    fetchToken(client.rpc, token),
+  ]);
+  t.false(spendingLimitAccount.exists);
+  t.is(await getBalance(client, recipient.address), rent);
+  t.like(tokenData, <Token>{
+    amount: 100n,
+    delegate: none(),
+    delegatedAmount: 0n,
+  });
+});
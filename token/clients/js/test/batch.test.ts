@@ -0,0 +1,73 @@
+import {
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import {
+  Mint,
+  Token,
+  TokenInstruction,
+  fetchMint,
+  fetchToken,
+  getBatchInstructionFromInstructions,
+  getMintToInstruction,
+  getTransferInstruction,
+  identifyTokenInstruction,
+  parseBatchInstruction,
+} from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  createToken,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it executes a batch of token instructions', async (t) => {
+  // Given a mint account and two empty token accounts.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, ownerA, ownerB] = await Promise.all([
+    generateKeyPairSignerWithSol(client),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const [tokenA, tokenB] = await Promise.all([
+    createToken(client, payer, mint, ownerA.address),
+    createToken(client, payer, mint, ownerB.address),
+  ]);
+
+  // When we mint 100 tokens to owner A and transfer 40 of them to owner B
+  // in a single batch instruction.
+  const batch = getBatchInstructionFromInstructions([
+    getMintToInstruction({ mint, token: tokenA, mintAuthority, amount: 100n }),
+    getTransferInstruction({
+      source: tokenA,
+      destination: tokenB,
+      authority: ownerA,
+      amount: 40n,
+    }),
+  ]);
+  t.is(identifyTokenInstruction(batch), TokenInstruction.Batch);
+  t.is(parseBatchInstruction(batch).data.instructions.length, 2);
+
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(batch, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the mint and token accounts to have the following updated data.
+  const [{ data: mintData }, { data: tokenDataA }, { data: tokenDataB }] =
+    await Promise.all([
+      fetchMint(client.rpc, mint),
+      fetchToken(client.rpc, tokenA),
+      fetchToken(client.rpc, tokenB),
+    ]);
+  t.like(mintData, <Mint>{ supply: 100n });
+  t.like(tokenDataA, <Token>{ amount: 60n });
+  t.like(tokenDataB, <Token>{ amount: 40n });
+});
@@ -0,0 +1,60 @@
+import {
+  appendTransactionMessageInstruction,
+  fetchEncodedAccount,
+  generateKeyPairSigner,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import { Mint, fetchMint, getBurnAndCloseInstruction } from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  createTokenWithAmount,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it burns the remaining tokens and closes the account', async (t) => {
+  // Given a mint account and a token account with 100 tokens.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, owner, destination] = await Promise.all([
+    generateKeyPairSignerWithSol(client),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const token = await createTokenWithAmount(
+    client,
+    payer,
+    mintAuthority,
+    mint,
+    owner.address,
+    100n
+  );
+  const rent = await getBalance(client, token);
+
+  // When the owner burns the tokens and closes the account.
+  const burnAndClose = getBurnAndCloseInstruction({
+    account: token,
+    mint,
+    destination: destination.address,
+    authority: owner,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(burnAndClose, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the token account to be closed and the supply to be 0.
+  const [tokenAccount, mintAccount] = await Promise.all([
+    fetchEncodedAccount(client.rpc, token),
+    fetchMint(client.rpc, mint),
+  ]);
+  t.false(tokenAccount.exists);
+  t.like(mintAccount.data, <Mint>{ supply: 0n });
+  t.is(await getBalance(client, destination.address), rent);
+});
@@ -0,0 +1,156 @@
+import { getCreateAccountInstruction } from '@solana-program/system';
+import {
+  appendTransactionMessageInstructions,
+  fetchEncodedAccount,
+  generateKeyPairSigner,
+  pipe,
+  some,
+} from '@solana/kit';
+import test from 'ava';
+import {
+  Mint,
+  MintPause,
+  TOKEN_PROGRAM_ADDRESS,
+  Token,
+  fetchMint,
+  fetchMintPause,
+  fetchToken,
+  getInitializeMintInstruction,
+  getMintPauseSize,
+  getMintSize,
+  getMintToInstruction,
+  getPauseMintInstruction,
+  getResumeMintInstruction,
+} from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createToken,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it pauses and resumes a mint', async (t) => {
+  // Given a mint account with a freeze authority and a token account.
+  const client = createDefaultSolanaClient();
+  const [payer, mint, mintPause, mintAuthority, freezeAuthority, owner] =
+    await Promise.all([
+      generateKeyPairSignerWithSol(client),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+      generateKeyPairSigner(),
+    ]);
+  const space = BigInt(getMintSize());
+  const rent = await client.rpc.getMinimumBalanceForRentExemption(space).send();
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getCreateAccountInstruction({
+            payer,
+            newAccount: mint,
+            lamports: rent,
+            space,
+            programAddress: TOKEN_PROGRAM_ADDRESS,
+          }),
+          getInitializeMintInstruction({
+            mint: mint.address,
+            decimals: 0,
+            mintAuthority: mintAuthority.address,
+            freezeAuthority: freezeAuthority.address,
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+  const token = await createToken(client, payer, mint.address, owner.address);
+
+  // When the freeze authority pauses the mint.
+  const pauseSpace = BigInt(getMintPauseSize());
+  const pauseRent = await client.rpc
+    .getMinimumBalanceForRentExemption(pauseSpace)
+    .send();
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getCreateAccountInstruction({
+            payer,
+            newAccount: mintPause,
+            lamports: pauseRent,
+            space: pauseSpace,
+            programAddress: TOKEN_PROGRAM_ADDRESS,
+          }),
+          getPauseMintInstruction({
+            mint: mint.address,
+            mintPause: mintPause.address,
+            owner: freezeAuthority,
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the mint pause account to record the freeze authority.
+  const { data: mintPauseData } = await fetchMintPause(
+    client.rpc,
+    mintPause.address
+  );
+  t.like(mintPauseData, <MintPause>{
+    isInitialized: true,
+    mint: mint.address,
+    freezeAuthority: freezeAuthority.address,
+  });
+
+  // And minting tokens to fail.
+  const mintTo = getMintToInstruction({
+    mint: mint.address,
+    token,
+    mintAuthority,
+    amount: 100n,
+  });
+  await t.throwsAsync(
+    pipe(
+      await createDefaultTransaction(client, payer),
+      (tx) => appendTransactionMessageInstructions([mintTo], tx),
+      (tx) => signAndSendTransaction(client, tx)
+    )
+  );
+
+  // And minting tokens to succeed once the mint is resumed, which restores
+  // the freeze authority and closes the mint pause account.
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getResumeMintInstruction({
+            mint: mint.address,
+            mintPause: mintPause.address,
+            destination: payer.address,
+            owner: freezeAuthority,
+          }),
+          mintTo,
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+  const [{ data: mintData }, { data: tokenData }, mintPauseAccount] =
+    await Promise.all([
+      fetchMint(client.rpc, mint.address),
+      fetchToken(client.rpc, token),
+      fetchEncodedAccount(client.rpc, mintPause.address),
+    ]);
+  t.like(mintData, <Mint>{
+    freezeAuthority: some(freezeAuthority.address),
+  });
+  t.like(tokenData, <Token>{ amount: 100n });
+  t.false(mintPauseAccount.exists);
+});
@@ -0,0 +1,106 @@
+import {
+  AccountRole,
+  SYSVAR_INSTRUCTIONS_ADDRESS,
+  address,
+  appendTransactionMessageInstructions,
+  generateKeyPairSigner,
+  getUtf8Encoder,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import {
+  Token,
+  fetchToken,
+  getSetMemoRequiredInstruction,
+  getTransferInstruction,
+} from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  createToken,
+  createTokenWithAmount,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from './_setup';
+
+const MEMO_PROGRAM_ADDRESS = address(
+  'MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr'
+);
+
+test('it transfers into an account that requires memos', async (t) => {
+  // Given a mint account and two token accounts.
+  // One with 100 tokens and the other with 0 tokens.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, ownerA, ownerB] = await Promise.all([
+    generateKeyPairSignerWithSol(client),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const [tokenA, tokenB] = await Promise.all([
+    createTokenWithAmount(
+      client,
+      payer,
+      mintAuthority,
+      mint,
+      ownerA.address,
+      100n
+    ),
+    createToken(client, payer, mint, ownerB.address),
+  ]);
+
+  // And owner B requires memos on incoming transfers.
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) =>
+      appendTransactionMessageInstructions(
+        [
+          getSetMemoRequiredInstruction({
+            account: tokenB,
+            owner: ownerB,
+            required: true,
+          }),
+        ],
+        tx
+      ),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // When owner A transfers 50 tokens to token account B after a memo.
+  const transfer = getTransferInstruction({
+    source: tokenA,
+    destination: tokenB,
+    authority: ownerA,
+    amount: 50n,
+  });
+  const instructions = [
+    {
+      programAddress: MEMO_PROGRAM_ADDRESS,
+      accounts: [],
+      data: getUtf8Encoder().encode('deposit'),
+    },
+    {
+      ...transfer,
+      // The Instructions sysvar is required to find the memo.
+      accounts: [
+        ...transfer.accounts,
+        { address: SYSVAR_INSTRUCTIONS_ADDRESS, role: AccountRole.READONLY },
+      ],
+    },
+  ];
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstructions(instructions, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the tokens to be transferred.
+  const [{ data: tokenDataA }, { data: tokenDataB }] = await Promise.all([
+    fetchToken(client.rpc, tokenA),
+    fetchToken(client.rpc, tokenB),
+  ]);
+  t.like(tokenDataA, <Token>{ amount: 50n });
+  t.like(tokenDataB, <Token>{ amount: 50n });
+});
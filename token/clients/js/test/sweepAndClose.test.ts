@@ -0,0 +1,67 @@
+import {
+  appendTransactionMessageInstruction,
+  fetchEncodedAccount,
+  generateKeyPairSigner,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import { Token, fetchToken, getSweepAndCloseInstruction } from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  createToken,
+  createTokenWithAmount,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it moves the whole balance and closes the source account', async (t) => {
+  // Given a mint account and two token accounts.
+  // One with 100 tokens and the other with 0 tokens.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, ownerA, ownerB, recipient] = await Promise.all([
+    generateKeyPairSignerWithSol(client),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const [tokenA, tokenB] = await Promise.all([
+    createTokenWithAmount(
+      client,
+      payer,
+      mintAuthority,
+      mint,
+      ownerA.address,
+      100n
+    ),
+    createToken(client, payer, mint, ownerB.address),
+  ]);
+  const rent = await getBalance(client, tokenA);
+
+  // When owner A sweeps the balance to owner B and closes the account.
+  const sweepAndClose = getSweepAndCloseInstruction({
+    source: tokenA,
+    destination: tokenB,
+    recipient: recipient.address,
+    authority: ownerA,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(sweepAndClose, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect token account A to be closed and token account B to
+  // hold all the tokens.
+  const [tokenAccountA, { data: tokenDataB }] = await Promise.all([
+    fetchEncodedAccount(client.rpc, tokenA),
+    fetchToken(client.rpc, tokenB),
+  ]);
+  t.false(tokenAccountA.exists);
+  t.like(tokenDataB, <Token>{ amount: 100n });
+  t.is(await getBalance(client, recipient.address), rent);
+});
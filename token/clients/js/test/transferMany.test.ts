@@ -0,0 +1,66 @@
+import {
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import { Token, fetchToken, getTransferManyInstruction } from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  createToken,
+  createTokenWithAmount,
+  generateKeyPairSignerWithSol,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it transfers tokens from one account to many accounts', async (t) => {
+  // Given a mint account and three token accounts.
+  // One with 100 tokens and the others with 0 tokens.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, ownerA, ownerB, ownerC] = await Promise.all([
+    generateKeyPairSignerWithSol(client),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+    generateKeyPairSigner(),
+  ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const [tokenA, tokenB, tokenC] = await Promise.all([
+    createTokenWithAmount(
+      client,
+      payer,
+      mintAuthority,
+      mint,
+      ownerA.address,
+      100n
+    ),
+    createToken(client, payer, mint, ownerB.address),
+    createToken(client, payer, mint, ownerC.address),
+  ]);
+
+  // When owner A transfers 30 tokens to owner B and 20 tokens to owner C.
+  const transferMany = getTransferManyInstruction({
+    source: tokenA,
+    authority: ownerA,
+    amounts: [30n, 20n],
+    destinations: [tokenB, tokenC],
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(transferMany, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the token accounts to have the following updated data.
+  const [{ data: tokenDataA }, { data: tokenDataB }, { data: tokenDataC }] =
+    await Promise.all([
+      fetchToken(client.rpc, tokenA),
+      fetchToken(client.rpc, tokenB),
+      fetchToken(client.rpc, tokenC),
+    ]);
+  t.like(tokenDataA, <Token>{ amount: 50n });
+  t.like(tokenDataB, <Token>{ amount: 30n });
+  t.like(tokenDataC, <Token>{ amount: 20n });
+});
@@ -0,0 +1,139 @@
+import { getCreateAccountInstruction } from '@solana-program/system';
+import {
+  Address,
+  appendTransactionMessageInstruction,
+  appendTransactionMessageInstructions,
+  generateKeyPairSigner,
+  lamports,
+  pipe,
+  TransactionSigner,
+} from '@solana/kit';
+import test from 'ava';
+import {
+  TOKEN_PROGRAM_ADDRESS,
+  Token,
+  fetchToken,
+  getInitializeAccount3Instruction,
+  getTokenSize,
+  getUnwrapLamportsInstruction,
+} from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  signAndSendTransaction,
+} from './_setup';
+
+const NATIVE_MINT =
+  'So11111111111111111111111111111111111111112' as Address<'So11111111111111111111111111111111111111112'>;
+
+const createNativeToken = async (
+  client: ReturnType<typeof createDefaultSolanaClient>,
+  payer: TransactionSigner,
+  owner: Address,
+  amount: bigint
+): Promise<Address> => {
+  const space = BigInt(getTokenSize());
+  const [transactionMessage, rent, token] = await Promise.all([
+    createDefaultTransaction(client, payer),
+    client.rpc.getMinimumBalanceForRentExemption(space).send(),
+    generateKeyPairSigner(),
+  ]);
+  const instructions = [
+    getCreateAccountInstruction({
+      payer,
+      newAccount: token,
+      lamports: lamports(rent + amount),
+      space,
+      programAddress: TOKEN_PROGRAM_ADDRESS,
+    }),
+    getInitializeAccount3Instruction({
+      account: token.address,
+      mint: NATIVE_MINT,
+      owner,
+    }),
+  ];
+  await pipe(
+    transactionMessage,
+    (tx) => appendTransactionMessageInstructions(instructions, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  return token.address;
+};
+
+test('it unwraps a given amount of lamports from a native token account', async (t) => {
+  // Given a native token account with 1 SOL and a destination account.
+  const client = createDefaultSolanaClient();
+  const [payer, owner, destination] = await Promise.all([
+    generateKeyPairSignerWithSol(client, 2_000_000_000n),
+    generateKeyPairSigner(),
+    generateKeyPairSignerWithSol(client),
+  ]);
+  const token = await createNativeToken(
+    client,
+    payer,
+    owner.address,
+    1_000_000_000n
+  );
+  const destinationBalance = await getBalance(client, destination.address);
+
+  // When the owner unwraps 0.4 SOL to the destination account.
+  const unwrapLamports = getUnwrapLamportsInstruction({
+    source: token,
+    destination: destination.address,
+    authority: owner,
+    amount: 400_000_000n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(unwrapLamports, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the lamports to have moved to the destination account.
+  const { data: tokenData } = await fetchToken(client.rpc, token);
+  t.like(tokenData, <Token>{ amount: 600_000_000n });
+  t.is(
+    await getBalance(client, destination.address),
+    lamports(destinationBalance + 400_000_000n)
+  );
+});
+
+test('it unwraps all lamports from a native token account', async (t) => {
+  // Given a native token account with 1 SOL and a destination account.
+  const client = createDefaultSolanaClient();
+  const [payer, owner, destination] = await Promise.all([
+    generateKeyPairSignerWithSol(client, 2_000_000_000n),
+    generateKeyPairSigner(),
+    generateKeyPairSignerWithSol(client),
+  ]);
+  const token = await createNativeToken(
+    client,
+    payer,
+    owner.address,
+    1_000_000_000n
+  );
+  const destinationBalance = await getBalance(client, destination.address);
+
+  // When the owner unwraps without specifying an amount.
+  const unwrapLamports = getUnwrapLamportsInstruction({
+    source: token,
+    destination: destination.address,
+    authority: owner,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(unwrapLamports, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the whole token balance to have moved to the destination.
+  const { data: tokenData } = await fetchToken(client.rpc, token);
+  t.like(tokenData, <Token>{ amount: 0n });
+  t.is(
+    await getBalance(client, destination.address),
+    lamports(destinationBalance + 1_000_000_000n)
+  );
+});
@@ -0,0 +1,61 @@
+import { getTransferSolInstruction } from '@solana-program/system';
+import {
+  appendTransactionMessageInstruction,
+  generateKeyPairSigner,
+  lamports,
+  pipe,
+} from '@solana/kit';
+import test from 'ava';
+import { getMintSize, getWithdrawExcessLamportsInstruction } from '../src';
+import {
+  createDefaultSolanaClient,
+  createDefaultTransaction,
+  createMint,
+  generateKeyPairSignerWithSol,
+  getBalance,
+  signAndSendTransaction,
+} from './_setup';
+
+test('it withdraws excess lamports from a mint account', async (t) => {
+  // Given a mint account holding more lamports than its rent exemption.
+  const client = createDefaultSolanaClient();
+  const [payer, mintAuthority, destination] = await Promise.all([
+    generateKeyPairSignerWithSol(client),
+    generateKeyPairSigner(),
+    generateKeyPairSignerWithSol(client),
+  ]);
+  const mint = await createMint(client, payer, mintAuthority.address);
+  const transferSol = getTransferSolInstruction({
+    source: payer,
+    destination: mint,
+    amount: 5_000n,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(transferSol, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+  const destinationBalance = await getBalance(client, destination.address);
+
+  // When the mint authority withdraws the excess lamports.
+  const withdrawExcessLamports = getWithdrawExcessLamportsInstruction({
+    source: mint,
+    destination: destination.address,
+    authority: mintAuthority,
+  });
+  await pipe(
+    await createDefaultTransaction(client, payer),
+    (tx) => appendTransactionMessageInstruction(withdrawExcessLamports, tx),
+    (tx) => signAndSendTransaction(client, tx)
+  );
+
+  // Then we expect the mint account to only hold its rent exemption.
+  const rent = await client.rpc
+    .getMinimumBalanceForRentExemption(BigInt(getMintSize()))
+    .send();
+  t.is(await getBalance(client, mint), rent);
+  t.is(
+    await getBalance(client, destination.address),
+    lamports(destinationBalance + 5_000n)
+  );
+});
@@ -0,0 +1,62 @@
+use {
+    crate::{accounts::Mint, instructions::InitializeMint2Builder, InstructionPlanConfig},
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+};
+
+/// Input of [`create_mint_instructions`].
+#[derive(Clone, Debug)]
+pub struct CreateMintInput {
+    /// Funding account (must be a system account).
+    pub payer: Pubkey,
+    /// New mint account to create.
+    pub new_mint: Pubkey,
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// The authority/multisignature to mint tokens.
+    pub mint_authority: Pubkey,
+    /// The optional freeze authority/multisignature of the mint.
+    pub freeze_authority: Option<Pubkey>,
+    /// Optional override for the amount of lamports to fund the mint account
+    /// with. Defaults to the rent-exempt minimum balance of a mint.
+    pub mint_account_lamports: Option<u64>,
+}
+
+/// Returns the instructions to create and initialize a new mint.
+///
+/// The plan consists of:
+///
+///   0. System program `CreateAccount` for the mint.
+///   1. `InitializeMint2`.
+pub fn create_mint_instructions(
+    input: &CreateMintInput,
+    rent: &Rent,
+    config: &InstructionPlanConfig,
+) -> Vec<Instruction> {
+    let token_program = config.token_program();
+
+    let mut create_account = solana_system_interface::instruction::create_account(
+        &input.payer,
+        &input.new_mint,
+        input
+            .mint_account_lamports
+            .unwrap_or_else(|| rent.minimum_balance(Mint::LEN)),
+        Mint::LEN as u64,
+        &token_program,
+    );
+    create_account.program_id = config.system_program();
+
+    let mut initialize_mint = InitializeMint2Builder::new();
+    initialize_mint
+        .mint(input.new_mint)
+        .decimals(input.decimals)
+        .mint_authority(input.mint_authority);
+    if let Some(freeze_authority) = input.freeze_authority {
+        initialize_mint.freeze_authority(freeze_authority);
+    }
+    let mut initialize_mint = initialize_mint.instruction();
+    initialize_mint.program_id = token_program;
+
+    vec![create_account, initialize_mint]
+}
@@ -0,0 +1,33 @@
+//! Serialization of optional values as `COption`: a `u32` tag followed by
+//! the value, which is zeroed when absent so that the size is fixed.
+
+use borsh::{
+    io::{Error, ErrorKind, Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+pub fn serialize<T: BorshSerialize + Default, W: Write>(
+    value: &Option<T>,
+    writer: &mut W,
+) -> Result<()> {
+    match value {
+        Some(value) => {
+            1u32.serialize(writer)?;
+            value.serialize(writer)
+        }
+        None => {
+            0u32.serialize(writer)?;
+            T::default().serialize(writer)
+        }
+    }
+}
+
+pub fn deserialize<T: BorshDeserialize, R: Read>(reader: &mut R) -> Result<Option<T>> {
+    let tag = u32::deserialize_reader(reader)?;
+    let value = T::deserialize_reader(reader)?;
+    match tag {
+        0 => Ok(None),
+        1 => Ok(Some(value)),
+        _ => Err(Error::new(ErrorKind::InvalidData, "invalid COption tag")),
+    }
+}
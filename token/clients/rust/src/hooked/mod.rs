@@ -0,0 +1,9 @@
+//! Hand-written serialization helpers referenced by the generated code.
+//!
+//! These live outside of `generated` so that regenerating the client with
+//! codama does not remove them.
+
+pub mod c_option;
+pub mod remainder_str;
+pub mod remainder_vec;
+pub mod u8_prefix_bytes;
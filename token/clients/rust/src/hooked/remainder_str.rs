@@ -0,0 +1,16 @@
+//! Serialization of strings that take the remainder of the data, without a
+//! length prefix.
+
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+
+pub fn serialize<W: Write>(value: &String, writer: &mut W) -> Result<()> {
+    writer.write_all(value.as_bytes())
+}
+
+pub fn deserialize<R: Read>(reader: &mut R) -> Result<String> {
+    let mut value = String::new();
+    reader
+        .read_to_string(&mut value)
+        .map_err(|_| Error::new(ErrorKind::InvalidData, "invalid UTF-8 string"))?;
+    Ok(value)
+}
@@ -0,0 +1,23 @@
+//! Serialization of vectors that take the remainder of the data, without a
+//! length prefix.
+
+use borsh::{
+    io::{Read, Result, Write},
+    BorshDeserialize, BorshSerialize,
+};
+
+pub fn serialize<T: BorshSerialize, W: Write>(value: &[T], writer: &mut W) -> Result<()> {
+    value.iter().try_for_each(|item| item.serialize(writer))
+}
+
+pub fn deserialize<T: BorshDeserialize, R: Read>(reader: &mut R) -> Result<Vec<T>> {
+    let mut data = Vec::new();
+    reader.read_to_end(&mut data)?;
+
+    let mut remaining = data.as_slice();
+    let mut value = Vec::new();
+    while !remaining.is_empty() {
+        value.push(T::deserialize(&mut remaining)?);
+    }
+    Ok(value)
+}
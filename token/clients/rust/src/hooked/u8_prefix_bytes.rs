@@ -0,0 +1,18 @@
+//! Serialization of bytes prefixed by their length as a `u8`.
+
+use borsh::io::{Error, ErrorKind, Read, Result, Write};
+
+pub fn serialize<W: Write>(value: &[u8], writer: &mut W) -> Result<()> {
+    let len = u8::try_from(value.len())
+        .map_err(|_| Error::new(ErrorKind::InvalidInput, "data longer than 255 bytes"))?;
+    writer.write_all(&[len])?;
+    writer.write_all(value)
+}
+
+pub fn deserialize<R: Read>(reader: &mut R) -> Result<Vec<u8>> {
+    let mut len = [0u8; 1];
+    reader.read_exact(&mut len)?;
+    let mut value = vec![0u8; len[0] as usize];
+    reader.read_exact(&mut value)?;
+    Ok(value)
+}
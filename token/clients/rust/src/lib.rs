@@ -1,6 +1,14 @@
-/*
+mod create_mint;
 mod generated;
+mod hooked;
+mod mint_to_ata;
+mod plan;
+mod transfer_to_ata;
 
-pub use generated::programs::TOKEN_ID as ID;
-pub use generated::*;
-*/
+pub use {
+    create_mint::*,
+    generated::{programs::TOKEN_ID as ID, *},
+    mint_to_ata::*,
+    plan::*,
+    transfer_to_ata::*,
+};
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        instructions::{CreateAssociatedTokenIdempotentBuilder, MintToCheckedBuilder},
+        plan::multisig_signer_metas,
+        InstructionPlanConfig,
+    },
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+};
+
+/// Input of [`mint_to_ata_instructions`].
+#[derive(Clone, Debug)]
+pub struct MintToAtaInput {
+    /// Funding account (must be a system account).
+    pub payer: Pubkey,
+    /// Associated token account address to mint to. It will be created if it
+    /// does not already exist.
+    ///
+    /// Derived from `owner` and `mint` when not set.
+    pub ata: Option<Pubkey>,
+    /// Wallet address for the associated token account.
+    pub owner: Pubkey,
+    /// The token mint for the associated token account.
+    pub mint: Pubkey,
+    /// The mint's minting authority or its multisignature account.
+    pub mint_authority: Pubkey,
+    /// The amount of new tokens to mint.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// Signer accounts if `mint_authority` is a multisignature account.
+    pub multi_signers: Vec<Pubkey>,
+}
+
+/// Returns the instructions to mint tokens to an associated token account,
+/// creating it if needed.
+///
+/// The plan consists of:
+///
+///   0. `CreateAssociatedTokenIdempotent`.
+///   1. `MintToChecked`.
+pub fn mint_to_ata_instructions(
+    input: &MintToAtaInput,
+    config: &InstructionPlanConfig,
+) -> Vec<Instruction> {
+    let token_program = config.token_program();
+    let ata = input
+        .ata
+        .unwrap_or_else(|| config.find_associated_token_address(&input.owner, &input.mint));
+
+    let mut create_ata = CreateAssociatedTokenIdempotentBuilder::new()
+        .payer(input.payer)
+        .ata(ata)
+        .owner(input.owner)
+        .mint(input.mint)
+        .system_program(config.system_program())
+        .token_program(token_program)
+        .instruction();
+    create_ata.program_id = config.associated_token_program();
+
+    let mut mint_to = MintToCheckedBuilder::new()
+        .mint(input.mint)
+        .token(ata)
+        .mint_authority(input.mint_authority, input.multi_signers.is_empty())
+        .amount(input.amount)
+        .decimals(input.decimals)
+        .add_remaining_accounts(&multisig_signer_metas(&input.multi_signers))
+        .instruction();
+    mint_to.program_id = token_program;
+
+    vec![create_ata, mint_to]
+}
@@ -0,0 +1,114 @@
+use {
+    crate::{
+        instructions::{BatchBuilder, BATCH_DISCRIMINATOR},
+        types::BatchedInstruction,
+    },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+};
+
+/// Program addresses used by the instruction plans.
+///
+/// Each address defaults to the corresponding program ID of this crate when
+/// not set.
+#[derive(Clone, Debug, Default)]
+pub struct InstructionPlanConfig {
+    /// The system program.
+    pub system_program: Option<Pubkey>,
+    /// The token program.
+    pub token_program: Option<Pubkey>,
+    /// The associated token program.
+    pub associated_token_program: Option<Pubkey>,
+}
+
+impl InstructionPlanConfig {
+    pub(crate) fn system_program(&self) -> Pubkey {
+        self.system_program
+            .unwrap_or(solana_system_interface::program::ID)
+    }
+
+    pub(crate) fn token_program(&self) -> Pubkey {
+        self.token_program.unwrap_or(crate::TOKEN_ID)
+    }
+
+    pub(crate) fn associated_token_program(&self) -> Pubkey {
+        self.associated_token_program
+            .unwrap_or(crate::ASSOCIATED_TOKEN_ID)
+    }
+
+    /// Finds the associated token account address of `owner` for `mint`.
+    pub fn find_associated_token_address(&self, owner: &Pubkey, mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[owner.as_ref(), self.token_program().as_ref(), mint.as_ref()],
+            &self.associated_token_program(),
+        )
+        .0
+    }
+}
+
+/// Returns the multisig signer accounts of an instruction.
+pub(crate) fn multisig_signer_metas(multi_signers: &[Pubkey]) -> Vec<AccountMeta> {
+    multi_signers
+        .iter()
+        .map(|signer| AccountMeta::new_readonly(*signer, true))
+        .collect()
+}
+
+/// Merges consecutive instructions of `token_program` into `Batch`
+/// instructions.
+///
+/// The order of the instructions is preserved. Runs of a single token
+/// instruction are left as they are, as well as instructions that cannot be
+/// represented in a batch frame, i.e., with more than `u8::MAX` accounts or
+/// bytes of instruction data, or that are themselves a `Batch`.
+///
+/// This must only be used when `token_program` supports the `Batch`
+/// instruction.
+pub fn batch_instructions(
+    instructions: Vec<Instruction>,
+    token_program: &Pubkey,
+) -> Vec<Instruction> {
+    let mut result = Vec::with_capacity(instructions.len());
+    let mut run: Vec<Instruction> = Vec::new();
+
+    for instruction in instructions {
+        let batchable = instruction.program_id == *token_program
+            && instruction.accounts.len() <= u8::MAX as usize
+            && instruction.data.len() <= u8::MAX as usize
+            && matches!(instruction.data.first(), Some(&d) if d != BATCH_DISCRIMINATOR);
+
+        if batchable {
+            run.push(instruction);
+        } else {
+            flush_run(&mut run, &mut result, token_program);
+            result.push(instruction);
+        }
+    }
+
+    flush_run(&mut run, &mut result, token_program);
+
+    result
+}
+
+fn flush_run(run: &mut Vec<Instruction>, result: &mut Vec<Instruction>, token_program: &Pubkey) {
+    if run.len() < 2 {
+        result.append(run);
+        return;
+    }
+
+    let mut batch = BatchBuilder::new();
+    let mut instructions = Vec::with_capacity(run.len());
+
+    for instruction in run.drain(..) {
+        instructions.push(BatchedInstruction {
+            num_accounts: instruction.accounts.len() as u8,
+            data: instruction.data,
+        });
+        batch.add_remaining_accounts(&instruction.accounts);
+    }
+
+    let mut instruction = batch.instructions(instructions).instruction();
+    instruction.program_id = *token_program;
+
+    result.push(instruction);
+}
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        instructions::{CreateAssociatedTokenIdempotentBuilder, TransferCheckedBuilder},
+        plan::multisig_signer_metas,
+        InstructionPlanConfig,
+    },
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+};
+
+/// Input of [`transfer_to_ata_instructions`].
+#[derive(Clone, Debug)]
+pub struct TransferToAtaInput {
+    /// Funding account (must be a system account).
+    pub payer: Pubkey,
+    /// The token mint to transfer.
+    pub mint: Pubkey,
+    /// The source account for the transfer.
+    pub source: Pubkey,
+    /// The source account's owner/delegate or its multisignature account.
+    pub authority: Pubkey,
+    /// Associated token account address to transfer to. It will be created if
+    /// it does not already exist.
+    ///
+    /// Derived from `recipient` and `mint` when not set.
+    pub destination: Option<Pubkey>,
+    /// Wallet address for the destination.
+    pub recipient: Pubkey,
+    /// The amount of tokens to transfer.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// Signer accounts if `authority` is a multisignature account.
+    pub multi_signers: Vec<Pubkey>,
+}
+
+/// Returns the instructions to transfer tokens to an associated token
+/// account, creating it if needed.
+///
+/// The plan consists of:
+///
+///   0. `CreateAssociatedTokenIdempotent`.
+///   1. `TransferChecked`.
+pub fn transfer_to_ata_instructions(
+    input: &TransferToAtaInput,
+    config: &InstructionPlanConfig,
+) -> Vec<Instruction> {
+    let token_program = config.token_program();
+    let destination = input
+        .destination
+        .unwrap_or_else(|| config.find_associated_token_address(&input.recipient, &input.mint));
+
+    let mut create_ata = CreateAssociatedTokenIdempotentBuilder::new()
+        .payer(input.payer)
+        .ata(destination)
+        .owner(input.recipient)
+        .mint(input.mint)
+        .system_program(config.system_program())
+        .token_program(token_program)
+        .instruction();
+    create_ata.program_id = config.associated_token_program();
+
+    let mut transfer = TransferCheckedBuilder::new()
+        .source(input.source)
+        .mint(input.mint)
+        .destination(destination)
+        .authority(input.authority, input.multi_signers.is_empty())
+        .amount(input.amount)
+        .decimals(input.decimals)
+        .add_remaining_accounts(&multisig_signer_metas(&input.multi_signers))
+        .instruction();
+    transfer.program_id = token_program;
+
+    vec![create_ata, transfer]
+}
@@ -0,0 +1,132 @@
+//! Tests decoding accounts packed by the token program.
+
+use {
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    spl_token_client::{
+        accounts::{Mint, Multisig, Token},
+        types::AccountState,
+    },
+};
+
+#[test]
+fn decode_mint() {
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+
+    let mut data = [0; spl_token_interface::state::Mint::LEN];
+    spl_token_interface::state::Mint {
+        mint_authority: COption::Some(mint_authority),
+        supply: 1_000_000,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority: COption::None,
+    }
+    .pack_into_slice(&mut data);
+
+    assert_eq!(Mint::LEN, data.len());
+    assert_eq!(
+        Mint::from_bytes(&data).unwrap(),
+        Mint {
+            mint_authority: Some(mint_authority),
+            supply: 1_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: None,
+        }
+    );
+
+    let mut data = [0; spl_token_interface::state::Mint::LEN];
+    spl_token_interface::state::Mint {
+        mint_authority: COption::None,
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: COption::Some(freeze_authority),
+    }
+    .pack_into_slice(&mut data);
+
+    let mint = Mint::from_bytes(&data).unwrap();
+    assert_eq!(mint.mint_authority, None);
+    assert_eq!(mint.freeze_authority, Some(freeze_authority));
+
+    // Encoding the decoded account must produce the same bytes.
+    assert_eq!(borsh::to_vec(&mint).unwrap(), data);
+}
+
+#[test]
+fn decode_token() {
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let close_authority = Pubkey::new_unique();
+
+    let mut data = [0; spl_token_interface::state::Account::LEN];
+    spl_token_interface::state::Account {
+        mint,
+        owner,
+        amount: 500,
+        delegate: COption::Some(delegate),
+        state: spl_token_interface::state::AccountState::Frozen,
+        is_native: COption::Some(2_039_280),
+        delegated_amount: 100,
+        close_authority: COption::Some(close_authority),
+    }
+    .pack_into_slice(&mut data);
+
+    assert_eq!(Token::LEN, data.len());
+
+    let token = Token::from_bytes(&data).unwrap();
+    assert_eq!(
+        token,
+        Token {
+            mint,
+            owner,
+            amount: 500,
+            delegate: Some(delegate),
+            state: AccountState::Frozen,
+            is_native: Some(2_039_280),
+            delegated_amount: 100,
+            close_authority: Some(close_authority),
+        }
+    );
+    assert_eq!(borsh::to_vec(&token).unwrap(), data);
+}
+
+#[test]
+fn decode_multisig() {
+    let signers = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+
+    let mut packed_signers = [Pubkey::default(); 11];
+    packed_signers[..signers.len()].copy_from_slice(&signers);
+
+    let mut data = [0; spl_token_interface::state::Multisig::LEN];
+    spl_token_interface::state::Multisig {
+        m: 2,
+        n: 3,
+        is_initialized: true,
+        signers: packed_signers,
+    }
+    .pack_into_slice(&mut data);
+
+    assert_eq!(Multisig::LEN, data.len());
+
+    let multisig = Multisig::from_bytes(&data).unwrap();
+    assert_eq!(multisig.m, 2);
+    assert_eq!(multisig.n, 3);
+    assert!(multisig.is_initialized);
+    assert_eq!(multisig.signers, packed_signers);
+}
+
+#[test]
+fn decode_invalid_option_tag() {
+    let mut data = [0; Mint::LEN];
+    data[0] = 2;
+
+    assert!(Mint::from_bytes(&data).is_err());
+}
@@ -0,0 +1,714 @@
+//! Tests that the instruction builders match the `spl-token-interface` ones.
+
+use {
+    borsh::BorshDeserialize,
+    solana_instruction::AccountMeta,
+    solana_pubkey::Pubkey,
+    spl_token_client::{
+        instructions::*,
+        pdas::find_associated_token_pda,
+        types::{AuthorityType, BatchedInstruction, Expiry},
+    },
+    spl_token_interface::instruction as interface,
+};
+
+fn multisig_signers(signers: &[Pubkey]) -> Vec<AccountMeta> {
+    signers
+        .iter()
+        .map(|signer| AccountMeta::new_readonly(*signer, true))
+        .collect()
+}
+
+#[test]
+fn initialize_instructions() {
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    assert_eq!(
+        InitializeMintBuilder::new()
+            .mint(mint)
+            .decimals(6)
+            .mint_authority(owner)
+            .freeze_authority(freeze_authority)
+            .instruction(),
+        interface::initialize_mint(
+            &spl_token_client::ID,
+            &mint,
+            &owner,
+            Some(&freeze_authority),
+            6
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        InitializeMint2Builder::new()
+            .mint(mint)
+            .decimals(9)
+            .mint_authority(owner)
+            .instruction(),
+        interface::initialize_mint2(&spl_token_client::ID, &mint, &owner, None, 9).unwrap()
+    );
+
+    assert_eq!(
+        InitializeAccountBuilder::new()
+            .account(account)
+            .mint(mint)
+            .owner(owner)
+            .instruction(),
+        interface::initialize_account(&spl_token_client::ID, &account, &mint, &owner).unwrap()
+    );
+
+    assert_eq!(
+        InitializeAccount2Builder::new()
+            .account(account)
+            .mint(mint)
+            .owner(owner)
+            .instruction(),
+        interface::initialize_account2(&spl_token_client::ID, &account, &mint, &owner).unwrap()
+    );
+
+    assert_eq!(
+        InitializeAccount3Builder::new()
+            .account(account)
+            .mint(mint)
+            .owner(owner)
+            .instruction(),
+        interface::initialize_account3(&spl_token_client::ID, &account, &mint, &owner).unwrap()
+    );
+
+    assert_eq!(
+        InitializeMultisigBuilder::new()
+            .multisig(account)
+            .m(2)
+            .add_remaining_accounts(&signers.map(|signer| AccountMeta::new_readonly(signer, false)))
+            .instruction(),
+        interface::initialize_multisig(
+            &spl_token_client::ID,
+            &account,
+            &[&signers[0], &signers[1]],
+            2
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        InitializeMultisig2Builder::new()
+            .multisig(account)
+            .m(1)
+            .add_remaining_accounts(&signers.map(|signer| AccountMeta::new_readonly(signer, false)))
+            .instruction(),
+        interface::initialize_multisig2(
+            &spl_token_client::ID,
+            &account,
+            &[&signers[0], &signers[1]],
+            1
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        InitializeImmutableOwnerBuilder::new()
+            .account(account)
+            .instruction(),
+        interface::initialize_immutable_owner(&spl_token_client::ID, &account).unwrap()
+    );
+}
+
+#[test]
+fn token_instructions() {
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let delegate = Pubkey::new_unique();
+    let delegation = Pubkey::new_unique();
+    let spending_limit = Pubkey::new_unique();
+    let mint_pause = Pubkey::new_unique();
+
+    assert_eq!(
+        TransferBuilder::new()
+            .source(source)
+            .destination(destination)
+            .authority(owner, true)
+            .amount(42)
+            .instruction(),
+        interface::transfer(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &owner,
+            &[],
+            42
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        TransferCheckedBuilder::new()
+            .source(source)
+            .mint(mint)
+            .destination(destination)
+            .authority(owner, true)
+            .amount(42)
+            .decimals(6)
+            .instruction(),
+        interface::transfer_checked(
+            &spl_token_client::ID,
+            &source,
+            &mint,
+            &destination,
+            &owner,
+            &[],
+            42,
+            6
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        ApproveBuilder::new()
+            .source(source)
+            .delegate(delegate)
+            .owner(owner, true)
+            .amount(10)
+            .instruction(),
+        interface::approve(&spl_token_client::ID, &source, &delegate, &owner, &[], 10).unwrap()
+    );
+
+    assert_eq!(
+        ApproveCheckedBuilder::new()
+            .source(source)
+            .mint(mint)
+            .delegate(delegate)
+            .owner(owner, true)
+            .amount(10)
+            .decimals(6)
+            .instruction(),
+        interface::approve_checked(
+            &spl_token_client::ID,
+            &source,
+            &mint,
+            &delegate,
+            &owner,
+            &[],
+            10,
+            6
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        RevokeBuilder::new()
+            .source(source)
+            .owner(owner, true)
+            .instruction(),
+        interface::revoke(&spl_token_client::ID, &source, &owner, &[]).unwrap()
+    );
+
+    assert_eq!(
+        SetAuthorityBuilder::new()
+            .owned(source)
+            .owner(owner, true)
+            .authority_type(AuthorityType::CloseAccount)
+            .new_authority(delegate)
+            .instruction(),
+        interface::set_authority(
+            &spl_token_client::ID,
+            &source,
+            Some(&delegate),
+            interface::AuthorityType::CloseAccount,
+            &owner,
+            &[]
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        MintToBuilder::new()
+            .mint(mint)
+            .token(destination)
+            .mint_authority(owner, true)
+            .amount(1_000)
+            .instruction(),
+        interface::mint_to(
+            &spl_token_client::ID,
+            &mint,
+            &destination,
+            &owner,
+            &[],
+            1_000
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        MintToCheckedBuilder::new()
+            .mint(mint)
+            .token(destination)
+            .mint_authority(owner, true)
+            .amount(1_000)
+            .decimals(6)
+            .instruction(),
+        interface::mint_to_checked(
+            &spl_token_client::ID,
+            &mint,
+            &destination,
+            &owner,
+            &[],
+            1_000,
+            6
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        BurnBuilder::new()
+            .account(source)
+            .mint(mint)
+            .authority(owner, true)
+            .amount(5)
+            .instruction(),
+        interface::burn(&spl_token_client::ID, &source, &mint, &owner, &[], 5).unwrap()
+    );
+
+    assert_eq!(
+        BurnCheckedBuilder::new()
+            .account(source)
+            .mint(mint)
+            .authority(owner, true)
+            .amount(5)
+            .decimals(6)
+            .instruction(),
+        interface::burn_checked(&spl_token_client::ID, &source, &mint, &owner, &[], 5, 6).unwrap()
+    );
+
+    assert_eq!(
+        CloseAccountBuilder::new()
+            .account(source)
+            .destination(destination)
+            .owner(owner, true)
+            .instruction(),
+        interface::close_account(&spl_token_client::ID, &source, &destination, &owner, &[])
+            .unwrap()
+    );
+
+    assert_eq!(
+        BurnAndCloseBuilder::new()
+            .account(source)
+            .mint(mint)
+            .destination(destination)
+            .authority(owner, true)
+            .instruction(),
+        interface::burn_and_close(
+            &spl_token_client::ID,
+            &source,
+            &mint,
+            &destination,
+            &owner,
+            &[]
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        SweepAndCloseBuilder::new()
+            .source(source)
+            .destination(destination)
+            .recipient(owner)
+            .authority(owner, true)
+            .instruction(),
+        interface::sweep_and_close(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &owner,
+            &owner,
+            &[]
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        ApproveWithExpiryBuilder::new()
+            .source(source)
+            .delegation(delegation)
+            .delegate(delegate)
+            .owner(owner, true)
+            .amount(10)
+            .expiry(Expiry::UnixTimestamp(-1))
+            .instruction(),
+        interface::approve_with_expiry(
+            &spl_token_client::ID,
+            &source,
+            &delegation,
+            &delegate,
+            &owner,
+            &[],
+            10,
+            spl_token_interface::state::Expiry::UnixTimestamp(-1),
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        CloseDelegationBuilder::new()
+            .source(source)
+            .delegation(delegation)
+            .destination(destination)
+            .owner(owner, true)
+            .instruction(),
+        interface::close_delegation(
+            &spl_token_client::ID,
+            &source,
+            &delegation,
+            &destination,
+            &owner,
+            &[]
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        ApproveWithSpendingLimitBuilder::new()
+            .source(source)
+            .spending_limit(spending_limit)
+            .delegate(delegate)
+            .owner(owner, true)
+            .limit(10)
+            .instruction(),
+        interface::approve_with_spending_limit(
+            &spl_token_client::ID,
+            &source,
+            &spending_limit,
+            &delegate,
+            &owner,
+            &[],
+            10,
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        SetMemoRequiredBuilder::new()
+            .account(source)
+            .owner(owner, true)
+            .required(true)
+            .instruction(),
+        interface::set_memo_required(&spl_token_client::ID, &source, &owner, &[], true).unwrap()
+    );
+
+    assert_eq!(
+        PauseMintBuilder::new()
+            .mint(mint)
+            .mint_pause(mint_pause)
+            .owner(owner, true)
+            .instruction(),
+        interface::pause_mint(&spl_token_client::ID, &mint, &mint_pause, &owner, &[]).unwrap()
+    );
+
+    assert_eq!(
+        ResumeMintBuilder::new()
+            .mint(mint)
+            .mint_pause(mint_pause)
+            .destination(destination)
+            .owner(owner, true)
+            .instruction(),
+        interface::resume_mint(
+            &spl_token_client::ID,
+            &mint,
+            &mint_pause,
+            &destination,
+            &owner,
+            &[]
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        FreezeAccountBuilder::new()
+            .account(source)
+            .mint(mint)
+            .owner(owner, true)
+            .instruction(),
+        interface::freeze_account(&spl_token_client::ID, &source, &mint, &owner, &[]).unwrap()
+    );
+
+    assert_eq!(
+        ThawAccountBuilder::new()
+            .account(source)
+            .mint(mint)
+            .owner(owner, true)
+            .instruction(),
+        interface::thaw_account(&spl_token_client::ID, &source, &mint, &owner, &[]).unwrap()
+    );
+
+    assert_eq!(
+        SyncNativeBuilder::new().account(source).instruction(),
+        interface::sync_native(&spl_token_client::ID, &source).unwrap()
+    );
+}
+
+#[test]
+fn multisig_instructions() {
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let multisig = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    assert_eq!(
+        TransferBuilder::new()
+            .source(source)
+            .destination(destination)
+            .authority(multisig, false)
+            .amount(42)
+            .add_remaining_accounts(&multisig_signers(&signers))
+            .instruction(),
+        interface::transfer(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &multisig,
+            &[&signers[0], &signers[1]],
+            42
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        CloseAccountBuilder::new()
+            .account(source)
+            .destination(destination)
+            .owner(multisig, false)
+            .add_remaining_accounts(&multisig_signers(&signers))
+            .instruction(),
+        interface::close_account(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &multisig,
+            &[&signers[0], &signers[1]]
+        )
+        .unwrap()
+    );
+
+    let destinations = [
+        AccountMeta::new(destination, false),
+        AccountMeta::new(source, false),
+    ];
+
+    assert_eq!(
+        TransferManyBuilder::new()
+            .source(source)
+            .authority(multisig, false)
+            .amounts(vec![42, 7])
+            .add_remaining_accounts(&destinations)
+            .add_remaining_accounts(&multisig_signers(&signers))
+            .instruction(),
+        interface::transfer_many(
+            &spl_token_client::ID,
+            &source,
+            &[(&destination, 42), (&source, 7)],
+            &multisig,
+            &[&signers[0], &signers[1]]
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn mint_instructions() {
+    let mint = Pubkey::new_unique();
+
+    assert_eq!(
+        GetAccountDataSizeBuilder::new().mint(mint).instruction(),
+        interface::get_account_data_size(&spl_token_client::ID, &mint).unwrap()
+    );
+
+    assert_eq!(
+        AmountToUiAmountBuilder::new()
+            .mint(mint)
+            .amount(1_500)
+            .instruction(),
+        interface::amount_to_ui_amount(&spl_token_client::ID, &mint, 1_500).unwrap()
+    );
+
+    assert_eq!(
+        UiAmountToAmountBuilder::new()
+            .mint(mint)
+            .ui_amount("1.5".to_string())
+            .instruction(),
+        interface::ui_amount_to_amount(&spl_token_client::ID, &mint, "1.5").unwrap()
+    );
+}
+
+#[test]
+fn lamports_instructions() {
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    assert_eq!(
+        WithdrawExcessLamportsBuilder::new()
+            .source(source)
+            .destination(destination)
+            .authority(authority, true)
+            .instruction(),
+        interface::withdraw_excess_lamports(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &authority,
+            &[]
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        WithdrawExcessLamportsBuilder::new()
+            .source(source)
+            .destination(destination)
+            .authority(authority, false)
+            .add_remaining_accounts(&multisig_signers(&signers))
+            .instruction(),
+        interface::withdraw_excess_lamports(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &authority,
+            &[&signers[0], &signers[1]]
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        UnwrapLamportsBuilder::new()
+            .source(source)
+            .destination(destination)
+            .authority(authority, true)
+            .instruction(),
+        interface::unwrap_lamports(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &authority,
+            &[],
+            None
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        UnwrapLamportsBuilder::new()
+            .source(source)
+            .destination(destination)
+            .authority(authority, false)
+            .amount(42)
+            .add_remaining_accounts(&multisig_signers(&signers))
+            .instruction(),
+        interface::unwrap_lamports(
+            &spl_token_client::ID,
+            &source,
+            &destination,
+            &authority,
+            &[&signers[0], &signers[1]],
+            Some(42)
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn batch_instruction() {
+    let mint = Pubkey::new_unique();
+    let account = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let instructions = vec![
+        interface::mint_to(&spl_token_client::ID, &mint, &account, &owner, &[], 100).unwrap(),
+        interface::transfer(
+            &spl_token_client::ID,
+            &account,
+            &destination,
+            &owner,
+            &[],
+            40,
+        )
+        .unwrap(),
+        interface::close_account(&spl_token_client::ID, &destination, &owner, &owner, &[]).unwrap(),
+    ];
+
+    let instruction = BatchBuilder::new()
+        .instructions(
+            instructions
+                .iter()
+                .map(|instruction| BatchedInstruction {
+                    num_accounts: instruction.accounts.len() as u8,
+                    data: instruction.data.clone(),
+                })
+                .collect(),
+        )
+        .add_remaining_accounts(
+            &instructions
+                .iter()
+                .flat_map(|instruction| instruction.accounts.clone())
+                .collect::<Vec<_>>(),
+        )
+        .instruction();
+
+    assert_eq!(instruction, interface::batch(instructions.clone()).unwrap());
+
+    // The instruction data round-trips through the batch frames.
+    let args = BatchInstructionArgs::try_from_slice(&instruction.data[1..]).unwrap();
+    assert_eq!(args.instructions.len(), instructions.len());
+    for (batched, instruction) in args.instructions.iter().zip(instructions.iter()) {
+        assert_eq!(batched.num_accounts as usize, instruction.accounts.len());
+        assert_eq!(batched.data, instruction.data);
+    }
+}
+
+#[test]
+fn associated_token_instructions() {
+    let payer = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+
+    let (ata, _) = find_associated_token_pda(&owner, &spl_token_client::ID, &mint);
+    let (expected, _) = Pubkey::find_program_address(
+        &[owner.as_ref(), spl_token_client::ID.as_ref(), mint.as_ref()],
+        &spl_token_client::programs::ASSOCIATED_TOKEN_ID,
+    );
+    assert_eq!(ata, expected);
+
+    let instruction = CreateAssociatedTokenIdempotentBuilder::new()
+        .payer(payer)
+        .ata(ata)
+        .owner(owner)
+        .mint(mint)
+        .instruction();
+
+    assert_eq!(
+        instruction.program_id,
+        spl_token_client::programs::ASSOCIATED_TOKEN_ID
+    );
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(payer, true),
+            AccountMeta::new(ata, false),
+            AccountMeta::new_readonly(owner, false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            AccountMeta::new_readonly(spl_token_client::ID, false),
+        ]
+    );
+    assert_eq!(
+        instruction.data,
+        vec![CREATE_ASSOCIATED_TOKEN_IDEMPOTENT_DISCRIMINATOR]
+    );
+}
@@ -0,0 +1,284 @@
+//! Tests that the instruction plans match the equivalent hand-built
+//! instruction sequences.
+
+use {
+    solana_instruction::AccountMeta,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_client::{
+        batch_instructions, create_mint_instructions,
+        instructions::CreateAssociatedTokenIdempotentBuilder, mint_to_ata_instructions,
+        pdas::find_associated_token_pda, programs::ASSOCIATED_TOKEN_ID,
+        transfer_to_ata_instructions, CreateMintInput, InstructionPlanConfig, MintToAtaInput,
+        TransferToAtaInput,
+    },
+    spl_token_interface::{instruction as interface, state::Mint},
+};
+
+#[test]
+fn create_mint_plan() {
+    let payer = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let freeze_authority = Pubkey::new_unique();
+    let rent = Rent::default();
+
+    let input = CreateMintInput {
+        payer,
+        new_mint: mint,
+        decimals: 9,
+        mint_authority,
+        freeze_authority: Some(freeze_authority),
+        mint_account_lamports: None,
+    };
+
+    assert_eq!(
+        create_mint_instructions(&input, &rent, &InstructionPlanConfig::default()),
+        vec![
+            solana_system_interface::instruction::create_account(
+                &payer,
+                &mint,
+                rent.minimum_balance(Mint::LEN),
+                Mint::LEN as u64,
+                &spl_token_interface::ID,
+            ),
+            interface::initialize_mint2(
+                &spl_token_interface::ID,
+                &mint,
+                &mint_authority,
+                Some(&freeze_authority),
+                9,
+            )
+            .unwrap(),
+        ]
+    );
+
+    // Lamports override and custom token program.
+
+    let token_program = Pubkey::new_unique();
+    let config = InstructionPlanConfig {
+        token_program: Some(token_program),
+        ..Default::default()
+    };
+    let input = CreateMintInput {
+        freeze_authority: None,
+        mint_account_lamports: Some(42),
+        ..input
+    };
+
+    let instructions = create_mint_instructions(&input, &rent, &config);
+
+    assert_eq!(
+        instructions[0],
+        solana_system_interface::instruction::create_account(
+            &payer,
+            &mint,
+            42,
+            Mint::LEN as u64,
+            &token_program,
+        )
+    );
+    let mut expected =
+        interface::initialize_mint2(&spl_token_interface::ID, &mint, &mint_authority, None, 9)
+            .unwrap();
+    expected.program_id = token_program;
+    assert_eq!(instructions[1], expected);
+}
+
+#[test]
+fn mint_to_ata_plan() {
+    let payer = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let (ata, _) = find_associated_token_pda(&owner, &spl_token_interface::ID, &mint);
+
+    let input = MintToAtaInput {
+        payer,
+        ata: None,
+        owner,
+        mint,
+        mint_authority,
+        amount: 1_000,
+        decimals: 6,
+        multi_signers: vec![],
+    };
+
+    assert_eq!(
+        mint_to_ata_instructions(&input, &InstructionPlanConfig::default()),
+        vec![
+            create_associated_token_idempotent(&payer, &ata, &owner, &mint),
+            interface::mint_to_checked(
+                &spl_token_interface::ID,
+                &mint,
+                &ata,
+                &mint_authority,
+                &[],
+                1_000,
+                6,
+            )
+            .unwrap(),
+        ]
+    );
+
+    // Explicit ATA and multisig authority.
+
+    let destination = Pubkey::new_unique();
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let input = MintToAtaInput {
+        ata: Some(destination),
+        multi_signers: signers.to_vec(),
+        ..input
+    };
+
+    assert_eq!(
+        mint_to_ata_instructions(&input, &InstructionPlanConfig::default()),
+        vec![
+            create_associated_token_idempotent(&payer, &destination, &owner, &mint),
+            interface::mint_to_checked(
+                &spl_token_interface::ID,
+                &mint,
+                &destination,
+                &mint_authority,
+                &[&signers[0], &signers[1]],
+                1_000,
+                6,
+            )
+            .unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn transfer_to_ata_plan() {
+    let payer = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let authority = Pubkey::new_unique();
+    let recipient = Pubkey::new_unique();
+    let (destination, _) = find_associated_token_pda(&recipient, &spl_token_interface::ID, &mint);
+    let signers = [Pubkey::new_unique(), Pubkey::new_unique()];
+
+    let input = TransferToAtaInput {
+        payer,
+        mint,
+        source,
+        authority,
+        destination: None,
+        recipient,
+        amount: 500,
+        decimals: 2,
+        multi_signers: signers.to_vec(),
+    };
+
+    assert_eq!(
+        transfer_to_ata_instructions(&input, &InstructionPlanConfig::default()),
+        vec![
+            create_associated_token_idempotent(&payer, &destination, &recipient, &mint),
+            interface::transfer_checked(
+                &spl_token_interface::ID,
+                &source,
+                &mint,
+                &destination,
+                &authority,
+                &[&signers[0], &signers[1]],
+                500,
+                2,
+            )
+            .unwrap(),
+        ]
+    );
+}
+
+#[test]
+fn batch_plan_instructions() {
+    let payer = Pubkey::new_unique();
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let owners = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let config = InstructionPlanConfig::default();
+
+    let mut instructions = create_mint_instructions(
+        &CreateMintInput {
+            payer,
+            new_mint: mint,
+            decimals: 0,
+            mint_authority,
+            freeze_authority: None,
+            mint_account_lamports: None,
+        },
+        &Rent::default(),
+        &config,
+    );
+    let mut mint_to = Vec::new();
+
+    for owner in owners {
+        let mut plan = mint_to_ata_instructions(
+            &MintToAtaInput {
+                payer,
+                ata: None,
+                owner,
+                mint,
+                mint_authority,
+                amount: 10,
+                decimals: 0,
+                multi_signers: vec![],
+            },
+            &config,
+        );
+        // Group the token instructions at the end.
+        mint_to.push(plan.pop().unwrap());
+        instructions.append(&mut plan);
+    }
+
+    let expected_batch = interface::batch(mint_to.clone()).unwrap();
+    instructions.append(&mut mint_to);
+
+    let batched = batch_instructions(instructions.clone(), &spl_token_interface::ID);
+
+    // Only the run of two `MintToChecked` instructions is batched; the single
+    // `InitializeMint2` is left as it is.
+    assert_eq!(batched.len(), instructions.len() - 1);
+    assert_eq!(
+        batched[..batched.len() - 1],
+        instructions[..instructions.len() - 2]
+    );
+    assert_eq!(batched.last(), Some(&expected_batch));
+
+    // Nothing to batch for a different program.
+    assert_eq!(
+        batch_instructions(instructions.clone(), &Pubkey::new_unique()),
+        instructions
+    );
+}
+
+fn create_associated_token_idempotent(
+    payer: &Pubkey,
+    ata: &Pubkey,
+    owner: &Pubkey,
+    mint: &Pubkey,
+) -> solana_instruction::Instruction {
+    let instruction = CreateAssociatedTokenIdempotentBuilder::new()
+        .payer(*payer)
+        .ata(*ata)
+        .owner(*owner)
+        .mint(*mint)
+        .instruction();
+
+    assert_eq!(instruction.program_id, ASSOCIATED_TOKEN_ID);
+    assert_eq!(
+        instruction.accounts,
+        vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new(*ata, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new_readonly(solana_system_interface::program::ID, false),
+            AccountMeta::new_readonly(spl_token_interface::ID, false),
+        ]
+    );
+    assert_eq!(instruction.data, vec![1]);
+
+    instruction
+}
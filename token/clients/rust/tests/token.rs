@@ -0,0 +1,159 @@
+//! Tests the generated client against the token program.
+
+use {
+    mollusk_svm::{result::Check, Mollusk},
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_client::{
+        accounts::{Mint, Token},
+        errors::TokenError,
+        instructions::{
+            InitializeAccount3Builder, InitializeMint2Builder, MintToCheckedBuilder,
+            TransferCheckedBuilder,
+        },
+        types::AccountState,
+    },
+};
+
+fn mollusk() -> Mollusk {
+    Mollusk::new(&spl_token_client::ID, "spl_token")
+}
+
+fn uninitialized_account(len: usize) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(len),
+        data: vec![0; len],
+        owner: spl_token_client::ID,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn mint_and_transfer() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let initialize_mint = InitializeMint2Builder::new()
+        .mint(mint)
+        .decimals(6)
+        .mint_authority(mint_authority)
+        .instruction();
+    let initialize_source = InitializeAccount3Builder::new()
+        .account(source)
+        .mint(mint)
+        .owner(owner)
+        .instruction();
+    let initialize_destination = InitializeAccount3Builder::new()
+        .account(destination)
+        .mint(mint)
+        .owner(owner)
+        .instruction();
+    let mint_to = MintToCheckedBuilder::new()
+        .mint(mint)
+        .token(source)
+        .mint_authority(mint_authority, true)
+        .amount(1_000)
+        .decimals(6)
+        .instruction();
+    let transfer = TransferCheckedBuilder::new()
+        .source(source)
+        .mint(mint)
+        .destination(destination)
+        .authority(owner, true)
+        .amount(400)
+        .decimals(6)
+        .instruction();
+
+    let result = mollusk().process_and_validate_instruction_chain(
+        &[
+            (&initialize_mint, &[Check::success()]),
+            (&initialize_source, &[Check::success()]),
+            (&initialize_destination, &[Check::success()]),
+            (&mint_to, &[Check::success()]),
+            (&transfer, &[Check::success()]),
+        ],
+        &[
+            (mint, uninitialized_account(Mint::LEN)),
+            (source, uninitialized_account(Token::LEN)),
+            (destination, uninitialized_account(Token::LEN)),
+            (mint_authority, Account::default()),
+            (owner, Account::default()),
+        ],
+    );
+
+    let mint_account = Mint::from_bytes(&result.get_account(&mint).unwrap().data).unwrap();
+    assert_eq!(mint_account.mint_authority, Some(mint_authority));
+    assert_eq!(mint_account.supply, 1_000);
+    assert_eq!(mint_account.decimals, 6);
+    assert!(mint_account.is_initialized);
+    assert_eq!(mint_account.freeze_authority, None);
+
+    let source_account = Token::from_bytes(&result.get_account(&source).unwrap().data).unwrap();
+    assert_eq!(source_account.mint, mint);
+    assert_eq!(source_account.owner, owner);
+    assert_eq!(source_account.amount, 600);
+    assert_eq!(source_account.state, AccountState::Initialized);
+
+    let destination_account =
+        Token::from_bytes(&result.get_account(&destination).unwrap().data).unwrap();
+    assert_eq!(destination_account.amount, 400);
+}
+
+#[test]
+fn transfer_insufficient_funds() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Pubkey::new_unique();
+    let owner = Pubkey::new_unique();
+    let source = Pubkey::new_unique();
+    let destination = Pubkey::new_unique();
+
+    let initialize_mint = InitializeMint2Builder::new()
+        .mint(mint)
+        .decimals(0)
+        .mint_authority(mint_authority)
+        .instruction();
+    let initialize_source = InitializeAccount3Builder::new()
+        .account(source)
+        .mint(mint)
+        .owner(owner)
+        .instruction();
+    let initialize_destination = InitializeAccount3Builder::new()
+        .account(destination)
+        .mint(mint)
+        .owner(owner)
+        .instruction();
+    let transfer = TransferCheckedBuilder::new()
+        .source(source)
+        .mint(mint)
+        .destination(destination)
+        .authority(owner, true)
+        .amount(1)
+        .decimals(0)
+        .instruction();
+
+    mollusk().process_and_validate_instruction_chain(
+        &[
+            (&initialize_mint, &[Check::success()]),
+            (&initialize_source, &[Check::success()]),
+            (&initialize_destination, &[Check::success()]),
+            (
+                &transfer,
+                &[Check::err(ProgramError::from(
+                    TokenError::InsufficientFunds,
+                ))],
+            ),
+        ],
+        &[
+            (mint, uninitialized_account(Mint::LEN)),
+            (source, uninitialized_account(Token::LEN)),
+            (destination, uninitialized_account(Token::LEN)),
+            (mint_authority, Account::default()),
+            (owner, Account::default()),
+        ],
+    );
+}
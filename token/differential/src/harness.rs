@@ -0,0 +1,177 @@
+use {
+    crate::{operation::referenced_keys, Case},
+    mollusk_svm::{
+        fuzz::mollusk::build_fixture_from_mollusk_test, result::InstructionResult, Mollusk,
+    },
+    mollusk_svm_fuzz_fs::FsHandler,
+    solana_account::Account,
+    solana_instruction::Instruction,
+    solana_pubkey::Pubkey,
+    std::{fmt, path::Path},
+};
+
+/// Name of the SPL Token program file.
+pub const SPL_TOKEN: &str = "spl_token";
+
+/// Name of the p-token program file.
+pub const P_TOKEN: &str = "pinocchio_token_program";
+
+/// Executes cases against both programs.
+///
+/// Both programs are loaded at the token program address from the directories
+/// searched by Mollusk (e.g., `SBF_OUT_DIR`).
+pub struct Harness {
+    spl_token: Mollusk,
+    p_token: Mollusk,
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Harness {
+    pub fn new() -> Self {
+        Self {
+            spl_token: Mollusk::new(&spl_token_interface::ID, SPL_TOKEN),
+            p_token: Mollusk::new(&spl_token_interface::ID, P_TOKEN),
+        }
+    }
+
+    /// Executes the operations of a case against both programs.
+    ///
+    /// Each operation is processed as a separate instruction on top of the
+    /// accounts produced by the previous one, so failed operations leave the
+    /// state untouched. Returns the first divergence found.
+    pub fn execute(&self, case: &Case) -> Result<(), Box<Divergence>> {
+        let mut state = case.state.accounts();
+        let rent_sysvar = self.spl_token.sysvars.keyed_account_for_rent_sysvar();
+
+        for (position, operation) in case.operations.iter().enumerate() {
+            let instruction = operation.instruction();
+            let accounts = referenced_keys(&instruction)
+                .into_iter()
+                .map(|key| {
+                    state
+                        .iter()
+                        .chain(std::iter::once(&rent_sysvar))
+                        .find(|(address, _)| *address == key)
+                        .cloned()
+                        .expect("account")
+                })
+                .collect::<Vec<_>>();
+
+            let spl_token = self.spl_token.process_instruction(&instruction, &accounts);
+            let p_token = self.p_token.process_instruction(&instruction, &accounts);
+
+            if let Some(reason) = compare(&spl_token, &p_token) {
+                return Err(Box::new(Divergence {
+                    position,
+                    instruction,
+                    accounts,
+                    spl_token,
+                    p_token,
+                    reason,
+                }));
+            }
+
+            for (key, account) in spl_token.resulting_accounts {
+                if let Some((_, current)) = state.iter_mut().find(|(address, _)| *address == key) {
+                    *current = account;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns a description of the first difference between the results, if
+/// any.
+fn compare(spl_token: &InstructionResult, p_token: &InstructionResult) -> Option<String> {
+    if spl_token.program_result != p_token.program_result {
+        return Some(format!(
+            "program result: spl_token {:?}, p-token {:?}",
+            spl_token.program_result, p_token.program_result
+        ));
+    }
+
+    if spl_token.return_data != p_token.return_data {
+        return Some(format!(
+            "return data: spl_token {:?}, p-token {:?}",
+            spl_token.return_data, p_token.return_data
+        ));
+    }
+
+    for ((key, expected), (_, actual)) in spl_token
+        .resulting_accounts
+        .iter()
+        .zip(p_token.resulting_accounts.iter())
+    {
+        if expected.lamports != actual.lamports {
+            return Some(format!(
+                "lamports of {key}: spl_token {}, p-token {}",
+                expected.lamports, actual.lamports
+            ));
+        }
+        if expected.owner != actual.owner {
+            return Some(format!(
+                "owner of {key}: spl_token {}, p-token {}",
+                expected.owner, actual.owner
+            ));
+        }
+        if expected.data != actual.data {
+            return Some(format!(
+                "data of {key}: spl_token {:?}, p-token {:?}",
+                expected.data, actual.data
+            ));
+        }
+    }
+
+    None
+}
+
+/// A difference between the results of both programs for the same
+/// instruction.
+#[derive(Debug)]
+pub struct Divergence {
+    /// Position of the operation in the case.
+    pub position: usize,
+    /// The instruction that diverged.
+    pub instruction: Instruction,
+    /// The accounts provided to the instruction.
+    pub accounts: Vec<(Pubkey, Account)>,
+    /// The result from SPL Token.
+    pub spl_token: InstructionResult,
+    /// The result from p-token.
+    pub p_token: InstructionResult,
+    /// Description of the difference.
+    pub reason: String,
+}
+
+impl Divergence {
+    /// Saves the diverging instruction as a fuzz fixture in `dir`.
+    ///
+    /// The fixture expects the SPL Token result, so replaying it against
+    /// p-token reproduces the divergence.
+    pub fn save_fixture(&self, harness: &Harness, dir: &Path) {
+        let fixture = build_fixture_from_mollusk_test(
+            &harness.spl_token,
+            &self.instruction,
+            &self.accounts,
+            &self.spl_token,
+        );
+        FsHandler::new(fixture).dump_to_blob_file(&dir.to_string_lossy());
+    }
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "operation {} diverged ({}), instruction data {:?}",
+            self.position, self.reason, self.instruction.data
+        )
+    }
+}
@@ -0,0 +1,34 @@
+//! Differential testing between SPL Token and p-token.
+//!
+//! Both programs are expected to behave identically for instructions `0`
+//! to `24`. This crate generates random account states and instruction
+//! sequences, executes them against `spl_token` and `pinocchio_token_program`
+//! using Mollusk, and reports any difference in the resulting account data,
+//! lamports, owners, return data or error codes.
+
+mod harness;
+mod operation;
+mod state;
+
+pub use {harness::*, operation::*, state::*};
+
+use proptest::{collection::vec, prelude::Strategy};
+
+/// Maximum number of operations in a generated case.
+pub const MAX_OPERATIONS: usize = 8;
+
+/// A differential test case: an initial set of accounts and the sequence of
+/// operations executed on top of it.
+#[derive(Clone, Debug)]
+pub struct Case {
+    /// Initial state of the accounts.
+    pub state: State,
+    /// Operations to execute, in order.
+    pub operations: Vec<Operation>,
+}
+
+/// Strategy for generating differential test cases.
+pub fn case() -> impl Strategy<Value = Case> {
+    (state(), vec(operation(), 1..=MAX_OPERATIONS))
+        .prop_map(|(state, operations)| Case { state, operations })
+}
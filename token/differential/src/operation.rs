@@ -0,0 +1,771 @@
+use {
+    crate::state::{amount, authority_index, decimals, key, FIRST_TOKEN_ACCOUNT, KEYS, MULTISIG},
+    proptest::{
+        collection::vec,
+        option,
+        prelude::{any, prop, prop_oneof, Just, Strategy},
+    },
+    solana_instruction::{AccountMeta, Instruction},
+    solana_pubkey::Pubkey,
+    solana_sdk_ids::sysvar::rent::ID as RENT_ID,
+    spl_token_interface::instruction::{AuthorityType, TokenInstruction},
+};
+
+/// Authority of an operation.
+///
+/// When the authority is the multisig, `signers` holds the indices of the
+/// signer accounts appended to the instruction.
+#[derive(Clone, Debug)]
+pub struct Authority {
+    pub index: u8,
+    pub is_signer: bool,
+    pub signers: Vec<u8>,
+}
+
+impl Authority {
+    fn metas(&self) -> Vec<AccountMeta> {
+        let mut metas = vec![AccountMeta::new_readonly(key(self.index), self.is_signer)];
+        if self.index == MULTISIG {
+            metas.extend(
+                self.signers
+                    .iter()
+                    .map(|signer| AccountMeta::new_readonly(key(*signer), true)),
+            );
+        }
+        metas
+    }
+}
+
+/// A token instruction with its accounts referenced by index.
+#[derive(Clone, Debug)]
+pub enum Operation {
+    InitializeMint {
+        mint: u8,
+        decimals: u8,
+        mint_authority: u8,
+        freeze_authority: Option<u8>,
+    },
+    InitializeAccount {
+        account: u8,
+        mint: u8,
+        owner: u8,
+    },
+    InitializeMultisig {
+        multisig: u8,
+        signers: Vec<u8>,
+        m: u8,
+    },
+    Transfer {
+        source: u8,
+        destination: u8,
+        authority: Authority,
+        amount: u64,
+    },
+    Approve {
+        source: u8,
+        delegate: u8,
+        owner: Authority,
+        amount: u64,
+    },
+    Revoke {
+        source: u8,
+        owner: Authority,
+    },
+    SetAuthority {
+        owned: u8,
+        authority_type: AuthorityType,
+        new_authority: Option<u8>,
+        owner: Authority,
+    },
+    MintTo {
+        mint: u8,
+        account: u8,
+        authority: Authority,
+        amount: u64,
+    },
+    Burn {
+        account: u8,
+        mint: u8,
+        authority: Authority,
+        amount: u64,
+    },
+    CloseAccount {
+        account: u8,
+        destination: u8,
+        owner: Authority,
+    },
+    FreezeAccount {
+        account: u8,
+        mint: u8,
+        owner: Authority,
+    },
+    ThawAccount {
+        account: u8,
+        mint: u8,
+        owner: Authority,
+    },
+    TransferChecked {
+        source: u8,
+        mint: u8,
+        destination: u8,
+        authority: Authority,
+        amount: u64,
+        decimals: u8,
+    },
+    ApproveChecked {
+        source: u8,
+        mint: u8,
+        delegate: u8,
+        owner: Authority,
+        amount: u64,
+        decimals: u8,
+    },
+    MintToChecked {
+        mint: u8,
+        account: u8,
+        authority: Authority,
+        amount: u64,
+        decimals: u8,
+    },
+    BurnChecked {
+        account: u8,
+        mint: u8,
+        authority: Authority,
+        amount: u64,
+        decimals: u8,
+    },
+    InitializeAccount2 {
+        account: u8,
+        mint: u8,
+        owner: u8,
+    },
+    SyncNative {
+        account: u8,
+    },
+    InitializeAccount3 {
+        account: u8,
+        mint: u8,
+        owner: u8,
+    },
+    InitializeMultisig2 {
+        multisig: u8,
+        signers: Vec<u8>,
+        m: u8,
+    },
+    InitializeMint2 {
+        mint: u8,
+        decimals: u8,
+        mint_authority: u8,
+        freeze_authority: Option<u8>,
+    },
+    GetAccountDataSize {
+        mint: u8,
+    },
+    InitializeImmutableOwner {
+        account: u8,
+    },
+    AmountToUiAmount {
+        mint: u8,
+        amount: u64,
+    },
+    UiAmountToAmount {
+        mint: u8,
+        ui_amount: String,
+    },
+}
+
+impl Operation {
+    /// Builds the instruction for the operation.
+    pub fn instruction(&self) -> Instruction {
+        let (data, accounts) = match self {
+            Self::InitializeMint {
+                mint,
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => (
+                TokenInstruction::InitializeMint {
+                    decimals: *decimals,
+                    mint_authority: key(*mint_authority),
+                    freeze_authority: freeze_authority.map(key).into(),
+                },
+                vec![
+                    AccountMeta::new(key(*mint), false),
+                    AccountMeta::new_readonly(RENT_ID, false),
+                ],
+            ),
+            Self::InitializeAccount {
+                account,
+                mint,
+                owner,
+            } => (
+                TokenInstruction::InitializeAccount,
+                vec![
+                    AccountMeta::new(key(*account), false),
+                    AccountMeta::new_readonly(key(*mint), false),
+                    AccountMeta::new_readonly(key(*owner), false),
+                    AccountMeta::new_readonly(RENT_ID, false),
+                ],
+            ),
+            Self::InitializeMultisig {
+                multisig,
+                signers,
+                m,
+            } => {
+                let mut accounts = vec![
+                    AccountMeta::new(key(*multisig), false),
+                    AccountMeta::new_readonly(RENT_ID, false),
+                ];
+                accounts.extend(signer_metas(signers));
+                (TokenInstruction::InitializeMultisig { m: *m }, accounts)
+            }
+            Self::Transfer {
+                source,
+                destination,
+                authority,
+                amount,
+            } => (
+                TokenInstruction::Transfer { amount: *amount },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*source), false),
+                        AccountMeta::new(key(*destination), false),
+                    ],
+                    authority,
+                ),
+            ),
+            Self::Approve {
+                source,
+                delegate,
+                owner,
+                amount,
+            } => (
+                TokenInstruction::Approve { amount: *amount },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*source), false),
+                        AccountMeta::new_readonly(key(*delegate), false),
+                    ],
+                    owner,
+                ),
+            ),
+            Self::Revoke { source, owner } => (
+                TokenInstruction::Revoke,
+                with_authority(vec![AccountMeta::new(key(*source), false)], owner),
+            ),
+            Self::SetAuthority {
+                owned,
+                authority_type,
+                new_authority,
+                owner,
+            } => (
+                TokenInstruction::SetAuthority {
+                    authority_type: authority_type.clone(),
+                    new_authority: new_authority.map(key).into(),
+                },
+                with_authority(vec![AccountMeta::new(key(*owned), false)], owner),
+            ),
+            Self::MintTo {
+                mint,
+                account,
+                authority,
+                amount,
+            } => (
+                TokenInstruction::MintTo { amount: *amount },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*mint), false),
+                        AccountMeta::new(key(*account), false),
+                    ],
+                    authority,
+                ),
+            ),
+            Self::Burn {
+                account,
+                mint,
+                authority,
+                amount,
+            } => (
+                TokenInstruction::Burn { amount: *amount },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*account), false),
+                        AccountMeta::new(key(*mint), false),
+                    ],
+                    authority,
+                ),
+            ),
+            Self::CloseAccount {
+                account,
+                destination,
+                owner,
+            } => (
+                TokenInstruction::CloseAccount,
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*account), false),
+                        AccountMeta::new(key(*destination), false),
+                    ],
+                    owner,
+                ),
+            ),
+            Self::FreezeAccount {
+                account,
+                mint,
+                owner,
+            } => (
+                TokenInstruction::FreezeAccount,
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*account), false),
+                        AccountMeta::new_readonly(key(*mint), false),
+                    ],
+                    owner,
+                ),
+            ),
+            Self::ThawAccount {
+                account,
+                mint,
+                owner,
+            } => (
+                TokenInstruction::ThawAccount,
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*account), false),
+                        AccountMeta::new_readonly(key(*mint), false),
+                    ],
+                    owner,
+                ),
+            ),
+            Self::TransferChecked {
+                source,
+                mint,
+                destination,
+                authority,
+                amount,
+                decimals,
+            } => (
+                TokenInstruction::TransferChecked {
+                    amount: *amount,
+                    decimals: *decimals,
+                },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*source), false),
+                        AccountMeta::new_readonly(key(*mint), false),
+                        AccountMeta::new(key(*destination), false),
+                    ],
+                    authority,
+                ),
+            ),
+            Self::ApproveChecked {
+                source,
+                mint,
+                delegate,
+                owner,
+                amount,
+                decimals,
+            } => (
+                TokenInstruction::ApproveChecked {
+                    amount: *amount,
+                    decimals: *decimals,
+                },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*source), false),
+                        AccountMeta::new_readonly(key(*mint), false),
+                        AccountMeta::new_readonly(key(*delegate), false),
+                    ],
+                    owner,
+                ),
+            ),
+            Self::MintToChecked {
+                mint,
+                account,
+                authority,
+                amount,
+                decimals,
+            } => (
+                TokenInstruction::MintToChecked {
+                    amount: *amount,
+                    decimals: *decimals,
+                },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*mint), false),
+                        AccountMeta::new(key(*account), false),
+                    ],
+                    authority,
+                ),
+            ),
+            Self::BurnChecked {
+                account,
+                mint,
+                authority,
+                amount,
+                decimals,
+            } => (
+                TokenInstruction::BurnChecked {
+                    amount: *amount,
+                    decimals: *decimals,
+                },
+                with_authority(
+                    vec![
+                        AccountMeta::new(key(*account), false),
+                        AccountMeta::new(key(*mint), false),
+                    ],
+                    authority,
+                ),
+            ),
+            Self::InitializeAccount2 {
+                account,
+                mint,
+                owner,
+            } => (
+                TokenInstruction::InitializeAccount2 { owner: key(*owner) },
+                vec![
+                    AccountMeta::new(key(*account), false),
+                    AccountMeta::new_readonly(key(*mint), false),
+                    AccountMeta::new_readonly(RENT_ID, false),
+                ],
+            ),
+            Self::SyncNative { account } => (
+                TokenInstruction::SyncNative,
+                vec![AccountMeta::new(key(*account), false)],
+            ),
+            Self::InitializeAccount3 {
+                account,
+                mint,
+                owner,
+            } => (
+                TokenInstruction::InitializeAccount3 { owner: key(*owner) },
+                vec![
+                    AccountMeta::new(key(*account), false),
+                    AccountMeta::new_readonly(key(*mint), false),
+                ],
+            ),
+            Self::InitializeMultisig2 {
+                multisig,
+                signers,
+                m,
+            } => {
+                let mut accounts = vec![AccountMeta::new(key(*multisig), false)];
+                accounts.extend(signer_metas(signers));
+                (TokenInstruction::InitializeMultisig2 { m: *m }, accounts)
+            }
+            Self::InitializeMint2 {
+                mint,
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => (
+                TokenInstruction::InitializeMint2 {
+                    decimals: *decimals,
+                    mint_authority: key(*mint_authority),
+                    freeze_authority: freeze_authority.map(key).into(),
+                },
+                vec![AccountMeta::new(key(*mint), false)],
+            ),
+            Self::GetAccountDataSize { mint } => (
+                TokenInstruction::GetAccountDataSize,
+                vec![AccountMeta::new_readonly(key(*mint), false)],
+            ),
+            Self::InitializeImmutableOwner { account } => (
+                TokenInstruction::InitializeImmutableOwner,
+                vec![AccountMeta::new(key(*account), false)],
+            ),
+            Self::AmountToUiAmount { mint, amount } => (
+                TokenInstruction::AmountToUiAmount { amount: *amount },
+                vec![AccountMeta::new_readonly(key(*mint), false)],
+            ),
+            Self::UiAmountToAmount { mint, ui_amount } => (
+                TokenInstruction::UiAmountToAmount { ui_amount },
+                vec![AccountMeta::new_readonly(key(*mint), false)],
+            ),
+        };
+
+        Instruction {
+            program_id: spl_token_interface::ID,
+            accounts,
+            data: data.pack(),
+        }
+    }
+}
+
+fn with_authority(mut accounts: Vec<AccountMeta>, authority: &Authority) -> Vec<AccountMeta> {
+    accounts.extend(authority.metas());
+    accounts
+}
+
+fn signer_metas(signers: &[u8]) -> impl Iterator<Item = AccountMeta> + '_ {
+    signers
+        .iter()
+        .map(|signer| AccountMeta::new_readonly(key(*signer), false))
+}
+
+/// Strategy for the index of any account in a case.
+fn any_account() -> impl Strategy<Value = u8> {
+    0..KEYS
+}
+
+/// Strategy for the index of a mint, occasionally replaced by any account.
+fn mint() -> impl Strategy<Value = u8> {
+    prop_oneof![
+        8 => 0..FIRST_TOKEN_ACCOUNT,
+        1 => any_account(),
+    ]
+}
+
+/// Strategy for the index of a token account, occasionally replaced by any
+/// account.
+fn token_account() -> impl Strategy<Value = u8> {
+    prop_oneof![
+        8 => FIRST_TOKEN_ACCOUNT..MULTISIG,
+        1 => any_account(),
+    ]
+}
+
+/// Strategy for the index of a multisig, occasionally replaced by any
+/// account.
+fn multisig() -> impl Strategy<Value = u8> {
+    prop_oneof![
+        4 => Just(MULTISIG),
+        1 => any_account(),
+    ]
+}
+
+fn signers() -> impl Strategy<Value = Vec<u8>> {
+    vec(authority_index(), 0..=4)
+}
+
+fn authority() -> impl Strategy<Value = Authority> {
+    (authority_index(), prop::bool::weighted(0.9), signers()).prop_map(
+        |(index, is_signer, signers)| Authority {
+            index,
+            is_signer,
+            signers,
+        },
+    )
+}
+
+fn ui_amount() -> impl Strategy<Value = String> {
+    prop_oneof![
+        8 => "[0-9]{0,6}(\\.[0-9]{0,12})?",
+        1 => ".{0,8}",
+    ]
+}
+
+/// Strategy for operations covering instructions `0` to `24`.
+pub fn operation() -> impl Strategy<Value = Operation> {
+    prop_oneof![
+        (
+            mint(),
+            decimals(),
+            authority_index(),
+            option::of(authority_index())
+        )
+            .prop_map(|(mint, decimals, mint_authority, freeze_authority)| {
+                Operation::InitializeMint {
+                    mint,
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                }
+            }),
+        (token_account(), mint(), authority_index()).prop_map(|(account, mint, owner)| {
+            Operation::InitializeAccount {
+                account,
+                mint,
+                owner,
+            }
+        }),
+        (multisig(), signers(), any::<u8>()).prop_map(|(multisig, signers, m)| {
+            Operation::InitializeMultisig {
+                multisig,
+                signers,
+                m: m % 5,
+            }
+        }),
+        (token_account(), token_account(), authority(), amount()).prop_map(
+            |(source, destination, authority, amount)| Operation::Transfer {
+                source,
+                destination,
+                authority,
+                amount,
+            }
+        ),
+        (token_account(), authority_index(), authority(), amount()).prop_map(
+            |(source, delegate, owner, amount)| Operation::Approve {
+                source,
+                delegate,
+                owner,
+                amount,
+            }
+        ),
+        (token_account(), authority())
+            .prop_map(|(source, owner)| Operation::Revoke { source, owner }),
+        (
+            prop_oneof![mint(), token_account()],
+            prop::sample::select(vec![
+                AuthorityType::MintTokens,
+                AuthorityType::FreezeAccount,
+                AuthorityType::AccountOwner,
+                AuthorityType::CloseAccount,
+            ]),
+            option::weighted(0.8, authority_index()),
+            authority(),
+        )
+            .prop_map(|(owned, authority_type, new_authority, owner)| {
+                Operation::SetAuthority {
+                    owned,
+                    authority_type,
+                    new_authority,
+                    owner,
+                }
+            }),
+        (mint(), token_account(), authority(), amount()).prop_map(
+            |(mint, account, authority, amount)| Operation::MintTo {
+                mint,
+                account,
+                authority,
+                amount,
+            }
+        ),
+        (token_account(), mint(), authority(), amount()).prop_map(
+            |(account, mint, authority, amount)| Operation::Burn {
+                account,
+                mint,
+                authority,
+                amount,
+            }
+        ),
+        (token_account(), any_account(), authority()).prop_map(|(account, destination, owner)| {
+            Operation::CloseAccount {
+                account,
+                destination,
+                owner,
+            }
+        }),
+        (token_account(), mint(), authority()).prop_map(|(account, mint, owner)| {
+            Operation::FreezeAccount {
+                account,
+                mint,
+                owner,
+            }
+        }),
+        (token_account(), mint(), authority()).prop_map(|(account, mint, owner)| {
+            Operation::ThawAccount {
+                account,
+                mint,
+                owner,
+            }
+        }),
+        (
+            token_account(),
+            mint(),
+            token_account(),
+            authority(),
+            amount(),
+            decimals()
+        )
+            .prop_map(|(source, mint, destination, authority, amount, decimals)| {
+                Operation::TransferChecked {
+                    source,
+                    mint,
+                    destination,
+                    authority,
+                    amount,
+                    decimals,
+                }
+            }),
+        (
+            token_account(),
+            mint(),
+            authority_index(),
+            authority(),
+            amount(),
+            decimals()
+        )
+            .prop_map(|(source, mint, delegate, owner, amount, decimals)| {
+                Operation::ApproveChecked {
+                    source,
+                    mint,
+                    delegate,
+                    owner,
+                    amount,
+                    decimals,
+                }
+            }),
+        (mint(), token_account(), authority(), amount(), decimals()).prop_map(
+            |(mint, account, authority, amount, decimals)| Operation::MintToChecked {
+                mint,
+                account,
+                authority,
+                amount,
+                decimals,
+            }
+        ),
+        (token_account(), mint(), authority(), amount(), decimals()).prop_map(
+            |(account, mint, authority, amount, decimals)| Operation::BurnChecked {
+                account,
+                mint,
+                authority,
+                amount,
+                decimals,
+            }
+        ),
+        (token_account(), mint(), authority_index()).prop_map(|(account, mint, owner)| {
+            Operation::InitializeAccount2 {
+                account,
+                mint,
+                owner,
+            }
+        }),
+        token_account().prop_map(|account| Operation::SyncNative { account }),
+        (token_account(), mint(), authority_index()).prop_map(|(account, mint, owner)| {
+            Operation::InitializeAccount3 {
+                account,
+                mint,
+                owner,
+            }
+        }),
+        (multisig(), signers(), any::<u8>()).prop_map(|(multisig, signers, m)| {
+            Operation::InitializeMultisig2 {
+                multisig,
+                signers,
+                m: m % 5,
+            }
+        }),
+        (
+            mint(),
+            decimals(),
+            authority_index(),
+            option::of(authority_index())
+        )
+            .prop_map(|(mint, decimals, mint_authority, freeze_authority)| {
+                Operation::InitializeMint2 {
+                    mint,
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                }
+            }),
+        mint().prop_map(|mint| Operation::GetAccountDataSize { mint }),
+        token_account().prop_map(|account| Operation::InitializeImmutableOwner { account }),
+        (mint(), amount()).prop_map(|(mint, amount)| Operation::AmountToUiAmount { mint, amount }),
+        (mint(), ui_amount())
+            .prop_map(|(mint, ui_amount)| Operation::UiAmountToAmount { mint, ui_amount }),
+    ]
+}
+
+/// Returns the addresses referenced by the instruction, without duplicates
+/// and in order of first appearance.
+pub fn referenced_keys(instruction: &Instruction) -> Vec<Pubkey> {
+    let mut keys: Vec<Pubkey> = Vec::with_capacity(instruction.accounts.len());
+    for meta in &instruction.accounts {
+        if !keys.contains(&meta.pubkey) {
+            keys.push(meta.pubkey);
+        }
+    }
+    keys
+}
@@ -0,0 +1,373 @@
+use {
+    proptest::{
+        option,
+        prelude::{any, prop, prop_oneof, Just, Strategy},
+    },
+    solana_account::Account,
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_interface::{
+        native_mint,
+        state::{Account as TokenAccount, AccountState, Mint, Multisig},
+    },
+};
+
+/// Number of non-native mints in a case.
+pub const MINTS: u8 = 2;
+
+/// Index of the native mint.
+pub const NATIVE_MINT: u8 = MINTS;
+
+/// Index of the first token account.
+pub const FIRST_TOKEN_ACCOUNT: u8 = NATIVE_MINT + 1;
+
+/// Number of token accounts in a case.
+pub const TOKEN_ACCOUNTS: u8 = 3;
+
+/// Index of the multisig account.
+pub const MULTISIG: u8 = FIRST_TOKEN_ACCOUNT + TOKEN_ACCOUNTS;
+
+/// Index of the first wallet, a system account that can act as an authority.
+pub const FIRST_WALLET: u8 = MULTISIG + 1;
+
+/// Number of wallets in a case.
+pub const WALLETS: u8 = 3;
+
+/// Total number of accounts in a case.
+pub const KEYS: u8 = FIRST_WALLET + WALLETS;
+
+/// Lamports held by each wallet.
+const WALLET_LAMPORTS: u64 = 1_000_000_000;
+
+/// Returns the address of the account at `index`.
+///
+/// Every case uses the same set of addresses, so operations refer to accounts
+/// by their index.
+pub fn key(index: u8) -> Pubkey {
+    if index == NATIVE_MINT {
+        native_mint::ID
+    } else {
+        Pubkey::new_from_array([index + 1; 32])
+    }
+}
+
+/// State of a mint account.
+#[derive(Clone, Debug)]
+pub struct MintState {
+    pub is_initialized: bool,
+    pub mint_authority: Option<u8>,
+    pub supply: u64,
+    pub decimals: u8,
+    pub freeze_authority: Option<u8>,
+    pub extra_lamports: u64,
+    pub token_owned: bool,
+}
+
+impl MintState {
+    fn account(&self) -> Account {
+        let mut data = vec![0; Mint::LEN];
+
+        if self.is_initialized {
+            Mint {
+                mint_authority: self.mint_authority.map(key).into(),
+                supply: self.supply,
+                decimals: self.decimals,
+                is_initialized: true,
+                freeze_authority: self.freeze_authority.map(key).into(),
+            }
+            .pack_into_slice(&mut data);
+        }
+
+        program_account(data, self.extra_lamports, self.token_owned)
+    }
+}
+
+/// State of a token account.
+#[derive(Clone, Debug)]
+pub struct TokenAccountState {
+    pub state: AccountState,
+    pub mint: u8,
+    pub owner: u8,
+    pub amount: u64,
+    pub delegate: Option<u8>,
+    pub delegated_amount: u64,
+    pub is_native: bool,
+    pub close_authority: Option<u8>,
+    pub extra_lamports: u64,
+    pub token_owned: bool,
+}
+
+impl TokenAccountState {
+    fn account(&self) -> Account {
+        let mut data = vec![0; TokenAccount::LEN];
+        let rent_exempt_reserve = Rent::default().minimum_balance(TokenAccount::LEN);
+        // Wrapped SOL accounts hold their token amount as lamports.
+        let is_native = self.is_native && self.mint == NATIVE_MINT;
+
+        if self.state != AccountState::Uninitialized {
+            TokenAccount {
+                mint: key(self.mint),
+                owner: key(self.owner),
+                amount: self.amount,
+                delegate: self.delegate.map(key).into(),
+                state: self.state,
+                is_native: if is_native {
+                    COption::Some(rent_exempt_reserve)
+                } else {
+                    COption::None
+                },
+                delegated_amount: self.delegated_amount,
+                close_authority: self.close_authority.map(key).into(),
+            }
+            .pack_into_slice(&mut data);
+        }
+
+        let extra_lamports = if is_native {
+            self.extra_lamports.saturating_add(self.amount)
+        } else {
+            self.extra_lamports
+        };
+
+        program_account(data, extra_lamports, self.token_owned)
+    }
+}
+
+/// State of the multisig account.
+///
+/// The signers of an initialized multisig are the first `n` wallets.
+#[derive(Clone, Debug)]
+pub struct MultisigState {
+    pub is_initialized: bool,
+    pub m: u8,
+    pub n: u8,
+    pub extra_lamports: u64,
+}
+
+impl MultisigState {
+    fn account(&self) -> Account {
+        let mut data = vec![0; Multisig::LEN];
+
+        if self.is_initialized {
+            let mut signers = [Pubkey::default(); 11];
+            (0..self.n).for_each(|i| signers[i as usize] = key(FIRST_WALLET + i));
+
+            Multisig {
+                m: self.m,
+                n: self.n,
+                is_initialized: true,
+                signers,
+            }
+            .pack_into_slice(&mut data);
+        }
+
+        program_account(data, self.extra_lamports, true)
+    }
+}
+
+/// Initial state of all accounts in a case.
+#[derive(Clone, Debug)]
+pub struct State {
+    pub mints: Vec<MintState>,
+    pub token_accounts: Vec<TokenAccountState>,
+    pub multisig: MultisigState,
+}
+
+impl State {
+    /// Returns the accounts of the state, ordered by their index.
+    pub fn accounts(&self) -> Vec<(Pubkey, Account)> {
+        let native_mint = MintState {
+            is_initialized: true,
+            mint_authority: None,
+            supply: 0,
+            decimals: native_mint::DECIMALS,
+            freeze_authority: None,
+            extra_lamports: 0,
+            token_owned: true,
+        };
+
+        self.mints
+            .iter()
+            .map(MintState::account)
+            .chain(std::iter::once(native_mint.account()))
+            .chain(self.token_accounts.iter().map(TokenAccountState::account))
+            .chain(std::iter::once(self.multisig.account()))
+            .chain((0..WALLETS).map(|_| Account::new(WALLET_LAMPORTS, 0, &Pubkey::default())))
+            .enumerate()
+            .map(|(index, account)| (key(index as u8), account))
+            .collect()
+    }
+}
+
+/// Creates an account owned by the token program (or by the system program
+/// when `token_owned` is `false`) holding the rent-exempt minimum balance
+/// plus `extra_lamports`.
+fn program_account(data: Vec<u8>, extra_lamports: u64, token_owned: bool) -> Account {
+    let lamports = Rent::default()
+        .minimum_balance(data.len())
+        .saturating_add(extra_lamports);
+    let owner = if token_owned {
+        spl_token_interface::ID
+    } else {
+        Pubkey::default()
+    };
+
+    Account {
+        lamports,
+        data,
+        owner,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+/// Strategy for token amounts, biased towards small values and the edges.
+pub fn amount() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        6 => 0..=1_000u64,
+        1 => Just(u64::MAX),
+        1 => any::<u64>(),
+    ]
+}
+
+/// Strategy for decimals, biased towards a few common values so that
+/// checked instructions match the mint decimals often enough.
+pub fn decimals() -> impl Strategy<Value = u8> {
+    prop_oneof![
+        6 => prop::sample::select(vec![0u8, 6, native_mint::DECIMALS]),
+        1 => any::<u8>(),
+    ]
+}
+
+/// Strategy for the index of an account that can act as an authority: the
+/// multisig or one of the wallets.
+pub fn authority_index() -> impl Strategy<Value = u8> {
+    MULTISIG..KEYS
+}
+
+/// Strategy for lamports above the rent-exempt minimum.
+fn extra_lamports() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        4 => Just(0u64),
+        1 => 1..=1_000_000u64,
+    ]
+}
+
+/// Strategy for whether an account is owned by the token program.
+fn token_owned() -> impl Strategy<Value = bool> {
+    prop::bool::weighted(0.95)
+}
+
+fn mint_state() -> impl Strategy<Value = MintState> {
+    (
+        prop::bool::weighted(0.9),
+        option::weighted(0.9, authority_index()),
+        amount(),
+        decimals(),
+        option::of(authority_index()),
+        extra_lamports(),
+        token_owned(),
+    )
+        .prop_map(
+            |(
+                is_initialized,
+                mint_authority,
+                supply,
+                decimals,
+                freeze_authority,
+                extra_lamports,
+                token_owned,
+            )| MintState {
+                is_initialized,
+                mint_authority,
+                supply,
+                decimals,
+                freeze_authority,
+                extra_lamports,
+                token_owned,
+            },
+        )
+}
+
+fn token_account_state() -> impl Strategy<Value = TokenAccountState> {
+    (
+        prop_oneof![
+            1 => Just(AccountState::Uninitialized),
+            8 => Just(AccountState::Initialized),
+            1 => Just(AccountState::Frozen),
+        ],
+        0..=NATIVE_MINT,
+        authority_index(),
+        amount(),
+        option::of(authority_index()),
+        amount(),
+        any::<bool>(),
+        option::weighted(0.3, authority_index()),
+        extra_lamports(),
+        token_owned(),
+    )
+        .prop_map(
+            |(
+                state,
+                mint,
+                owner,
+                amount,
+                delegate,
+                delegated_amount,
+                is_native,
+                close_authority,
+                extra_lamports,
+                token_owned,
+            )| {
+                // Keep wrapped SOL balances within the lamports supply.
+                let amount = if is_native && mint == NATIVE_MINT {
+                    amount % 1_000_000_000_000
+                } else {
+                    amount
+                };
+
+                TokenAccountState {
+                    state,
+                    mint,
+                    owner,
+                    amount,
+                    delegate,
+                    delegated_amount: if delegate.is_some() {
+                        delegated_amount
+                    } else {
+                        0
+                    },
+                    is_native,
+                    close_authority,
+                    extra_lamports,
+                    token_owned,
+                }
+            },
+        )
+}
+
+fn multisig_state() -> impl Strategy<Value = MultisigState> {
+    (any::<bool>(), 1..=WALLETS, 0..WALLETS, extra_lamports()).prop_map(
+        |(is_initialized, n, m, extra_lamports)| MultisigState {
+            is_initialized,
+            m: m % n + 1,
+            n,
+            extra_lamports,
+        },
+    )
+}
+
+/// Strategy for the initial state of a case.
+pub fn state() -> impl Strategy<Value = State> {
+    (
+        prop::collection::vec(mint_state(), MINTS as usize),
+        prop::collection::vec(token_account_state(), TOKEN_ACCOUNTS as usize),
+        multisig_state(),
+    )
+        .prop_map(|(mints, token_accounts, multisig)| State {
+            mints,
+            token_accounts,
+            multisig,
+        })
+}
@@ -0,0 +1,46 @@
+use {
+    proptest::test_runner::{Config, TestCaseError, TestError, TestRunner},
+    std::path::PathBuf,
+    token_differential::{case, Harness},
+};
+
+/// Directory where fixtures for divergences are saved, unless overridden
+/// by the `DIVERGENCE_FIXTURES_DIR` environment variable.
+const DIVERGENCE_FIXTURES_DIR: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../target/fixtures/divergences"
+);
+
+#[test]
+fn spl_token_and_p_token_are_equivalent() {
+    let harness = Harness::new();
+    // The number of cases can be configured with `PROPTEST_CASES`.
+    let mut runner = TestRunner::new(Config::default());
+
+    let result = runner.run(&case(), |case| {
+        harness
+            .execute(&case)
+            .map_err(|divergence| TestCaseError::fail(divergence.to_string()))
+    });
+
+    match result {
+        Ok(()) => (),
+        Err(TestError::Fail(_, minimal)) => {
+            // Re-run the shrunk case to recover the diverging instruction.
+            let divergence = harness
+                .execute(&minimal)
+                .expect_err("minimal case should diverge");
+
+            let dir = std::env::var("DIVERGENCE_FIXTURES_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|_| PathBuf::from(DIVERGENCE_FIXTURES_DIR));
+            divergence.save_fixture(&harness, &dir);
+
+            panic!(
+                "{divergence}\nminimal case: {minimal:#?}\nfixture saved to {}",
+                dir.display()
+            );
+        }
+        Err(TestError::Abort(reason)) => panic!("differential test aborted: {reason}"),
+    }
+}
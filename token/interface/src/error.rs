@@ -77,6 +77,17 @@ pub enum TokenError {
     /// Instruction does not support non-native tokens
     #[error("Instruction does not support non-native tokens")]
     NonNativeNotSupported,
+
+    // 20
+    /// Delegation has expired.
+    #[error("Delegation has expired")]
+    DelegationExpired,
+    /// Transfer into the account requires a memo in the previous instruction.
+    #[error("Transfer into the account requires a memo in the previous instruction")]
+    NoMemo,
+    /// Mint is paused.
+    #[error("Mint is paused")]
+    MintPaused,
 }
 impl From<TokenError> for ProgramError {
     fn from(e: TokenError) -> Self {
@@ -108,6 +119,9 @@ impl TryFrom<u32> for TokenError {
             17 => Ok(TokenError::AccountFrozen),
             18 => Ok(TokenError::MintDecimalsMismatch),
             19 => Ok(TokenError::NonNativeNotSupported),
+            20 => Ok(TokenError::DelegationExpired),
+            21 => Ok(TokenError::NoMemo),
+            22 => Ok(TokenError::MintPaused),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -146,6 +160,11 @@ impl ToStr for TokenError {
             TokenError::NonNativeNotSupported => {
                 "Error: Instruction does not support non-native tokens"
             }
+            TokenError::DelegationExpired => "Error: Delegation has expired",
+            TokenError::NoMemo => {
+                "Error: Transfer into the account requires a memo in the previous instruction"
+            }
+            TokenError::MintPaused => "Error: Mint is paused",
         }
     }
 }
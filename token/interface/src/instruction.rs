@@ -1,7 +1,7 @@
 //! Instruction types
 
 use {
-    crate::{check_program_account, error::TokenError},
+    crate::{check_program_account, error::TokenError, state::Expiry},
     solana_instruction::{AccountMeta, Instruction},
     solana_program_error::ProgramError,
     solana_program_option::COption,
@@ -16,6 +16,8 @@ pub const MIN_SIGNERS: usize = 1;
 pub const MAX_SIGNERS: usize = 11;
 /// Serialized length of a `u64`, for unpacking
 const U64_BYTES: usize = 8;
+/// Instruction discriminator of a `Batch`
+const BATCH_DISCRIMINATOR: u8 = 255;
 
 /// Instructions supported by the token program.
 #[repr(C)]
@@ -90,6 +92,13 @@ pub enum TokenInstruction<'a> {
     /// amounts of SOL and Tokens will be transferred to the destination
     /// account.
     ///
+    /// If the destination account requires memos, the instruction preceding
+    /// the top-level instruction in the transaction must be a memo and the
+    /// Instructions sysvar must be provided as the last account. Through a
+    /// cross-program invocation, the memo must precede the instruction of the
+    /// calling program; inside a `Batch` the transfer fails, since the memo
+    /// could not be attributed to it.
+    ///
     /// Accounts expected by this instruction:
     ///
     ///   * Single owner/delegate
@@ -251,6 +260,13 @@ pub enum TokenInstruction<'a> {
     /// decimals value is checked by the caller.  This may be useful when
     /// creating transactions offline or within a hardware wallet.
     ///
+    /// If the destination account requires memos, the instruction preceding
+    /// the top-level instruction in the transaction must be a memo and the
+    /// Instructions sysvar must be provided as the last account. Through a
+    /// cross-program invocation, the memo must precede the instruction of the
+    /// calling program; inside a `Batch` the transfer fails, since the memo
+    /// could not be attributed to it.
+    ///
     /// Accounts expected by this instruction:
     ///
     ///   * Single owner/delegate
@@ -467,6 +483,291 @@ pub enum TokenInstruction<'a> {
         /// The `ui_amount` of tokens to reformat.
         ui_amount: &'a str,
     },
+    /// This instruction is to be used to rescue SOL sent to any `TokenProgram`
+    /// owned account by sending them to any other account, leaving behind only
+    /// lamports for rent exemption.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` Source Account owned by the token program
+    ///   1. `[writable]` Destination account
+    ///   2. `[signer]` Authority
+    ///   3. `..+M` `[signer]` M signer accounts.
+    WithdrawExcessLamports,
+    /// Transfer lamports from a native SOL account to a destination account.
+    ///
+    /// This is useful to unwrap lamports from a wrapped SOL account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The source account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[]` The source account's multisignature owner.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    UnwrapLamports {
+        /// The amount of lamports to transfer. When an amount is not
+        /// specified, the entire balance of the source account will be
+        /// transferred.
+        amount: Option<u64>,
+    },
+    /// Transfers tokens from one account to several destination accounts,
+    /// validating the source account's owner or delegate only once.
+    ///
+    /// The destination accounts must be of the same mint as the source
+    /// account. The ownership of all token accounts is validated explicitly.
+    ///
+    /// Each transfer into a destination account that requires memos needs its
+    /// own memo: one memo instruction per such transfer must immediately
+    /// precede the top-level instruction in the transaction, and the
+    /// Instructions sysvar must be provided as the last account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[signer]` The source account's owner/delegate.
+    ///   2. ..`2+N` `[writable]` The N destination accounts.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The source account's multisignature owner/delegate.
+    ///   2. ..`2+N` `[writable]` The N destination accounts.
+    ///   3. ..`2+N+M` `[signer]` M signer accounts.
+    TransferMany {
+        /// The amounts of tokens to transfer to each destination account.
+        amounts: Vec<u64>,
+    },
+    /// Burns the remaining balance of a token account and closes it,
+    /// transferring all its SOL to the destination account.
+    ///
+    /// The authority must be the account's close authority, which must also
+    /// be the account's owner when the account has a non-zero balance.
+    /// Accounts owned by the system program or the incinerator can only be
+    /// closed to the incinerator, without an authority check.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The account to burn from and close.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The account's close authority.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The account to burn from and close.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The account's multisignature close authority.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    BurnAndClose,
+    /// Transfers the whole balance of a token account to a destination
+    /// account of the same mint and closes it, transferring all its SOL to
+    /// the recipient account.
+    ///
+    /// The authority must be able to both transfer the tokens and close the
+    /// account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[writable]` The recipient of the source account's SOL.
+    ///   3. `[signer]` The source account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[writable]` The recipient of the source account's SOL.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    SweepAndClose,
+    /// Approves a delegate until the given expiry. The delegation account is
+    /// set as the delegate of the source account and records the actual
+    /// delegate together with the expiry.
+    ///
+    /// To use the delegation, the delegate provides the delegation account
+    /// as the authority, followed by the delegate's signer account(s). Once
+    /// the expiry is reached, the delegation can no longer be used.
+    ///
+    /// The delegation account must be owned by the token program and have
+    /// enough lamports to be rent exempt. It can be reused to approve the
+    /// same source account again and closed with `CloseDelegation`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation account.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation account.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts
+    ApproveWithExpiry {
+        /// The amount of tokens the delegate is approved for.
+        amount: u64,
+        /// The expiry of the delegation.
+        expiry: Expiry,
+    },
+    /// Approves a delegate with a recurring spending limit. The spending limit
+    /// account is set as the delegate of the source account and records the
+    /// actual delegate together with the amount of tokens it can spend per
+    /// epoch.
+    ///
+    /// To use the spending limit, the delegate provides the spending limit
+    /// account as the (writable) authority, followed by the delegate's signer
+    /// account(s). Transfers and burns are checked against the amount left in
+    /// the current epoch, which is refilled at the start of each epoch;
+    /// transfers to the source account itself do not consume it.
+    ///
+    /// The spending limit account must be owned by the token program and have
+    /// enough lamports to be rent exempt. It can be reused to approve the
+    /// same source account again, which resets the spent amount, and closed
+    /// with `CloseDelegation`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The spending limit account.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The spending limit account.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts
+    ApproveWithSpendingLimit {
+        /// The amount of tokens the delegate can spend per epoch.
+        limit: u64,
+    },
+    /// Sets whether transfers into a token account require a memo. When
+    /// required, `Transfer`, `TransferChecked` and `TransferMany` into the
+    /// account fail unless each transfer is preceded by its own memo
+    /// instruction and is not part of a `Batch`.
+    ///
+    /// Native accounts do not support the memo requirement.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The account to update.
+    ///   1. `[signer]` The account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The account to update.
+    ///   1. `[]` The account's multisignature owner.
+    ///   2. ..`2+M` `[signer]` M signer accounts.
+    SetMemoRequired {
+        /// Whether incoming transfers require a memo.
+        required: bool,
+    },
+    /// Pauses a mint using the mint's freeze authority. While a mint is
+    /// paused, `TransferChecked`, `MintTo`, `MintToChecked`, `Burn`,
+    /// `BurnChecked`, `BurnAndClose` and `ApproveChecked` on its accounts
+    /// fail.
+    ///
+    /// Instructions that do not take the mint account, such as `Transfer`
+    /// and `Approve`, cannot observe the pause.
+    ///
+    /// The freeze authority of the mint is recorded in the mint pause
+    /// account and replaced by `PAUSED_MINT_FREEZE_AUTHORITY`, so the mint
+    /// keeps its layout. Until the mint is resumed, `FreezeAccount`,
+    /// `ThawAccount` and `SetAuthority` on the freeze authority fail.
+    ///
+    /// The mint pause account must be owned by the token program and have
+    /// enough lamports to be rent exempt.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The mint to pause.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[signer]` The mint freeze authority.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The mint to pause.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[]` The mint's multisignature freeze authority.
+    ///   3. ..`3+M` `[signer]` M signer accounts.
+    PauseMint,
+    /// Resumes a paused mint using the freeze authority recorded in its mint
+    /// pause account, which is restored as the freeze authority of the mint.
+    /// The mint pause account is closed and its lamports are transferred to
+    /// the destination account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The mint to resume.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The mint freeze authority.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The mint to resume.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The mint's multisignature freeze authority.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    ResumeMint,
+    /// Closes a delegation or spending limit account of a token account,
+    /// transferring its lamports to the destination account. When the closed
+    /// account is the delegate of the token account, the delegation is also
+    /// revoked.
+    ///
+    /// Only the owner of the token account can close the delegation or
+    /// spending limit account, so it should be closed before the token
+    /// account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation or spending limit account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation or spending limit account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    CloseDelegation,
+    /// Executes a batch of instructions. The instructions to be executed are
+    /// specified in sequence on the instruction data. Each instruction
+    /// provides:
+    ///   - `u8`: number of accounts
+    ///   - `u8`: instruction data length (includes the discriminator)
+    ///   - `u8`: instruction discriminator
+    ///   - `[u8]`: instruction data
+    ///
+    /// Accounts follow a similar pattern, where accounts for each instruction
+    /// are specified in sequence. Therefore, the number of accounts
+    /// expected by this instruction is variable, i.e., it depends on the
+    /// instructions provided.
+    ///
+    /// Note that it is not sound to have a `batch` instruction that contains
+    /// other `batch` instruction; an error will be raised when this is
+    /// detected.
+    Batch {
+        /// The serialized instructions of the batch.
+        data: &'a [u8],
+    },
     // Any new variants also need to be added to program-2022 `TokenInstruction`, so that the
     // latter remains a superset of this instruction set. New variants also need to be added to
     // token/js/src/instructions/types.ts to maintain @solana/spl-token compatibility
@@ -573,6 +874,51 @@ impl<'a> TokenInstruction<'a> {
                 let ui_amount = std::str::from_utf8(rest).map_err(|_| InvalidInstruction)?;
                 Self::UiAmountToAmount { ui_amount }
             }
+            38 => Self::WithdrawExcessLamports,
+            45 => {
+                let (amount, _rest) = Self::unpack_u64_option(rest)?;
+                Self::UnwrapLamports { amount }
+            }
+            46 => {
+                if rest.is_empty() || rest.len() % U64_BYTES != 0 {
+                    return Err(TokenError::InvalidInstruction.into());
+                }
+                let amounts = rest
+                    .chunks_exact(U64_BYTES)
+                    .map(|amount| u64::from_le_bytes(amount.try_into().unwrap()))
+                    .collect();
+                Self::TransferMany { amounts }
+            }
+            47 => Self::BurnAndClose,
+            48 => Self::SweepAndClose,
+            49 => {
+                let (amount, rest) = Self::unpack_u64(rest)?;
+                let (&expiry_type, rest) =
+                    rest.split_first().ok_or(TokenError::InvalidInstruction)?;
+                let (value, _rest) = Self::unpack_u64(rest)?;
+                let expiry = match expiry_type {
+                    0 => Expiry::Slot(value),
+                    1 => Expiry::UnixTimestamp(value as i64),
+                    _ => return Err(TokenError::InvalidInstruction.into()),
+                };
+                Self::ApproveWithExpiry { amount, expiry }
+            }
+            50 => {
+                let (limit, _rest) = Self::unpack_u64(rest)?;
+                Self::ApproveWithSpendingLimit { limit }
+            }
+            51 => {
+                let required = match rest.first() {
+                    Some(0) => false,
+                    Some(1) => true,
+                    _ => return Err(TokenError::InvalidInstruction.into()),
+                };
+                Self::SetMemoRequired { required }
+            }
+            52 => Self::PauseMint,
+            53 => Self::ResumeMint,
+            54 => Self::CloseDelegation,
+            255 => Self::Batch { data: rest },
             _ => return Err(TokenError::InvalidInstruction.into()),
         })
     }
@@ -684,6 +1030,54 @@ impl<'a> TokenInstruction<'a> {
                 buf.push(24);
                 buf.extend_from_slice(ui_amount.as_bytes());
             }
+            &Self::WithdrawExcessLamports => {
+                buf.push(38);
+            }
+            Self::UnwrapLamports { amount } => {
+                buf.push(45);
+                Self::pack_u64_option(amount, &mut buf);
+            }
+            Self::TransferMany { amounts } => {
+                buf.push(46);
+                for amount in amounts {
+                    buf.extend_from_slice(&amount.to_le_bytes());
+                }
+            }
+            &Self::BurnAndClose => {
+                buf.push(47);
+            }
+            &Self::SweepAndClose => {
+                buf.push(48);
+            }
+            &Self::ApproveWithExpiry { amount, expiry } => {
+                buf.push(49);
+                buf.extend_from_slice(&amount.to_le_bytes());
+                match expiry {
+                    Expiry::Slot(slot) => {
+                        buf.push(0);
+                        buf.extend_from_slice(&slot.to_le_bytes());
+                    }
+                    Expiry::UnixTimestamp(unix_timestamp) => {
+                        buf.push(1);
+                        buf.extend_from_slice(&unix_timestamp.to_le_bytes());
+                    }
+                }
+            }
+            &Self::ApproveWithSpendingLimit { limit } => {
+                buf.push(50);
+                buf.extend_from_slice(&limit.to_le_bytes());
+            }
+            &Self::SetMemoRequired { required } => {
+                buf.push(51);
+                buf.push(required as u8);
+            }
+            Self::PauseMint => buf.push(52),
+            Self::ResumeMint => buf.push(53),
+            Self::CloseDelegation => buf.push(54),
+            Self::Batch { data } => {
+                buf.push(255);
+                buf.extend_from_slice(data);
+            }
         };
         buf
     }
@@ -729,6 +1123,27 @@ impl<'a> TokenInstruction<'a> {
         Ok((value, &input[U64_BYTES..]))
     }
 
+    fn unpack_u64_option(input: &[u8]) -> Result<(Option<u64>, &[u8]), ProgramError> {
+        match input.split_first() {
+            Option::Some((&0, rest)) => Ok((Option::None, rest)),
+            Option::Some((&1, rest)) => {
+                let (value, rest) = Self::unpack_u64(rest)?;
+                Ok((Option::Some(value), rest))
+            }
+            _ => Err(TokenError::InvalidInstruction.into()),
+        }
+    }
+
+    fn pack_u64_option(value: &Option<u64>, buf: &mut Vec<u8>) {
+        match *value {
+            Option::Some(value) => {
+                buf.push(1);
+                buf.extend_from_slice(&value.to_le_bytes());
+            }
+            Option::None => buf.push(0),
+        }
+    }
+
     fn unpack_amount_decimals(input: &[u8]) -> Result<(u64, u8, &[u8]), ProgramError> {
         let (amount, rest) = Self::unpack_u64(input)?;
         let (&decimals, rest) = rest.split_first().ok_or(TokenError::InvalidInstruction)?;
@@ -1429,60 +1844,443 @@ pub fn ui_amount_to_amount(
     })
 }
 
-/// Utility function that checks index is between `MIN_SIGNERS` and
-/// `MAX_SIGNERS`
-pub fn is_valid_signer_index(index: usize) -> bool {
-    (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
-}
+/// Creates a `WithdrawExcessLamports` instruction.
+pub fn withdraw_excess_lamports(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::WithdrawExcessLamports.pack();
 
-#[cfg(test)]
-mod test {
-    use {super::*, proptest::prelude::*};
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
 
-    #[test]
-    fn test_instruction_packing() {
-        let check = TokenInstruction::InitializeMint {
-            decimals: 2,
-            mint_authority: Pubkey::new_from_array([1u8; 32]),
-            freeze_authority: COption::None,
-        };
-        let packed = check.pack();
-        let mut expect = Vec::from([0u8, 2]);
-        expect.extend_from_slice(&[1u8; 32]);
-        expect.extend_from_slice(&[0]);
-        assert_eq!(packed, expect);
-        let unpacked = TokenInstruction::unpack(&expect).unwrap();
-        assert_eq!(unpacked, check);
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
 
-        let check = TokenInstruction::InitializeMint {
-            decimals: 2,
-            mint_authority: Pubkey::new_from_array([2u8; 32]),
-            freeze_authority: COption::Some(Pubkey::new_from_array([3u8; 32])),
-        };
-        let packed = check.pack();
-        let mut expect = vec![0u8, 2];
-        expect.extend_from_slice(&[2u8; 32]);
-        expect.extend_from_slice(&[1]);
-        expect.extend_from_slice(&[3u8; 32]);
-        assert_eq!(packed, expect);
-        let unpacked = TokenInstruction::unpack(&expect).unwrap();
-        assert_eq!(unpacked, check);
+/// Creates an `UnwrapLamports` instruction.
+pub fn unwrap_lamports(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: Option<u64>,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::UnwrapLamports { amount }.pack();
 
-        let check = TokenInstruction::InitializeAccount;
-        let packed = check.pack();
-        let expect = Vec::from([1u8]);
-        assert_eq!(packed, expect);
-        let unpacked = TokenInstruction::unpack(&expect).unwrap();
-        assert_eq!(unpacked, check);
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
 
-        let check = TokenInstruction::InitializeMultisig { m: 1 };
-        let packed = check.pack();
-        let expect = Vec::from([2u8, 1]);
-        assert_eq!(packed, expect);
-        let unpacked = TokenInstruction::unpack(&expect).unwrap();
-        assert_eq!(unpacked, check);
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
 
-        let check = TokenInstruction::Transfer { amount: 1 };
+/// Creates a `TransferMany` instruction.
+///
+/// Each destination account is credited with its corresponding amount of
+/// tokens.
+pub fn transfer_many(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destinations: &[(&Pubkey, u64)],
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::TransferMany {
+        amounts: destinations.iter().map(|(_, amount)| *amount).collect(),
+    }
+    .pack();
+
+    let mut accounts = Vec::with_capacity(2 + destinations.len() + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for (destination_pubkey, _) in destinations.iter() {
+        accounts.push(AccountMeta::new(**destination_pubkey, false));
+    }
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `BurnAndClose` instruction.
+pub fn burn_and_close(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::BurnAndClose.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SweepAndClose` instruction.
+pub fn sweep_and_close(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    recipient_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::SweepAndClose.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new(*recipient_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `ApproveWithExpiry` instruction.
+#[allow(clippy::too_many_arguments)]
+pub fn approve_with_expiry(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegation_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+    expiry: Expiry,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::ApproveWithExpiry { amount, expiry }.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*delegation_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `ApproveWithSpendingLimit` instruction.
+pub fn approve_with_spending_limit(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    spending_limit_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    limit: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::ApproveWithSpendingLimit { limit }.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*spending_limit_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `SetMemoRequired` instruction.
+pub fn set_memo_required(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    required: bool,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::SetMemoRequired { required }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `PauseMint` instruction.
+pub fn pause_mint(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_pause_pubkey: &Pubkey,
+    freeze_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::PauseMint.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*mint_pause_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *freeze_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ResumeMint` instruction.
+pub fn resume_mint(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_pause_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    freeze_authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::ResumeMint.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*mint_pause_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *freeze_authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CloseDelegation` instruction.
+pub fn close_delegation(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegation_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::CloseDelegation.pack();
+
+    let mut accounts = Vec::with_capacity(4 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*delegation_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `Batch` instruction from a list of token instructions.
+///
+/// The accounts of each instruction are appended in order, so the same account
+/// might appear multiple times in the resulting instruction. Fails if any of
+/// the instructions is not a token instruction, is itself a `Batch` or does
+/// not fit the `u8` header of a batch frame.
+pub fn batch(instructions: Vec<Instruction>) -> Result<Instruction, ProgramError> {
+    if instructions.is_empty() {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
+    let mut accounts = Vec::with_capacity(instructions.iter().map(|ix| ix.accounts.len()).sum());
+    let mut data = Vec::new();
+
+    for instruction in instructions {
+        check_program_account(&instruction.program_id)?;
+
+        match instruction.data.first() {
+            Some(&discriminator) if discriminator != BATCH_DISCRIMINATOR => {}
+            _ => return Err(TokenError::InvalidInstruction.into()),
+        }
+
+        let num_accounts =
+            u8::try_from(instruction.accounts.len()).map_err(|_| TokenError::InvalidInstruction)?;
+        let data_len =
+            u8::try_from(instruction.data.len()).map_err(|_| TokenError::InvalidInstruction)?;
+
+        data.push(num_accounts);
+        data.push(data_len);
+        data.extend_from_slice(&instruction.data);
+        accounts.extend(instruction.accounts);
+    }
+
+    Ok(Instruction {
+        program_id: crate::id(),
+        accounts,
+        data: TokenInstruction::Batch { data: &data }.pack(),
+    })
+}
+
+/// Utility function that checks index is between `MIN_SIGNERS` and
+/// `MAX_SIGNERS`
+pub fn is_valid_signer_index(index: usize) -> bool {
+    (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
+}
+
+#[cfg(test)]
+mod test {
+    use {super::*, proptest::prelude::*};
+
+    #[test]
+    fn test_instruction_packing() {
+        let check = TokenInstruction::InitializeMint {
+            decimals: 2,
+            mint_authority: Pubkey::new_from_array([1u8; 32]),
+            freeze_authority: COption::None,
+        };
+        let packed = check.pack();
+        let mut expect = Vec::from([0u8, 2]);
+        expect.extend_from_slice(&[1u8; 32]);
+        expect.extend_from_slice(&[0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeMint {
+            decimals: 2,
+            mint_authority: Pubkey::new_from_array([2u8; 32]),
+            freeze_authority: COption::Some(Pubkey::new_from_array([3u8; 32])),
+        };
+        let packed = check.pack();
+        let mut expect = vec![0u8, 2];
+        expect.extend_from_slice(&[2u8; 32]);
+        expect.extend_from_slice(&[1]);
+        expect.extend_from_slice(&[3u8; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeAccount;
+        let packed = check.pack();
+        let expect = Vec::from([1u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeMultisig { m: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([2u8, 1]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::Transfer { amount: 1 };
         let packed = check.pack();
         let expect = Vec::from([3u8, 1, 0, 0, 0, 0, 0, 0, 0]);
         assert_eq!(packed, expect);
@@ -1678,6 +2476,167 @@ mod test {
         assert_eq!(packed, expect);
         let unpacked = TokenInstruction::unpack(&expect).unwrap();
         assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::WithdrawExcessLamports;
+        let packed = check.pack();
+        let expect = vec![38u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::UnwrapLamports { amount: None };
+        let packed = check.pack();
+        let expect = vec![45u8, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::UnwrapLamports { amount: Some(42) };
+        let packed = check.pack();
+        let expect = vec![45u8, 1, 42, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::TransferMany {
+            amounts: vec![1, 2],
+        };
+        let packed = check.pack();
+        let expect = vec![46u8, 1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+        assert!(TokenInstruction::unpack(&[46u8]).is_err());
+        assert!(TokenInstruction::unpack(&[46u8, 1, 0, 0, 0]).is_err());
+
+        let check = TokenInstruction::BurnAndClose;
+        let packed = check.pack();
+        let expect = vec![47u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::SweepAndClose;
+        let packed = check.pack();
+        let expect = vec![48u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ApproveWithExpiry {
+            amount: 1,
+            expiry: Expiry::Slot(2),
+        };
+        let packed = check.pack();
+        let expect = vec![49u8, 1, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ApproveWithExpiry {
+            amount: 1,
+            expiry: Expiry::UnixTimestamp(-1),
+        };
+        let packed = check.pack();
+        let mut expect = vec![49u8, 1, 0, 0, 0, 0, 0, 0, 0, 1];
+        expect.extend_from_slice(&[255; 8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ApproveWithSpendingLimit { limit: 1 };
+        let packed = check.pack();
+        let expect = vec![50u8, 1, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::SetMemoRequired { required: true };
+        let packed = check.pack();
+        let expect = vec![51u8, 1];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::PauseMint;
+        let packed = check.pack();
+        let expect = vec![52u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ResumeMint;
+        let packed = check.pack();
+        let expect = vec![53u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+        let check = TokenInstruction::CloseDelegation;
+        let packed = check.pack();
+        let expect = vec![54u8];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::Batch {
+            data: &[3, 9, 3, 42, 0, 0, 0, 0, 0, 0, 0],
+        };
+        let packed = check.pack();
+        let expect = vec![255u8, 3, 9, 3, 42, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+    }
+
+    #[test]
+    fn test_batch() {
+        let source = Pubkey::new_unique();
+        let destination = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let transfer_ix =
+            transfer(&crate::id(), &source, &destination, &authority, &[], 42).unwrap();
+        let unwrap_ix =
+            unwrap_lamports(&crate::id(), &source, &destination, &authority, &[], None).unwrap();
+
+        let batch_ix = batch(vec![transfer_ix.clone(), unwrap_ix.clone()]).unwrap();
+        assert_eq!(batch_ix.program_id, crate::id());
+        assert_eq!(
+            batch_ix.accounts,
+            [transfer_ix.accounts, unwrap_ix.accounts].concat()
+        );
+
+        let mut expect = vec![255u8, 3, 9];
+        expect.extend_from_slice(&transfer_ix.data);
+        expect.extend_from_slice(&[3, 2]);
+        expect.extend_from_slice(&unwrap_ix.data);
+        assert_eq!(batch_ix.data, expect);
+
+        let unpacked = TokenInstruction::unpack(&batch_ix.data).unwrap();
+        assert_eq!(unpacked, TokenInstruction::Batch { data: &expect[1..] });
+        assert_eq!(unpacked.pack(), batch_ix.data);
+
+        // nested batches are rejected
+        assert_eq!(
+            batch(vec![batch_ix]),
+            Err(TokenError::InvalidInstruction.into())
+        );
+
+        // empty batches are rejected
+        assert_eq!(batch(vec![]), Err(TokenError::InvalidInstruction.into()));
+
+        // non-token instructions are rejected
+        let mut other_ix = sync_native(&crate::id(), &source).unwrap();
+        other_ix.program_id = Pubkey::new_unique();
+        assert_eq!(batch(vec![other_ix]), Err(ProgramError::IncorrectProgramId));
+
+        // instructions must fit the frame header
+        let mut large_ix = sync_native(&crate::id(), &source).unwrap();
+        large_ix.accounts = vec![AccountMeta::new(source, false); 256];
+        assert_eq!(
+            batch(vec![large_ix]),
+            Err(TokenError::InvalidInstruction.into())
+        );
     }
 
     #[test]
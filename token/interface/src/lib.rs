@@ -11,6 +11,7 @@ use {
 
 pub mod error;
 pub mod instruction;
+pub mod memo;
 pub mod native_mint;
 pub mod state;
 
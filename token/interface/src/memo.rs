@@ -0,0 +1,15 @@
+//! The Memo programs accepted before transfers into accounts that require
+//! memos
+
+use solana_pubkey::Pubkey;
+
+/// The Memo program
+pub const ID: Pubkey = Pubkey::from_str_const("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// The legacy (v1) Memo program
+pub const V1_ID: Pubkey = Pubkey::from_str_const("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+
+/// Checks whether the program is one of the Memo programs
+pub fn is_memo_program(program_id: &Pubkey) -> bool {
+    program_id == &ID || program_id == &V1_ID
+}
@@ -80,6 +80,74 @@ impl Pack for Mint {
         pack_coption_key(freeze_authority, freeze_authority_dst);
     }
 }
+impl Mint {
+    /// Returns `true` if the mint is paused.
+    pub fn is_paused(&self) -> bool {
+        self.freeze_authority == COption::Some(PAUSED_MINT_FREEZE_AUTHORITY)
+    }
+}
+
+/// The freeze authority of a paused mint
+///
+/// Pausing a mint moves its freeze authority to a [`MintPause`] account and
+/// sets this address, which has no private key, as the freeze authority, so
+/// paused mints keep the regular layout.
+pub const PAUSED_MINT_FREEZE_AUTHORITY: Pubkey =
+    Pubkey::from_str_const("MintPaused111111111111111111111111111111111");
+
+/// The offset of the `freeze_authority` option tag in Mint's C representation
+const MINT_FREEZE_AUTHORITY_INDEX: usize = 46;
+
+/// Check if the mint data buffer represents a paused mint.
+pub fn is_paused_mint(mint_data: &[u8]) -> bool {
+    mint_data.len() == Mint::LEN
+        && mint_data[MINT_FREEZE_AUTHORITY_INDEX..MINT_FREEZE_AUTHORITY_INDEX + 4] == [1, 0, 0, 0]
+        && mint_data[MINT_FREEZE_AUTHORITY_INDEX + 4..] == PAUSED_MINT_FREEZE_AUTHORITY.to_bytes()
+}
+
+/// Pause of a mint.
+///
+/// While the mint is paused, the mint pause account records the actual
+/// freeze authority of the mint, which is restored when the mint is resumed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MintPause {
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// The paused mint
+    pub mint: Pubkey,
+    /// The freeze authority of the mint
+    pub freeze_authority: Pubkey,
+}
+impl Sealed for MintPause {}
+impl IsInitialized for MintPause {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+impl Pack for MintPause {
+    const LEN: usize = 65;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 65];
+        let (is_initialized, mint, freeze_authority) = array_refs![src, 1, 32, 32];
+        Ok(MintPause {
+            is_initialized: match is_initialized {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+            mint: Pubkey::new_from_array(*mint),
+            freeze_authority: Pubkey::new_from_array(*freeze_authority),
+        })
+    }
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 65];
+        let (is_initialized, mint, freeze_authority) = mut_array_refs![dst, 1, 32, 32];
+        *is_initialized = [self.is_initialized as u8];
+        mint.copy_from_slice(self.mint.as_ref());
+        freeze_authority.copy_from_slice(self.freeze_authority.as_ref());
+    }
+}
 
 /// Account data.
 #[repr(C)]
@@ -249,6 +317,160 @@ impl Pack for Multisig {
     }
 }
 
+/// Expiry of a delegation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Expiry {
+    /// The delegation expires at the given slot.
+    Slot(u64),
+    /// The delegation expires at the given unix timestamp.
+    UnixTimestamp(i64),
+}
+impl Expiry {
+    /// Returns `true` if the expiry has been reached at the given slot and
+    /// unix timestamp.
+    pub fn is_expired(&self, slot: u64, unix_timestamp: i64) -> bool {
+        match *self {
+            Expiry::Slot(expiry) => slot >= expiry,
+            Expiry::UnixTimestamp(expiry) => unix_timestamp >= expiry,
+        }
+    }
+}
+
+/// Delegation with an expiry.
+///
+/// The delegation account is set as the delegate of the token account, while
+/// the actual delegate is recorded in the delegation account together with
+/// the expiry.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Delegation {
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// The token account of the delegation
+    pub account: Pubkey,
+    /// The delegate of the token account
+    pub delegate: Pubkey,
+    /// The expiry of the delegation
+    pub expiry: Expiry,
+}
+impl Sealed for Delegation {}
+impl IsInitialized for Delegation {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+impl Pack for Delegation {
+    const LEN: usize = 74;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 74];
+        let (is_initialized, account, delegate, expiry_type, expiry) =
+            array_refs![src, 1, 32, 32, 1, 8];
+        Ok(Delegation {
+            is_initialized: match is_initialized {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+            account: Pubkey::new_from_array(*account),
+            delegate: Pubkey::new_from_array(*delegate),
+            expiry: match expiry_type {
+                [0] => Expiry::Slot(u64::from_le_bytes(*expiry)),
+                [1] => Expiry::UnixTimestamp(i64::from_le_bytes(*expiry)),
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+        })
+    }
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 74];
+        let (is_initialized, account, delegate, expiry_type, expiry) =
+            mut_array_refs![dst, 1, 32, 32, 1, 8];
+        *is_initialized = [self.is_initialized as u8];
+        account.copy_from_slice(self.account.as_ref());
+        delegate.copy_from_slice(self.delegate.as_ref());
+        match self.expiry {
+            Expiry::Slot(slot) => {
+                *expiry_type = [0];
+                *expiry = slot.to_le_bytes();
+            }
+            Expiry::UnixTimestamp(unix_timestamp) => {
+                *expiry_type = [1];
+                *expiry = unix_timestamp.to_le_bytes();
+            }
+        }
+    }
+}
+
+/// Delegation with a recurring spending limit.
+///
+/// The spending limit account is set as the delegate of the token account,
+/// while the actual delegate is recorded in the spending limit account
+/// together with the amount it can spend per epoch. The spent amount is
+/// reset at the start of each epoch.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SpendingLimit {
+    /// Is `true` if this structure has been initialized
+    pub is_initialized: bool,
+    /// The token account of the spending limit
+    pub account: Pubkey,
+    /// The delegate of the token account
+    pub delegate: Pubkey,
+    /// The amount of tokens the delegate can spend per epoch
+    pub limit: u64,
+    /// The epoch of the spent amount
+    pub epoch: u64,
+    /// The amount of tokens spent in the epoch
+    pub spent: u64,
+}
+impl SpendingLimit {
+    /// Returns the amount of tokens that can still be spent in the given
+    /// epoch.
+    pub fn remaining(&self, epoch: u64) -> u64 {
+        if self.epoch == epoch {
+            self.limit.saturating_sub(self.spent)
+        } else {
+            self.limit
+        }
+    }
+}
+impl Sealed for SpendingLimit {}
+impl IsInitialized for SpendingLimit {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+impl Pack for SpendingLimit {
+    const LEN: usize = 89;
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 89];
+        let (is_initialized, account, delegate, limit, epoch, spent) =
+            array_refs![src, 1, 32, 32, 8, 8, 8];
+        Ok(SpendingLimit {
+            is_initialized: match is_initialized {
+                [0] => false,
+                [1] => true,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+            account: Pubkey::new_from_array(*account),
+            delegate: Pubkey::new_from_array(*delegate),
+            limit: u64::from_le_bytes(*limit),
+            epoch: u64::from_le_bytes(*epoch),
+            spent: u64::from_le_bytes(*spent),
+        })
+    }
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 89];
+        let (is_initialized, account, delegate, limit, epoch, spent) =
+            mut_array_refs![dst, 1, 32, 32, 8, 8, 8];
+        *is_initialized = [self.is_initialized as u8];
+        account.copy_from_slice(self.account.as_ref());
+        delegate.copy_from_slice(self.delegate.as_ref());
+        *limit = self.limit.to_le_bytes();
+        *epoch = self.epoch.to_le_bytes();
+        *spent = self.spent.to_le_bytes();
+    }
+}
+
 // Helpers
 fn pack_coption_key(src: &COption<Pubkey>, dst: &mut [u8; 36]) {
     let (tag, body) = mut_array_refs![dst, 4, 32];
@@ -350,6 +572,23 @@ pub fn is_initialized_account(account_data: &[u8]) -> bool {
         != AccountState::Uninitialized as u8
 }
 
+/// The offset of the `is_native` option tag in Account's C representation
+const ACCOUNT_IS_NATIVE_INDEX: usize = 109;
+
+/// The offset of the memo-required flag in Account's C representation
+///
+/// The flag is stored in the first byte of the native amount, which is unused
+/// by non-native accounts, so flagged accounts keep the regular layout.
+pub const ACCOUNT_MEMO_REQUIRED_INDEX: usize = 113;
+
+/// Check if the account data buffer represents an account that requires a
+/// memo before incoming transfers.
+pub fn is_memo_required_account(account_data: &[u8]) -> bool {
+    account_data.len() == Account::LEN
+        && account_data[ACCOUNT_IS_NATIVE_INDEX] == 0
+        && account_data[ACCOUNT_MEMO_REQUIRED_INDEX] == 1
+}
+
 impl GenericTokenAccount for Account {
     fn valid_account_data(account_data: &[u8]) -> bool {
         account_data.len() == Account::LEN && is_initialized_account(account_data)
@@ -401,6 +640,57 @@ mod tests {
         assert_eq!(multisig, ProgramError::InvalidAccountData);
     }
 
+    #[test]
+    fn test_delegation_pack_unpack() {
+        let check = Delegation {
+            is_initialized: true,
+            account: Pubkey::new_from_array([1; 32]),
+            delegate: Pubkey::new_from_array([2; 32]),
+            expiry: Expiry::UnixTimestamp(-42),
+        };
+        let mut packed = vec![0; Delegation::get_packed_len()];
+        Delegation::pack(check, &mut packed).unwrap();
+        let mut expect = vec![1u8];
+        expect.extend_from_slice(&[1; 32]);
+        expect.extend_from_slice(&[2; 32]);
+        expect.push(1);
+        expect.extend_from_slice(&(-42i64).to_le_bytes());
+        assert_eq!(packed, expect);
+        let unpacked = Delegation::unpack(&packed).unwrap();
+        assert_eq!(unpacked, check);
+
+        let mut src: [u8; 74] = [0; 74];
+        src[65] = 2;
+        let delegation = Delegation::unpack_from_slice(&src).unwrap_err();
+        assert_eq!(delegation, ProgramError::InvalidAccountData);
+    }
+
+    #[test]
+    fn test_spending_limit_pack_unpack() {
+        let check = SpendingLimit {
+            is_initialized: true,
+            account: Pubkey::new_from_array([1; 32]),
+            delegate: Pubkey::new_from_array([2; 32]),
+            limit: 3,
+            epoch: 4,
+            spent: 2,
+        };
+        let mut packed = vec![0; SpendingLimit::get_packed_len()];
+        SpendingLimit::pack(check, &mut packed).unwrap();
+        let mut expect = vec![1u8];
+        expect.extend_from_slice(&[1; 32]);
+        expect.extend_from_slice(&[2; 32]);
+        expect.extend_from_slice(&[3, 0, 0, 0, 0, 0, 0, 0]);
+        expect.extend_from_slice(&[4, 0, 0, 0, 0, 0, 0, 0]);
+        expect.extend_from_slice(&[2, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = SpendingLimit::unpack(&packed).unwrap();
+        assert_eq!(unpacked, check);
+
+        assert_eq!(unpacked.remaining(4), 1);
+        assert_eq!(unpacked.remaining(5), 3);
+    }
+
     #[test]
     fn test_unpack_coption_key() {
         let src: [u8; 36] = [0; 36];
@@ -487,4 +777,81 @@ mod tests {
         let result = Account::unpack_account_mint(&src);
         assert_eq!(result, Option::None);
     }
+
+    #[test]
+    fn test_is_memo_required_account() {
+        let mut account = Account {
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        let mut dst = [0; Account::LEN];
+        Account::pack(account, &mut dst).unwrap();
+        assert!(!is_memo_required_account(&dst));
+
+        // The flag is preserved when packing a non-native account.
+        dst[ACCOUNT_MEMO_REQUIRED_INDEX] = 1;
+        assert!(is_memo_required_account(&dst));
+        Account::pack(Account::unpack(&dst).unwrap(), &mut dst).unwrap();
+        assert!(is_memo_required_account(&dst));
+
+        // The native amount of native accounts is not a flag.
+        account.is_native = COption::Some(1);
+        Account::pack(account, &mut dst).unwrap();
+        assert!(!is_memo_required_account(&dst));
+
+        // Wrong account data size.
+        assert!(!is_memo_required_account(&dst[..Account::LEN - 1]));
+    }
+
+    #[test]
+    fn test_is_paused_mint() {
+        let mut mint = Mint {
+            mint_authority: COption::Some(Pubkey::new_from_array([1; 32])),
+            supply: 42,
+            decimals: 7,
+            is_initialized: true,
+            freeze_authority: COption::Some(Pubkey::new_from_array([2; 32])),
+        };
+        let mut dst = [0; Mint::LEN];
+        Mint::pack(mint, &mut dst).unwrap();
+        assert!(!mint.is_paused());
+        assert!(!is_paused_mint(&dst));
+
+        // A paused mint has a regular layout.
+        mint.freeze_authority = COption::Some(PAUSED_MINT_FREEZE_AUTHORITY);
+        Mint::pack(mint, &mut dst).unwrap();
+        assert!(mint.is_paused());
+        assert!(is_paused_mint(&dst));
+        assert_eq!(Mint::unpack(&dst).unwrap(), mint);
+
+        mint.freeze_authority = COption::None;
+        Mint::pack(mint, &mut dst).unwrap();
+        assert!(!mint.is_paused());
+        assert!(!is_paused_mint(&dst));
+
+        // Wrong mint data size.
+        assert!(!is_paused_mint(&dst[..Mint::LEN - 1]));
+    }
+
+    #[test]
+    fn test_mint_pause_pack_unpack() {
+        let check = MintPause {
+            is_initialized: true,
+            mint: Pubkey::new_from_array([1; 32]),
+            freeze_authority: Pubkey::new_from_array([2; 32]),
+        };
+        let mut packed = vec![0; MintPause::get_packed_len()];
+        MintPause::pack(check, &mut packed).unwrap();
+        let mut expect = vec![1u8];
+        expect.extend_from_slice(&[1; 32]);
+        expect.extend_from_slice(&[2; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = MintPause::unpack(&packed).unwrap();
+        assert_eq!(unpacked, check);
+
+        let mut src: [u8; 65] = [0; 65];
+        src[0] = 2;
+        let mint_pause = MintPause::unpack_from_slice(&src).unwrap_err();
+        assert_eq!(mint_pause, ProgramError::InvalidAccountData);
+    }
 }
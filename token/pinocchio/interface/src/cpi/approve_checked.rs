@@ -0,0 +1,74 @@
+use {
+    super::{
+        amount_decimals_data, authority_meta, BatchBuilder, BatchInstruction, InstructionParts,
+    },
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Approves a delegate, asserting the token mint and decimals.
+pub struct ApproveChecked<'a> {
+    /// Source account.
+    pub source: &'a AccountInfo,
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+    /// Delegate account.
+    pub delegate: &'a AccountInfo,
+    /// Source account owner.
+    pub owner: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+    /// Amount of tokens.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl<'a> ApproveChecked<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 4, 10> {
+        let data = amount_decimals_data(
+            TokenInstruction::ApproveChecked as u8,
+            self.amount,
+            self.decimals,
+        );
+
+        InstructionParts::new(
+            [self.source, self.mint, self.delegate, self.owner],
+            [
+                AccountMeta::writable(self.source.key()),
+                AccountMeta::readonly(self.mint.key()),
+                AccountMeta::readonly(self.delegate.key()),
+                authority_meta(self.owner, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            data,
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for ApproveChecked<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
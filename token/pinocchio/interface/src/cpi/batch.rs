@@ -0,0 +1,243 @@
+use {
+    super::InstructionParts,
+    crate::{instruction::TokenInstruction, state::multisig::MAX_SIGNERS},
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::slice_invoke_signed,
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        ProgramResult,
+    },
+};
+
+/// The size of the batch instruction header.
+///
+/// The header of each instruction consists of two `u8` values:
+///  * number of the accounts
+///  * length of the instruction data
+const IX_HEADER_SIZE: usize = 2;
+
+/// Executes a batch of instructions.
+///
+/// The `data` must start with the `Batch` discriminator followed by the
+/// instruction frames, each one consisting of the number of accounts (`u8`),
+/// the length of the instruction data (`u8`) and the instruction data. The
+/// `accounts` of all instructions are concatenated in the same order as the
+/// frames.
+pub struct Batch<'a> {
+    /// Accounts of the instructions in the batch.
+    pub accounts: &'a [&'a AccountInfo],
+    /// Account metas of the instructions in the batch.
+    pub account_metas: &'a [AccountMeta<'a>],
+    /// Instruction data of the batch.
+    pub data: &'a [u8],
+}
+
+impl Batch<'_> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    ///
+    /// Returns [`ProgramError::InvalidInstructionData`] if the data does not
+    /// start with the `Batch` discriminator or has no instructions.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        match self.data {
+            [discriminator, _, ..] if *discriminator == TokenInstruction::Batch as u8 => (),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        }
+
+        slice_invoke_signed(
+            &Instruction {
+                program_id: &crate::program::ID,
+                accounts: self.account_metas,
+                data: self.data,
+            },
+            self.accounts,
+            signers,
+        )
+    }
+}
+
+/// An instruction that can be added to a [`BatchBuilder`].
+///
+/// This is implemented by all instruction structs of the [`cpi`](super)
+/// module except [`Batch`], since batches cannot be nested.
+pub trait BatchInstruction<'a> {
+    /// Appends the instruction to the `batch`.
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult;
+}
+
+/// Fixed-capacity builder for [`Batch`] instructions.
+///
+/// The builder holds up to `ACCOUNTS` accounts and `DATA` bytes of instruction
+/// data (including the `Batch` discriminator) on the stack. Accounts are not
+/// deduplicated: each instruction appends all of its accounts, in order.
+///
+/// ```ignore
+/// let mut batch = BatchBuilder::<6, 32>::new();
+///
+/// batch.push(&Transfer { .. })?;
+/// batch.push(&CloseAccount { .. })?;
+///
+/// batch.invoke_signed(&[signer])?;
+/// ```
+pub struct BatchBuilder<'a, const ACCOUNTS: usize, const DATA: usize> {
+    account_infos: [MaybeUninit<&'a AccountInfo>; ACCOUNTS],
+    account_metas: [MaybeUninit<AccountMeta<'a>>; ACCOUNTS],
+    num_accounts: usize,
+    data: [MaybeUninit<u8>; DATA],
+    data_len: usize,
+}
+
+impl<'a, const ACCOUNTS: usize, const DATA: usize> BatchBuilder<'a, ACCOUNTS, DATA> {
+    /// Creates an empty batch.
+    #[inline(always)]
+    pub const fn new() -> Self {
+        const { assert!(DATA > 0, "DATA must hold at least the batch discriminator") };
+
+        let mut data = [const { MaybeUninit::uninit() }; DATA];
+        data[0] = MaybeUninit::new(TokenInstruction::Batch as u8);
+
+        Self {
+            account_infos: [const { MaybeUninit::uninit() }; ACCOUNTS],
+            account_metas: [const { MaybeUninit::uninit() }; ACCOUNTS],
+            num_accounts: 0,
+            data,
+            data_len: 1,
+        }
+    }
+
+    /// Appends an instruction to the batch.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if the instruction does not
+    /// fit in the batch or exceeds the `u8` limits on the number of accounts
+    /// and data length of an instruction.
+    #[inline(always)]
+    pub fn push<I: BatchInstruction<'a>>(&mut self, instruction: &I) -> ProgramResult {
+        instruction.append_to(self)
+    }
+
+    /// Appends the accounts and data of an instruction to the batch.
+    pub(super) fn append<const IX_ACCOUNTS: usize, const IX_DATA: usize>(
+        &mut self,
+        parts: InstructionParts<'a, IX_ACCOUNTS, IX_DATA>,
+    ) -> ProgramResult {
+        let data = parts.data();
+
+        if data.first() == Some(&(TokenInstruction::Batch as u8)) {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if parts.multisig_signers.len() > MAX_SIGNERS as usize {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let ix_accounts = IX_ACCOUNTS + parts.multisig_signers.len();
+
+        if ix_accounts > u8::MAX as usize
+            || data.len() > u8::MAX as usize
+            || self.num_accounts + ix_accounts > ACCOUNTS
+            || self.data_len + IX_HEADER_SIZE + data.len() > DATA
+        {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let account_metas = parts.account_metas.iter().cloned().chain(
+            parts
+                .multisig_signers
+                .iter()
+                .map(|signer| AccountMeta::readonly_signer(signer.key())),
+        );
+        let account_infos = parts
+            .account_infos
+            .iter()
+            .chain(parts.multisig_signers.iter());
+
+        for (index, (info, meta)) in account_infos.zip(account_metas).enumerate() {
+            self.account_infos[self.num_accounts + index].write(info);
+            self.account_metas[self.num_accounts + index].write(meta);
+        }
+        self.num_accounts += ix_accounts;
+
+        self.data[self.data_len].write(ix_accounts as u8);
+        self.data[self.data_len + 1].write(data.len() as u8);
+        self.data_len += IX_HEADER_SIZE;
+
+        for (target, byte) in self.data[self.data_len..].iter_mut().zip(data) {
+            target.write(*byte);
+        }
+        self.data_len += data.len();
+
+        Ok(())
+    }
+
+    /// Returns `true` if no instruction was added to the batch.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.num_accounts == 0 && self.data_len == 1
+    }
+
+    /// Returns the accounts of the batch.
+    #[inline(always)]
+    pub fn account_infos(&self) -> &[&'a AccountInfo] {
+        // SAFETY: The first `num_accounts` accounts are initialized.
+        unsafe {
+            from_raw_parts(
+                self.account_infos.as_ptr() as *const &AccountInfo,
+                self.num_accounts,
+            )
+        }
+    }
+
+    /// Returns the account metas of the batch.
+    #[inline(always)]
+    pub fn account_metas(&self) -> &[AccountMeta<'a>] {
+        // SAFETY: The first `num_accounts` account metas are initialized.
+        unsafe {
+            from_raw_parts(
+                self.account_metas.as_ptr() as *const AccountMeta,
+                self.num_accounts,
+            )
+        }
+    }
+
+    /// Returns the instruction data of the batch.
+    #[inline(always)]
+    pub fn data(&self) -> &[u8] {
+        // SAFETY: The first `data_len` bytes are initialized.
+        unsafe { from_raw_parts(self.data.as_ptr() as *const u8, self.data_len) }
+    }
+
+    /// Invokes the batch.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the batch with the given PDA signers.
+    ///
+    /// Returns [`ProgramError::InvalidInstructionData`] if no instruction was
+    /// added to the batch.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        Batch {
+            accounts: self.account_infos(),
+            account_metas: self.account_metas(),
+            data: self.data(),
+        }
+        .invoke_signed(signers)
+    }
+}
+
+impl<const ACCOUNTS: usize, const DATA: usize> Default for BatchBuilder<'_, ACCOUNTS, DATA> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
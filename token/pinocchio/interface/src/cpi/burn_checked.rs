@@ -0,0 +1,72 @@
+use {
+    super::{
+        amount_decimals_data, authority_meta, BatchBuilder, BatchInstruction, InstructionParts,
+    },
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Burns tokens by removing them from an account, asserting the token mint
+/// and decimals.
+pub struct BurnChecked<'a> {
+    /// Account to burn from.
+    pub account: &'a AccountInfo,
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+    /// Account owner or delegate.
+    pub authority: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+    /// Amount of tokens.
+    pub amount: u64,
+    /// Expected number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+}
+
+impl<'a> BurnChecked<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 3, 10> {
+        let data = amount_decimals_data(
+            TokenInstruction::BurnChecked as u8,
+            self.amount,
+            self.decimals,
+        );
+
+        InstructionParts::new(
+            [self.account, self.mint, self.authority],
+            [
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::writable(self.mint.key()),
+                authority_meta(self.authority, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            data,
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for BurnChecked<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
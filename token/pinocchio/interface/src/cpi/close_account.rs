@@ -0,0 +1,60 @@
+use {
+    super::{authority_meta, BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Closes an account by transferring all its lamports to the destination
+/// account.
+pub struct CloseAccount<'a> {
+    /// Account to close.
+    pub account: &'a AccountInfo,
+    /// Destination account.
+    pub destination: &'a AccountInfo,
+    /// Account owner or close authority.
+    pub authority: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+}
+
+impl<'a> CloseAccount<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 3, 1> {
+        InstructionParts::new(
+            [self.account, self.destination, self.authority],
+            [
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::writable(self.destination.key()),
+                authority_meta(self.authority, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            [TokenInstruction::CloseAccount as u8],
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for CloseAccount<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
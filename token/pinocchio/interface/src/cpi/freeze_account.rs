@@ -0,0 +1,59 @@
+use {
+    super::{authority_meta, BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Freezes an initialized account using the mint's freeze authority.
+pub struct FreezeAccount<'a> {
+    /// Account to freeze.
+    pub account: &'a AccountInfo,
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+    /// Mint freeze authority.
+    pub freeze_authority: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+}
+
+impl<'a> FreezeAccount<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 3, 1> {
+        InstructionParts::new(
+            [self.account, self.mint, self.freeze_authority],
+            [
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::readonly(self.mint.key()),
+                authority_meta(self.freeze_authority, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            [TokenInstruction::FreezeAccount as u8],
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for FreezeAccount<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
@@ -0,0 +1,65 @@
+use {
+    super::{BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initializes a new account to hold tokens without requiring the Rent sysvar
+/// account.
+pub struct InitializeAccount3<'a> {
+    /// Account to initialize.
+    pub account: &'a AccountInfo,
+    /// Mint this account will be associated with.
+    pub mint: &'a AccountInfo,
+    /// The new account's owner.
+    pub owner: &'a Pubkey,
+}
+
+impl<'a> InitializeAccount3<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 2, 33> {
+        // instruction data
+        // - [0]: instruction discriminator (1 byte, u8)
+        // - [1..33]: owner (32 bytes, Pubkey)
+        let mut data = [0; 33];
+        data[0] = TokenInstruction::InitializeAccount3 as u8;
+        data[1..].copy_from_slice(self.owner);
+
+        InstructionParts::new(
+            [self.account, self.mint],
+            [
+                AccountMeta::writable(self.account.key()),
+                AccountMeta::readonly(self.mint.key()),
+            ],
+            &[],
+            data,
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for InitializeAccount3<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
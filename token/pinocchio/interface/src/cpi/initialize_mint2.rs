@@ -0,0 +1,76 @@
+use {
+    super::{BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Initializes a new mint without requiring the Rent sysvar account.
+pub struct InitializeMint2<'a> {
+    /// Mint to initialize.
+    pub mint: &'a AccountInfo,
+    /// Number of base 10 digits to the right of the decimal place.
+    pub decimals: u8,
+    /// The authority to mint tokens.
+    pub mint_authority: &'a Pubkey,
+    /// The optional freeze authority of the mint.
+    pub freeze_authority: Option<&'a Pubkey>,
+}
+
+impl<'a> InitializeMint2<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 1, 67> {
+        // instruction data
+        // - [0]: instruction discriminator (1 byte, u8)
+        // - [1]: decimals (1 byte, u8)
+        // - [2..34]: mint_authority (32 bytes, Pubkey)
+        // - [34]: freeze_authority presence flag (1 byte, u8)
+        // - [35..67]: freeze_authority (optional, 32 bytes, Pubkey)
+        let mut data = [0; 67];
+        data[0] = TokenInstruction::InitializeMint2 as u8;
+        data[1] = self.decimals;
+        data[2..34].copy_from_slice(self.mint_authority);
+
+        let len = if let Some(freeze_authority) = self.freeze_authority {
+            data[34] = 1;
+            data[35..].copy_from_slice(freeze_authority);
+            67
+        } else {
+            35
+        };
+
+        InstructionParts::new(
+            [self.mint],
+            [AccountMeta::writable(self.mint.key())],
+            &[],
+            data,
+        )
+        .with_data_len(len)
+    }
+}
+
+impl<'a> BatchInstruction<'a> for InitializeMint2<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
@@ -0,0 +1,63 @@
+use {
+    super::{amount_data, authority_meta, BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Mints new tokens to an account.
+pub struct MintTo<'a> {
+    /// Token mint.
+    pub mint: &'a AccountInfo,
+    /// Account to mint tokens to.
+    pub account: &'a AccountInfo,
+    /// Mint authority.
+    pub mint_authority: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+    /// Amount of tokens.
+    pub amount: u64,
+}
+
+impl<'a> MintTo<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 3, 9> {
+        let data = amount_data(TokenInstruction::MintTo as u8, self.amount);
+
+        InstructionParts::new(
+            [self.mint, self.account, self.mint_authority],
+            [
+                AccountMeta::writable(self.mint.key()),
+                AccountMeta::writable(self.account.key()),
+                authority_meta(self.mint_authority, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            data,
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for MintTo<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
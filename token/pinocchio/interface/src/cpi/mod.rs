@@ -0,0 +1,200 @@
+//! Cross-program invocation helpers.
+//!
+//! Each instruction of the token program is represented by a struct holding
+//! the accounts and arguments of the instruction. The instruction is executed
+//! by calling either `invoke` or `invoke_signed` on the struct. Instruction
+//! data and account metas are laid out on the stack, so no heap allocation is
+//! required.
+//!
+//! Instructions that require an authority accept a (possibly empty) slice of
+//! `multisig_signers`. When it is empty, the authority is expected to sign the
+//! instruction; otherwise the authority is a multisig account and the signer
+//! accounts are appended as read-only signers.
+//!
+//! Several instructions can be executed in a single invocation by adding them
+//! to a [`BatchBuilder`].
+
+mod approve;
+mod approve_checked;
+mod batch;
+mod burn;
+mod burn_checked;
+mod close_account;
+mod freeze_account;
+mod initialize_account3;
+mod initialize_mint2;
+mod mint_to;
+mod mint_to_checked;
+mod revoke;
+mod set_authority;
+mod sync_native;
+mod thaw_account;
+mod transfer;
+mod transfer_checked;
+mod unwrap_lamports;
+mod withdraw_excess_lamports;
+
+use {
+    crate::state::multisig::MAX_SIGNERS,
+    core::{mem::MaybeUninit, slice::from_raw_parts},
+    pinocchio::{
+        account_info::AccountInfo,
+        cpi::{invoke_signed, invoke_signed_with_bounds},
+        instruction::{AccountMeta, Instruction, Signer},
+        program_error::ProgramError,
+        ProgramResult,
+    },
+};
+pub use {
+    approve::*, approve_checked::*, batch::*, burn::*, burn_checked::*, close_account::*,
+    freeze_account::*, initialize_account3::*, initialize_mint2::*, mint_to::*, mint_to_checked::*,
+    revoke::*, set_authority::*, sync_native::*, thaw_account::*, transfer::*, transfer_checked::*,
+    unwrap_lamports::*, withdraw_excess_lamports::*,
+};
+
+/// Maximum number of accounts expected by an instruction, excluding the
+/// multisig signers.
+const MAX_INSTRUCTION_ACCOUNTS: usize = 4;
+
+/// Maximum number of accounts of a cross-program invocation, including the
+/// multisig signers.
+const MAX_ACCOUNTS: usize = MAX_INSTRUCTION_ACCOUNTS + MAX_SIGNERS as usize;
+
+/// Accounts and data of a token instruction.
+///
+/// The instruction accounts are followed by the `multisig_signers` as
+/// read-only signer accounts. Only the first `data_len` bytes of `data` are
+/// used as instruction data.
+struct InstructionParts<'a, const ACCOUNTS: usize, const DATA: usize> {
+    account_infos: [&'a AccountInfo; ACCOUNTS],
+    account_metas: [AccountMeta<'a>; ACCOUNTS],
+    multisig_signers: &'a [&'a AccountInfo],
+    data: [u8; DATA],
+    data_len: usize,
+}
+
+impl<'a, const ACCOUNTS: usize, const DATA: usize> InstructionParts<'a, ACCOUNTS, DATA> {
+    /// Creates the parts of an instruction using all bytes of `data`.
+    #[inline(always)]
+    fn new(
+        account_infos: [&'a AccountInfo; ACCOUNTS],
+        account_metas: [AccountMeta<'a>; ACCOUNTS],
+        multisig_signers: &'a [&'a AccountInfo],
+        data: [u8; DATA],
+    ) -> Self {
+        const { assert!(ACCOUNTS <= MAX_INSTRUCTION_ACCOUNTS) };
+
+        Self {
+            account_infos,
+            account_metas,
+            multisig_signers,
+            data,
+            data_len: DATA,
+        }
+    }
+
+    /// Restricts the instruction data to the first `data_len` bytes.
+    #[inline(always)]
+    fn with_data_len(mut self, data_len: usize) -> Self {
+        self.data_len = data_len;
+        self
+    }
+
+    /// Returns the instruction data.
+    #[inline(always)]
+    fn data(&self) -> &[u8] {
+        &self.data[..self.data_len]
+    }
+
+    /// Invokes the token program.
+    ///
+    /// Returns [`ProgramError::InvalidArgument`] if there are more than
+    /// [`MAX_SIGNERS`] multisig signers.
+    #[inline(always)]
+    fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        if self.multisig_signers.is_empty() {
+            return invoke_signed(
+                &Instruction {
+                    program_id: &crate::program::ID,
+                    accounts: &self.account_metas,
+                    data: self.data(),
+                },
+                &self.account_infos,
+                signers,
+            );
+        }
+
+        if self.multisig_signers.len() > MAX_SIGNERS as usize {
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut metas = [const { MaybeUninit::<AccountMeta>::uninit() }; MAX_ACCOUNTS];
+        let mut infos = [const { MaybeUninit::<&AccountInfo>::uninit() }; MAX_ACCOUNTS];
+
+        for (index, (info, meta)) in self
+            .account_infos
+            .iter()
+            .zip(self.account_metas.iter())
+            .enumerate()
+        {
+            metas[index].write(meta.clone());
+            infos[index].write(info);
+        }
+
+        for (index, signer) in self.multisig_signers.iter().enumerate() {
+            metas[ACCOUNTS + index].write(AccountMeta::readonly_signer(signer.key()));
+            infos[ACCOUNTS + index].write(signer);
+        }
+
+        let num_accounts = ACCOUNTS + self.multisig_signers.len();
+
+        // SAFETY: The first `num_accounts` elements of both arrays were initialized
+        // above.
+        let (metas, infos) = unsafe {
+            (
+                from_raw_parts(metas.as_ptr() as *const AccountMeta, num_accounts),
+                from_raw_parts(infos.as_ptr() as *const &AccountInfo, num_accounts),
+            )
+        };
+
+        invoke_signed_with_bounds::<MAX_ACCOUNTS>(
+            &Instruction {
+                program_id: &crate::program::ID,
+                accounts: metas,
+                data: self.data(),
+            },
+            infos,
+            signers,
+        )
+    }
+}
+
+/// Returns the account meta for an authority, which must sign the instruction
+/// unless it is a multisig account.
+#[inline(always)]
+fn authority_meta<'a>(
+    authority: &'a AccountInfo,
+    multisig_signers: &[&AccountInfo],
+) -> AccountMeta<'a> {
+    AccountMeta::new(authority.key(), false, multisig_signers.is_empty())
+}
+
+/// Writes an `amount` argument after the instruction discriminator.
+#[inline(always)]
+fn amount_data(discriminator: u8, amount: u64) -> [u8; 9] {
+    let mut data = [0; 9];
+    data[0] = discriminator;
+    data[1..].copy_from_slice(&amount.to_le_bytes());
+    data
+}
+
+/// Writes `amount` and `decimals` arguments after the instruction
+/// discriminator.
+#[inline(always)]
+fn amount_decimals_data(discriminator: u8, amount: u64, decimals: u8) -> [u8; 10] {
+    let mut data = [0; 10];
+    data[0] = discriminator;
+    data[1..9].copy_from_slice(&amount.to_le_bytes());
+    data[9] = decimals;
+    data
+}
@@ -0,0 +1,56 @@
+use {
+    super::{authority_meta, BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Revokes the delegate's authority.
+pub struct Revoke<'a> {
+    /// Source account.
+    pub source: &'a AccountInfo,
+    /// Source account owner.
+    pub owner: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+}
+
+impl<'a> Revoke<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 2, 1> {
+        InstructionParts::new(
+            [self.source, self.owner],
+            [
+                AccountMeta::writable(self.source.key()),
+                authority_meta(self.owner, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            [TokenInstruction::Revoke as u8],
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for Revoke<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
@@ -0,0 +1,79 @@
+use {
+    super::{authority_meta, BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::{AuthorityType, TokenInstruction},
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        pubkey::Pubkey,
+        ProgramResult,
+    },
+};
+
+/// Sets a new authority of a mint or account.
+pub struct SetAuthority<'a> {
+    /// Mint or account to change the authority of.
+    pub account: &'a AccountInfo,
+    /// Current authority of the mint or account.
+    pub authority: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+    /// The type of authority to update.
+    pub authority_type: AuthorityType,
+    /// The new authority.
+    pub new_authority: Option<&'a Pubkey>,
+}
+
+impl<'a> SetAuthority<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 2, 35> {
+        // instruction data
+        // - [0]: instruction discriminator (1 byte, u8)
+        // - [1]: authority_type (1 byte, u8)
+        // - [2]: new_authority presence flag (1 byte, u8)
+        // - [3..35]: new_authority (optional, 32 bytes, Pubkey)
+        let mut data = [0; 35];
+        data[0] = TokenInstruction::SetAuthority as u8;
+        data[1] = self.authority_type.clone() as u8;
+
+        let len = if let Some(new_authority) = self.new_authority {
+            data[2] = 1;
+            data[3..].copy_from_slice(new_authority);
+            35
+        } else {
+            3
+        };
+
+        InstructionParts::new(
+            [self.account, self.authority],
+            [
+                AccountMeta::writable(self.account.key()),
+                authority_meta(self.authority, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            data,
+        )
+        .with_data_len(len)
+    }
+}
+
+impl<'a> BatchInstruction<'a> for SetAuthority<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
@@ -0,0 +1,49 @@
+use {
+    super::{BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Syncs the amount of a native account with its wrapped lamports.
+pub struct SyncNative<'a> {
+    /// Native account to sync.
+    pub account: &'a AccountInfo,
+}
+
+impl<'a> SyncNative<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 1, 1> {
+        InstructionParts::new(
+            [self.account],
+            [AccountMeta::writable(self.account.key())],
+            &[],
+            [TokenInstruction::SyncNative as u8],
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for SyncNative<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
@@ -0,0 +1,77 @@
+use {
+    super::{authority_meta, BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Transfers lamports out of a native account.
+pub struct UnwrapLamports<'a> {
+    /// Native source account.
+    pub source: &'a AccountInfo,
+    /// Destination account.
+    pub destination: &'a AccountInfo,
+    /// Source account owner.
+    pub authority: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+    /// Amount of lamports to unwrap; all the wrapped lamports if `None`.
+    pub amount: Option<u64>,
+}
+
+impl<'a> UnwrapLamports<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 3, 10> {
+        // instruction data
+        // - [0]: instruction discriminator (1 byte, u8)
+        // - [1]: amount presence flag (1 byte, u8)
+        // - [2..10]: amount (optional, 8 bytes, u64)
+        let mut data = [0; 10];
+        data[0] = TokenInstruction::UnwrapLamports as u8;
+
+        let len = if let Some(amount) = self.amount {
+            data[1] = 1;
+            data[2..].copy_from_slice(&amount.to_le_bytes());
+            10
+        } else {
+            2
+        };
+
+        InstructionParts::new(
+            [self.source, self.destination, self.authority],
+            [
+                AccountMeta::writable(self.source.key()),
+                AccountMeta::writable(self.destination.key()),
+                authority_meta(self.authority, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            data,
+        )
+        .with_data_len(len)
+    }
+}
+
+impl<'a> BatchInstruction<'a> for UnwrapLamports<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
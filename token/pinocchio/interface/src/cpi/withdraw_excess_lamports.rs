@@ -0,0 +1,60 @@
+use {
+    super::{authority_meta, BatchBuilder, BatchInstruction, InstructionParts},
+    crate::instruction::TokenInstruction,
+    pinocchio::{
+        account_info::AccountInfo,
+        instruction::{AccountMeta, Signer},
+        ProgramResult,
+    },
+};
+
+/// Withdraws the lamports above the rent-exempt reserve of a mint, token or
+/// multisig account.
+pub struct WithdrawExcessLamports<'a> {
+    /// Source account.
+    pub source: &'a AccountInfo,
+    /// Destination account.
+    pub destination: &'a AccountInfo,
+    /// Source account authority.
+    pub authority: &'a AccountInfo,
+    /// Signer accounts if the authority is a multisig.
+    pub multisig_signers: &'a [&'a AccountInfo],
+}
+
+impl<'a> WithdrawExcessLamports<'a> {
+    /// Invokes the instruction.
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    /// Invokes the instruction with the given PDA signers.
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        self.parts().invoke_signed(signers)
+    }
+
+    /// Returns the accounts and data of the instruction.
+    #[inline(always)]
+    fn parts(&self) -> InstructionParts<'a, 3, 1> {
+        InstructionParts::new(
+            [self.source, self.destination, self.authority],
+            [
+                AccountMeta::writable(self.source.key()),
+                AccountMeta::writable(self.destination.key()),
+                authority_meta(self.authority, self.multisig_signers),
+            ],
+            self.multisig_signers,
+            [TokenInstruction::WithdrawExcessLamports as u8],
+        )
+    }
+}
+
+impl<'a> BatchInstruction<'a> for WithdrawExcessLamports<'a> {
+    #[inline(always)]
+    fn append_to<const ACCOUNTS: usize, const DATA: usize>(
+        &self,
+        batch: &mut BatchBuilder<'a, ACCOUNTS, DATA>,
+    ) -> ProgramResult {
+        batch.append(self.parts())
+    }
+}
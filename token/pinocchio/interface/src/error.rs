@@ -52,6 +52,14 @@ pub enum TokenError {
     MintDecimalsMismatch,
     /// Instruction does not support non-native tokens
     NonNativeNotSupported,
+
+    // 20
+    /// Delegation has expired.
+    DelegationExpired,
+    /// Transfer into the account requires a memo in the previous instruction.
+    NoMemo,
+    /// Mint is paused.
+    MintPaused,
 }
 
 impl From<TokenError> for ProgramError {
@@ -96,6 +104,11 @@ impl ToStr for TokenError {
             TokenError::NonNativeNotSupported => {
                 "Error: Instruction does not support non-native tokens"
             }
+            TokenError::DelegationExpired => "Error: Delegation has expired",
+            TokenError::NoMemo => {
+                "Error: Transfer into the account requires a memo in the previous instruction"
+            }
+            TokenError::MintPaused => "Error: Mint is paused",
         }
     }
 }
@@ -124,6 +137,9 @@ impl TryFrom<u32> for TokenError {
             17 => Ok(TokenError::AccountFrozen),
             18 => Ok(TokenError::MintDecimalsMismatch),
             19 => Ok(TokenError::NonNativeNotSupported),
+            20 => Ok(TokenError::DelegationExpired),
+            21 => Ok(TokenError::NoMemo),
+            22 => Ok(TokenError::MintPaused),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
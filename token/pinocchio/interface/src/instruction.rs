@@ -2,11 +2,75 @@
 
 use {crate::error::TokenError, pinocchio::program_error::ProgramError};
 
-/// Instructions supported by the token program.
-#[repr(u8)]
-#[derive(Clone, Debug, PartialEq)]
-#[cfg_attr(test, derive(strum_macros::FromRepr, strum_macros::EnumIter))]
-pub enum TokenInstruction {
+/// Declares the instruction set of the token program.
+///
+/// This is the single source of the instruction discriminators: the
+/// [`TokenInstruction`] enum, its `u8` conversion and the accounts that need
+/// an explicit ownership check when an instruction is executed as part of a
+/// batch are all generated from it, and the program dispatches on the
+/// generated variants. The `instruction_table` tests check that the IDL and the
+/// generated JS client agree with it.
+///
+/// Each entry has the form `Variant = discriminator => [indices]`, where the
+/// optional `[indices]` lists the positions of the accounts that the
+/// instruction writes without otherwise validating that they are owned by the
+/// token program.
+macro_rules! token_instructions {
+    (
+        $(
+            $(#[$attr:meta])*
+            $variant:ident = $discriminator:literal $(=> [$($index:literal),+])?,
+        )+
+    ) => {
+        /// Instructions supported by the token program.
+        #[repr(u8)]
+        #[derive(Clone, Debug, PartialEq)]
+        #[cfg_attr(test, derive(strum_macros::FromRepr, strum_macros::EnumIter))]
+        pub enum TokenInstruction {
+            $(
+                $(#[$attr])*
+                $variant = $discriminator,
+            )+
+        }
+
+        impl TokenInstruction {
+            /// All instructions supported by the token program.
+            pub const ALL: &'static [TokenInstruction] = &[$(TokenInstruction::$variant),+];
+
+            /// Returns the instruction with the given discriminator, if any.
+            #[inline(always)]
+            pub const fn from_discriminator(discriminator: u8) -> Option<Self> {
+                match discriminator {
+                    $($discriminator => Some(TokenInstruction::$variant),)+
+                    _ => None,
+                }
+            }
+
+            /// Returns the name of the instruction.
+            pub const fn name(&self) -> &'static str {
+                match self {
+                    $(TokenInstruction::$variant => stringify!($variant),)+
+                }
+            }
+
+            /// Returns the indices of the accounts that must be owned by the
+            /// token program when the instruction is executed as part of a
+            /// batch.
+            ///
+            /// Ownership is only enforced by the runtime at the end of the
+            /// batch processing, so these accounts need an explicit check. The
+            /// indices are in ascending order.
+            #[inline(always)]
+            pub const fn batch_owner_checks(&self) -> &'static [usize] {
+                match self {
+                    $(TokenInstruction::$variant => &[$($($index),+)?],)+
+                }
+            }
+        }
+    };
+}
+
+token_instructions! {
     /// Initializes a new mint and optionally deposits all the newly minted
     /// tokens in an account.
     ///
@@ -26,7 +90,7 @@ pub enum TokenInstruction {
     ///   - `u8` The number of base 10 digits to the right of the decimal place.
     ///   - `Pubkey` The authority/multisignature to mint tokens.
     ///   - `Option<Pubkey>` The freeze authority/multisignature of the mint.
-    InitializeMint,
+    InitializeMint = 0,
 
     /// Initializes a new account to hold tokens.  If this account is associated
     /// with the native mint then the token balance of the initialized account
@@ -46,7 +110,7 @@ pub enum TokenInstruction {
     ///   1. `[]` The mint this account will be associated with.
     ///   2. `[]` The new account's owner/multisignature.
     ///   3. `[]` Rent sysvar.
-    InitializeAccount,
+    InitializeAccount = 1,
 
     /// Initializes a multisignature account with N provided signers.
     ///
@@ -72,13 +136,20 @@ pub enum TokenInstruction {
     ///
     ///   - `u8` The number of signers (M) required to validate this
     ///     multisignature account.
-    InitializeMultisig,
+    InitializeMultisig = 2,
 
     /// Transfers tokens from one account to another either directly or via a
     /// delegate.  If this account is associated with the native mint then equal
     /// amounts of SOL and Tokens will be transferred to the destination
     /// account.
     ///
+    /// If the destination account requires memos, the instruction preceding
+    /// the top-level instruction in the transaction must be a memo and the
+    /// Instructions sysvar must be provided as the last account. Through a
+    /// cross-program invocation, the memo must precede the instruction of the
+    /// calling program; inside a `Batch` the transfer fails, since the memo
+    /// could not be attributed to it.
+    ///
     /// Accounts expected by this instruction:
     ///
     ///   * Single owner/delegate
@@ -95,7 +166,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     ///   - `u64` The amount of tokens to transfer.
-    Transfer,
+    Transfer = 3 => [0, 1],
 
     /// Approves a delegate.  A delegate is given the authority over tokens on
     /// behalf of the source account's owner.
@@ -116,7 +187,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     ///   - `u64` The amount of tokens the delegate is approved for.
-    Approve,
+    Approve = 4 => [0],
 
     /// Revokes the delegate's authority.
     ///
@@ -130,7 +201,7 @@ pub enum TokenInstruction {
     ///   0. `[writable]` The source account.
     ///   1. `[]` The source account's multisignature owner.
     ///   2. `..+M` `[signer]` M signer accounts.
-    Revoke,
+    Revoke = 5 => [0],
 
     /// Sets a new authority of a mint or account.
     ///
@@ -149,7 +220,7 @@ pub enum TokenInstruction {
     ///
     ///   - `AuthorityType` The type of authority to update.
     ///   - `Option<Pubkey>` The new authority.
-    SetAuthority,
+    SetAuthority = 6 => [0],
 
     /// Mints new tokens to an account.  The native mint does not support
     /// minting.
@@ -170,7 +241,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     ///   - `u64` The amount of new tokens to mint.
-    MintTo,
+    MintTo = 7 => [0, 1],
 
     /// Burns tokens by removing them from an account.  `Burn` does not support
     /// accounts associated with the native mint, use `CloseAccount` instead.
@@ -191,7 +262,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     ///   - `u64` The amount of tokens to burn.
-    Burn,
+    Burn = 8 => [0, 1],
 
     /// Close an account by transferring all its SOL to the destination account.
     /// Non-native accounts may only be closed if its token amount is zero.
@@ -208,7 +279,7 @@ pub enum TokenInstruction {
     ///   1. `[writable]` The destination account.
     ///   2. `[]` The account's multisignature owner.
     ///   3. `..+M` `[signer]` M signer accounts.
-    CloseAccount,
+    CloseAccount = 9 => [0],
 
     /// Freeze an Initialized account using the Mint's [`freeze_authority`] (if
     /// set).
@@ -225,7 +296,7 @@ pub enum TokenInstruction {
     ///   1. `[]` The token mint.
     ///   2. `[]` The mint's multisignature freeze authority.
     ///   3. `..+M` `[signer]` M signer accounts.
-    FreezeAccount,
+    FreezeAccount = 10 => [0],
 
     /// Thaw a Frozen account using the Mint's [`freeze_authority`] (if set).
     ///
@@ -241,7 +312,7 @@ pub enum TokenInstruction {
     ///   1. `[]` The token mint.
     ///   2. `[]` The mint's multisignature freeze authority.
     ///   3. `..+M` `[signer]` M signer accounts.
-    ThawAccount,
+    ThawAccount = 11 => [0],
 
     /// Transfers tokens from one account to another either directly or via a
     /// delegate.  If this account is associated with the native mint then equal
@@ -252,6 +323,13 @@ pub enum TokenInstruction {
     /// decimals value is checked by the caller.  This may be useful when
     /// creating transactions offline or within a hardware wallet.
     ///
+    /// If the destination account requires memos, the instruction preceding
+    /// the top-level instruction in the transaction must be a memo and the
+    /// Instructions sysvar must be provided as the last account. Through a
+    /// cross-program invocation, the memo must precede the instruction of the
+    /// calling program; inside a `Batch` the transfer fails, since the memo
+    /// could not be attributed to it.
+    ///
     /// Accounts expected by this instruction:
     ///
     ///   * Single owner/delegate
@@ -272,7 +350,7 @@ pub enum TokenInstruction {
     ///   - `u64` The amount of tokens to transfer.
     ///   - `u8` Expected number of base 10 digits to the right of the decimal
     ///     place.
-    TransferChecked,
+    TransferChecked = 12 => [0, 2],
 
     /// Approves a delegate.  A delegate is given the authority over tokens on
     /// behalf of the source account's owner.
@@ -301,7 +379,7 @@ pub enum TokenInstruction {
     ///   - `u64` The amount of tokens the delegate is approved for.
     ///   - `u8` Expected number of base 10 digits to the right of the decimal
     ///     place.
-    ApproveChecked,
+    ApproveChecked = 13 => [0],
 
     /// Mints new tokens to an account.  The native mint does not support
     /// minting.
@@ -328,7 +406,7 @@ pub enum TokenInstruction {
     ///   - `u64` The amount of new tokens to mint.
     ///   - `u8` Expected number of base 10 digits to the right of the decimal
     ///     place.
-    MintToChecked,
+    MintToChecked = 14 => [0, 1],
 
     /// Burns tokens by removing them from an account.  [`BurnChecked`] does not
     /// support accounts associated with the native mint, use `CloseAccount`
@@ -356,7 +434,7 @@ pub enum TokenInstruction {
     ///   - `u64` The amount of tokens to burn.
     ///   - `u8` Expected number of base 10 digits to the right of the decimal
     ///     place.
-    BurnChecked,
+    BurnChecked = 15 => [0, 1],
 
     /// Like [`InitializeAccount`], but the owner pubkey is passed via
     /// instruction data rather than the accounts list. This variant may be
@@ -372,7 +450,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     ///  - `Pubkey` The new account's owner/multisignature.
-    InitializeAccount2,
+    InitializeAccount2 = 16,
 
     /// Given a wrapped / native token account (a token account containing SOL)
     /// updates its amount field based on the account's underlying `lamports`.
@@ -384,7 +462,7 @@ pub enum TokenInstruction {
     ///
     ///   0. `[writable]`  The native token account to sync with its underlying
     ///      lamports.
-    SyncNative,
+    SyncNative = 17,
 
     /// Like [`InitializeAccount2`], but does not require the Rent sysvar to be
     /// provided
@@ -397,7 +475,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     /// - `Pubkey` The new account's owner/multisignature.
-    InitializeAccount3,
+    InitializeAccount3 = 18,
 
     /// Like [`InitializeMultisig`], but does not require the Rent sysvar to be
     /// provided
@@ -412,7 +490,7 @@ pub enum TokenInstruction {
     ///
     ///   - `u8` The number of signers (M) required to validate this
     ///     multisignature account.
-    InitializeMultisig2,
+    InitializeMultisig2 = 19,
 
     /// Like [`InitializeMint`], but does not require the Rent sysvar to be
     /// provided
@@ -426,7 +504,7 @@ pub enum TokenInstruction {
     ///   - `u8` The number of base 10 digits to the right of the decimal place.
     ///   - `Pubkey` The authority/multisignature to mint tokens.
     ///   - `Option<Pubkey>` The freeze authority/multisignature of the mint.
-    InitializeMint2,
+    InitializeMint2 = 20,
 
     /// Gets the required size of an account for the given mint as a
     /// little-endian `u64`.
@@ -437,7 +515,7 @@ pub enum TokenInstruction {
     /// Accounts expected by this instruction:
     ///
     ///   0. `[]` The mint to calculate for.
-    GetAccountDataSize,
+    GetAccountDataSize = 21,
 
     /// Initialize the Immutable Owner extension for the given token account
     ///
@@ -450,7 +528,7 @@ pub enum TokenInstruction {
     /// Accounts expected by this instruction:
     ///
     ///   0. `[writable]`  The account to initialize.
-    InitializeImmutableOwner,
+    InitializeImmutableOwner = 22 => [0],
 
     /// Convert an Amount of tokens to a `UiAmount` `string`, using the given
     /// mint. In this version of the program, the mint can only specify the
@@ -468,7 +546,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     ///   - `u64` The amount of tokens to reformat.
-    AmountToUiAmount,
+    AmountToUiAmount = 23,
 
     /// Convert a `UiAmount` of tokens to a little-endian `u64` raw Amount,
     /// using the given mint. In this version of the program, the mint can
@@ -484,7 +562,7 @@ pub enum TokenInstruction {
     /// Data expected by this instruction:
     ///
     ///   - `&str` The `ui_amount` of tokens to reformat.
-    UiAmountToAmount,
+    UiAmountToAmount = 24,
 
     /// This instruction is to be used to rescue SOL sent to any `TokenProgram`
     /// owned account by sending them to any other account, leaving behind only
@@ -496,7 +574,7 @@ pub enum TokenInstruction {
     ///   1. `[writable]` Destination account
     ///   2. `[signer]` Authority
     ///   3. `..+M` `[signer]` M signer accounts.
-    WithdrawExcessLamports = 38,
+    WithdrawExcessLamports = 38 => [0],
 
     /// Transfer lamports from a native SOL account to a destination account.
     ///
@@ -513,7 +591,254 @@ pub enum TokenInstruction {
     ///   - `Option<u64>` The amount of lamports to transfer. When an amount is
     ///     not specified, the entire balance of the source account will be
     ///     transferred.
-    UnwrapLamports = 45,
+    UnwrapLamports = 45 => [0],
+
+    /// Transfers tokens from one account to several destination accounts,
+    /// validating the source account's owner or delegate only once.
+    ///
+    /// The destination accounts must be of the same mint as the source
+    /// account. The ownership of all token accounts is validated explicitly.
+    ///
+    /// Each transfer into a destination account that requires memos needs its
+    /// own memo: one memo instruction per such transfer must immediately
+    /// precede the top-level instruction in the transaction, and the
+    /// Instructions sysvar must be provided as the last account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[signer]` The source account's owner/delegate.
+    ///   2. ..`2+N` `[writable]` The N destination accounts.
+    ///
+    ///   * Multisignature owner/delegate
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The source account's multisignature owner/delegate.
+    ///   2. ..`2+N` `[writable]` The N destination accounts.
+    ///   3. ..`2+N+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `[u64; N]` The amounts of tokens to transfer to each destination
+    ///     account.
+    TransferMany = 46,
+
+    /// Burns the remaining balance of a token account and closes it,
+    /// transferring all its SOL to the destination account.
+    ///
+    /// The authority must be the account's close authority, which must also
+    /// be the account's owner when the account has a non-zero balance.
+    /// Accounts owned by the system program or the incinerator can only be
+    /// closed to the incinerator, without an authority check.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The account to burn from and close.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The account's close authority.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The account to burn from and close.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The account's multisignature close authority.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    BurnAndClose = 47 => [0, 1],
+
+    /// Transfers the whole balance of a token account to a destination
+    /// account of the same mint and closes it, transferring all its SOL to
+    /// the recipient account.
+    ///
+    /// The authority must be able to both transfer the tokens and close the
+    /// account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[writable]` The recipient of the source account's SOL.
+    ///   3. `[signer]` The source account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[writable]` The recipient of the source account's SOL.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. ..`4+M` `[signer]` M signer accounts.
+    SweepAndClose = 48 => [0, 1],
+
+    /// Approves a delegate until the given expiry. The delegation account is
+    /// set as the delegate of the source account and records the actual
+    /// delegate together with the expiry.
+    ///
+    /// To use the delegation, the delegate provides the delegation account
+    /// as the authority, followed by the delegate's signer account(s). Once
+    /// the expiry is reached, the delegation can no longer be used.
+    ///
+    /// The delegation account must be owned by the token program and have
+    /// enough lamports to be rent exempt. It can be reused to approve the
+    /// same source account again and closed with `CloseDelegation`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation account.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation account.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens the delegate is approved for.
+    ///   - `u8` The type of the expiry (`0` for a slot, `1` for a unix
+    ///     timestamp).
+    ///   - `u64` or `i64` The expiry.
+    ApproveWithExpiry = 49 => [0],
+
+    /// Approves a delegate with a recurring spending limit. The spending limit
+    /// account is set as the delegate of the source account and records the
+    /// actual delegate together with the amount of tokens it can spend per
+    /// epoch.
+    ///
+    /// To use the spending limit, the delegate provides the spending limit
+    /// account as the (writable) authority, followed by the delegate's signer
+    /// account(s). Transfers and burns are checked against the amount left in
+    /// the current epoch, which is refilled at the start of each epoch;
+    /// transfers to the source account itself do not consume it.
+    ///
+    /// The spending limit account must be owned by the token program and have
+    /// enough lamports to be rent exempt. It can be reused to approve the
+    /// same source account again, which resets the spent amount, and closed
+    /// with `CloseDelegation`.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The spending limit account.
+    ///   2. `[]` The delegate.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The spending limit account.
+    ///   2. `[]` The delegate.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `u64` The amount of tokens the delegate can spend per epoch.
+    ApproveWithSpendingLimit = 50 => [0],
+
+    /// Sets whether transfers into a token account require a memo. When
+    /// required, `Transfer`, `TransferChecked` and `TransferMany` into the
+    /// account fail unless each transfer is preceded by its own memo
+    /// instruction and is not part of a `Batch`.
+    ///
+    /// Native accounts do not support the memo requirement.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The account to update.
+    ///   1. `[signer]` The account's owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The account to update.
+    ///   1. `[]` The account's multisignature owner.
+    ///   2. `..+M` `[signer]` M signer accounts.
+    ///
+    /// Data expected by this instruction:
+    ///
+    ///   - `bool` Whether incoming transfers require a memo.
+    SetMemoRequired = 51 => [0],
+
+    /// Pauses a mint using the mint's freeze authority. While a mint is
+    /// paused, `TransferChecked`, `MintTo`, `MintToChecked`, `Burn`,
+    /// `BurnChecked`, `BurnAndClose` and `ApproveChecked` on its accounts
+    /// fail.
+    ///
+    /// Instructions that do not take the mint account, such as `Transfer`
+    /// and `Approve`, cannot observe the pause.
+    ///
+    /// The freeze authority of the mint is recorded in the mint pause
+    /// account and replaced by `PAUSED_FREEZE_AUTHORITY`, so the mint keeps
+    /// its layout. Until the mint is resumed, `FreezeAccount`, `ThawAccount`
+    /// and `SetAuthority` on the freeze authority fail.
+    ///
+    /// The mint pause account must be owned by the token program and have
+    /// enough lamports to be rent exempt.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The mint to pause.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[signer]` The mint freeze authority.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The mint to pause.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[]` The mint's multisignature freeze authority.
+    ///   3. `..+M` `[signer]` M signer accounts.
+    PauseMint = 52 => [0],
+
+    /// Resumes a paused mint using the freeze authority recorded in its mint
+    /// pause account, which is restored as the freeze authority of the mint.
+    /// The mint pause account is closed and its lamports are transferred to
+    /// the destination account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The mint to resume.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The mint freeze authority.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The mint to resume.
+    ///   1. `[writable]` The mint pause account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The mint's multisignature freeze authority.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    ResumeMint = 53 => [0],
+
+    /// Closes a delegation or spending limit account of a token account,
+    /// transferring its lamports to the destination account. When the closed
+    /// account is the delegate of the token account, the delegation is also
+    /// revoked.
+    ///
+    /// Only the owner of the token account can close the delegation or
+    /// spending limit account, so it should be closed before the token
+    /// account.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   * Single owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation or spending limit account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[signer]` The source account owner.
+    ///
+    ///   * Multisignature owner
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The delegation or spending limit account.
+    ///   2. `[writable]` The destination account.
+    ///   3. `[]` The source account's multisignature owner.
+    ///   4. `..+M` `[signer]` M signer accounts.
+    CloseDelegation = 54 => [0],
 
     /// Executes a batch of instructions. The instructions to be executed are
     /// specified in sequence on the instruction data. Each instruction
@@ -545,11 +870,7 @@ impl TryFrom<u8> for TokenInstruction {
     type Error = ProgramError;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match value {
-            // SAFETY: `value` is guaranteed to be in the range of the enum variants.
-            0..=24 | 38 | 255 => Ok(unsafe { core::mem::transmute::<u8, TokenInstruction>(value) }),
-            _ => Err(TokenError::InvalidInstruction.into()),
-        }
+        TokenInstruction::from_discriminator(value).ok_or(TokenError::InvalidInstruction.into())
     }
 }
 
@@ -600,6 +921,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_token_instruction_from_discriminator() {
+        for discriminator in 0..=u8::MAX {
+            assert_eq!(
+                TokenInstruction::from_repr(discriminator),
+                TokenInstruction::from_discriminator(discriminator)
+            );
+        }
+        assert_eq!(
+            TokenInstruction::ALL.len(),
+            TokenInstruction::iter().count()
+        );
+    }
+
     #[test]
     fn test_authority_type_from_u8_exhaustive() {
         for variant in AuthorityType::iter() {
@@ -1,7 +1,9 @@
 #![no_std]
 
+pub mod cpi;
 pub mod error;
 pub mod instruction;
+pub mod memo;
 pub mod native_mint;
 pub mod state;
 
@@ -0,0 +1,15 @@
+//! The Memo programs accepted before transfers into accounts that require
+//! memos.
+
+use pinocchio::pubkey::Pubkey;
+
+/// The Memo program.
+pub const ID: Pubkey = pinocchio_pubkey::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// The legacy (v1) Memo program.
+pub const V1_ID: Pubkey = pinocchio_pubkey::pubkey!("Memo1UhkJRfHyvLMcVucJwxXeuD728EqVDDwQDxFMNo");
+
+#[inline(always)]
+pub fn is_memo_program(program_id: &Pubkey) -> bool {
+    program_id == &ID || program_id == &V1_ID
+}
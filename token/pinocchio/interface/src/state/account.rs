@@ -40,6 +40,9 @@ pub struct Account {
     /// rent-exempt reserve. An Account is required to be rent-exempt, so
     /// the value is used by the Processor to ensure that wrapped SOL
     /// accounts do not drop below this threshold.
+    ///
+    /// Otherwise, the first byte is the memo-required flag, which is ignored
+    /// when reading `is_native` as an `Option<u64>`.
     native_amount: [u8; 8],
 
     /// The amount delegated.
@@ -114,6 +117,20 @@ impl Account {
         }
     }
 
+    /// Sets whether incoming transfers require a memo.
+    ///
+    /// This must only be called on non-native accounts, since the flag is
+    /// stored in the (otherwise unused) native amount.
+    #[inline(always)]
+    pub fn set_memo_required(&mut self, value: bool) {
+        self.native_amount[0] = value as u8;
+    }
+
+    #[inline(always)]
+    pub fn is_memo_required(&self) -> bool {
+        !self.is_native() && self.native_amount[0] == 1
+    }
+
     #[inline(always)]
     pub fn set_delegated_amount(&mut self, amount: u64) {
         self.delegated_amount = amount.to_le_bytes();
@@ -0,0 +1,96 @@
+use {
+    super::{Initializable, Transmutable},
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey, sysvars::clock::Clock},
+};
+
+/// Type of a delegation expiry.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ExpiryType {
+    /// The expiry is a slot.
+    Slot,
+
+    /// The expiry is a unix timestamp.
+    UnixTimestamp,
+}
+
+impl TryFrom<u8> for ExpiryType {
+    type Error = ProgramError;
+
+    #[inline(always)]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            // SAFETY: `value` is guaranteed to be in the range of the enum variants.
+            0..=1 => Ok(unsafe { core::mem::transmute::<u8, ExpiryType>(value) }),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
+
+/// Delegation with an expiry.
+///
+/// The delegation account is set as the delegate of the token account, while
+/// the actual delegate is recorded in the delegation account together with
+/// the expiry.
+#[repr(C)]
+pub struct Delegation {
+    /// Is `true` if this structure has been initialized.
+    is_initialized: u8,
+
+    /// The token account of the delegation.
+    pub account: Pubkey,
+
+    /// The delegate of the token account.
+    pub delegate: Pubkey,
+
+    /// Type of the expiry.
+    expiry_type: u8,
+
+    /// The expiry of the delegation, either a slot (`u64`) or a unix
+    /// timestamp (`i64`).
+    expiry: [u8; 8],
+}
+
+impl Delegation {
+    #[inline]
+    pub fn set_initialized(&mut self, value: bool) {
+        self.is_initialized = value as u8;
+    }
+
+    #[inline(always)]
+    pub fn set_expiry(&mut self, expiry_type: ExpiryType, expiry: [u8; 8]) {
+        self.expiry_type = expiry_type as u8;
+        self.expiry = expiry;
+    }
+
+    #[inline(always)]
+    pub fn expiry_type(&self) -> Result<ExpiryType, ProgramError> {
+        ExpiryType::try_from(self.expiry_type)
+    }
+
+    /// Returns `true` if the delegation can no longer be used, i.e., the
+    /// current slot or unix timestamp has reached the expiry.
+    #[inline(always)]
+    pub fn is_expired(&self, clock: &Clock) -> Result<bool, ProgramError> {
+        Ok(match self.expiry_type()? {
+            ExpiryType::Slot => clock.slot >= u64::from_le_bytes(self.expiry),
+            ExpiryType::UnixTimestamp => clock.unix_timestamp >= i64::from_le_bytes(self.expiry),
+        })
+    }
+}
+
+unsafe impl Transmutable for Delegation {
+    /// The length of the `Delegation` account data.
+    const LEN: usize = core::mem::size_of::<Delegation>();
+}
+
+impl Initializable for Delegation {
+    #[inline(always)]
+    fn is_initialized(&self) -> Result<bool, ProgramError> {
+        match self.is_initialized {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
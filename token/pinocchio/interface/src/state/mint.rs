@@ -3,6 +3,13 @@ use {
     pinocchio::{program_error::ProgramError, pubkey::Pubkey},
 };
 
+/// The freeze authority of a paused mint.
+///
+/// Pausing a mint moves its freeze authority to a mint pause account and sets
+/// this address, which has no private key, as the freeze authority.
+pub const PAUSED_FREEZE_AUTHORITY: Pubkey =
+    pinocchio_pubkey::pubkey!("MintPaused111111111111111111111111111111111");
+
 /// Internal representation of a mint data.
 #[repr(C)]
 pub struct Mint {
@@ -82,6 +89,14 @@ impl Mint {
             None
         }
     }
+
+    /// Returns `true` if the mint is paused, i.e., its freeze authority is
+    /// [`PAUSED_FREEZE_AUTHORITY`].
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        // Avoid using `pubkey_eq` since the comparison is against a constant.
+        self.freeze_authority.0[0] == 1 && self.freeze_authority.1 == PAUSED_FREEZE_AUTHORITY
+    }
 }
 
 unsafe impl Transmutable for Mint {
@@ -0,0 +1,43 @@
+use {
+    super::{Initializable, Transmutable},
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Pause of a mint.
+///
+/// While the mint is paused, the mint pause account records the actual
+/// freeze authority of the mint, which is restored when the mint is resumed.
+#[repr(C)]
+pub struct MintPause {
+    /// Is `true` if this structure has been initialized.
+    is_initialized: u8,
+
+    /// The paused mint.
+    pub mint: Pubkey,
+
+    /// The freeze authority of the mint.
+    pub freeze_authority: Pubkey,
+}
+
+impl MintPause {
+    #[inline]
+    pub fn set_initialized(&mut self, value: bool) {
+        self.is_initialized = value as u8;
+    }
+}
+
+unsafe impl Transmutable for MintPause {
+    /// The length of the `MintPause` account data.
+    const LEN: usize = core::mem::size_of::<MintPause>();
+}
+
+impl Initializable for MintPause {
+    #[inline(always)]
+    fn is_initialized(&self) -> Result<bool, ProgramError> {
+        match self.is_initialized {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
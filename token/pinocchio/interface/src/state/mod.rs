@@ -2,8 +2,11 @@ use pinocchio::program_error::ProgramError;
 
 pub mod account;
 pub mod account_state;
+pub mod delegation;
 pub mod mint;
+pub mod mint_pause;
 pub mod multisig;
+pub mod spending_limit;
 
 /// Type alias for fields represented as `COption`.
 pub type COption<T> = ([u8; 4], T);
@@ -0,0 +1,95 @@
+use {
+    super::{Initializable, Transmutable},
+    pinocchio::{program_error::ProgramError, pubkey::Pubkey},
+};
+
+/// Delegation with a recurring spending limit.
+///
+/// The spending limit account is set as the delegate of the token account,
+/// while the actual delegate is recorded in the spending limit account
+/// together with the amount it can spend per epoch. The spent amount is
+/// reset at the start of each epoch.
+#[repr(C)]
+pub struct SpendingLimit {
+    /// Is `true` if this structure has been initialized.
+    is_initialized: u8,
+
+    /// The token account of the spending limit.
+    pub account: Pubkey,
+
+    /// The delegate of the token account.
+    pub delegate: Pubkey,
+
+    /// The amount of tokens the delegate can spend per epoch.
+    limit: [u8; 8],
+
+    /// The epoch of the spent amount.
+    epoch: [u8; 8],
+
+    /// The amount of tokens spent in the epoch.
+    spent: [u8; 8],
+}
+
+impl SpendingLimit {
+    #[inline]
+    pub fn set_initialized(&mut self, value: bool) {
+        self.is_initialized = value as u8;
+    }
+
+    #[inline]
+    pub fn set_limit(&mut self, limit: u64) {
+        self.limit = limit.to_le_bytes();
+    }
+
+    #[inline]
+    pub fn limit(&self) -> u64 {
+        u64::from_le_bytes(self.limit)
+    }
+
+    #[inline]
+    pub fn set_epoch(&mut self, epoch: u64) {
+        self.epoch = epoch.to_le_bytes();
+    }
+
+    #[inline]
+    pub fn epoch(&self) -> u64 {
+        u64::from_le_bytes(self.epoch)
+    }
+
+    #[inline]
+    pub fn set_spent(&mut self, spent: u64) {
+        self.spent = spent.to_le_bytes();
+    }
+
+    #[inline]
+    pub fn spent(&self) -> u64 {
+        u64::from_le_bytes(self.spent)
+    }
+
+    /// Returns the amount of tokens that can still be spent in the given
+    /// epoch.
+    #[inline(always)]
+    pub fn remaining(&self, epoch: u64) -> u64 {
+        if self.epoch() == epoch {
+            self.limit().saturating_sub(self.spent())
+        } else {
+            self.limit()
+        }
+    }
+}
+
+unsafe impl Transmutable for SpendingLimit {
+    /// The length of the `SpendingLimit` account data.
+    const LEN: usize = core::mem::size_of::<SpendingLimit>();
+}
+
+impl Initializable for SpendingLimit {
+    #[inline(always)]
+    fn is_initialized(&self) -> Result<bool, ProgramError> {
+        match self.is_initialized {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ProgramError::InvalidAccountData),
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Tests for the `BatchBuilder` of the `cpi` module.
+
+use {
+    pinocchio::{
+        account_info::AccountInfo, entrypoint::NON_DUP_MARKER, program_error::ProgramError,
+        pubkey::Pubkey,
+    },
+    pinocchio_token_interface::cpi::{BatchBuilder, CloseAccount, MintToChecked, Transfer},
+    solana_pubkey::Pubkey as SolanaPubkey,
+};
+
+/// Memory layout of an account as serialized by the runtime.
+#[repr(C, align(8))]
+struct RawAccount {
+    borrow_state: u8,
+    is_signer: u8,
+    is_writable: u8,
+    executable: u8,
+    resize_delta: i32,
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    data_len: u64,
+}
+
+impl RawAccount {
+    fn new(key: Pubkey, is_signer: bool) -> Self {
+        Self {
+            borrow_state: NON_DUP_MARKER,
+            is_signer: is_signer as u8,
+            is_writable: 1,
+            executable: 0,
+            resize_delta: 0,
+            key,
+            owner: pinocchio_token_interface::program::ID,
+            lamports: 0,
+            data_len: 0,
+        }
+    }
+
+    fn info(&mut self) -> AccountInfo {
+        // SAFETY: `AccountInfo` is a `repr(C)` wrapper around a pointer to the
+        // account memory layout, which `RawAccount` reproduces.
+        unsafe { core::mem::transmute::<*mut RawAccount, AccountInfo>(self) }
+    }
+}
+
+fn solana_pubkey(key: &Pubkey) -> SolanaPubkey {
+    SolanaPubkey::new_from_array(*key)
+}
+
+#[test]
+fn test_batch_builder() {
+    let mut source = RawAccount::new([1; 32], false);
+    let mut destination = RawAccount::new([2; 32], false);
+    let mut mint = RawAccount::new([3; 32], false);
+    let mut owner = RawAccount::new([4; 32], true);
+
+    let (source, destination, mint, owner) =
+        (source.info(), destination.info(), mint.info(), owner.info());
+
+    let mut batch = BatchBuilder::<10, 64>::new();
+    assert!(batch.is_empty());
+
+    batch
+        .push(&MintToChecked {
+            mint: &mint,
+            account: &source,
+            mint_authority: &owner,
+            multisig_signers: &[],
+            amount: 1_000,
+            decimals: 6,
+        })
+        .unwrap();
+    batch
+        .push(&Transfer {
+            source: &source,
+            destination: &destination,
+            authority: &owner,
+            multisig_signers: &[],
+            amount: 500,
+        })
+        .unwrap();
+    batch
+        .push(&CloseAccount {
+            account: &source,
+            destination: &owner,
+            authority: &owner,
+            multisig_signers: &[],
+        })
+        .unwrap();
+
+    assert!(!batch.is_empty());
+
+    let (source_key, destination_key, mint_key, owner_key) = (
+        solana_pubkey(source.key()),
+        solana_pubkey(destination.key()),
+        solana_pubkey(mint.key()),
+        solana_pubkey(owner.key()),
+    );
+
+    let expected = spl_token_interface::instruction::batch(vec![
+        spl_token_interface::instruction::mint_to_checked(
+            &spl_token_interface::ID,
+            &mint_key,
+            &source_key,
+            &owner_key,
+            &[],
+            1_000,
+            6,
+        )
+        .unwrap(),
+        spl_token_interface::instruction::transfer(
+            &spl_token_interface::ID,
+            &source_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            500,
+        )
+        .unwrap(),
+        spl_token_interface::instruction::close_account(
+            &spl_token_interface::ID,
+            &source_key,
+            &owner_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+    ])
+    .unwrap();
+
+    assert_eq!(batch.data(), expected.data.as_slice());
+    assert_eq!(batch.account_metas().len(), expected.accounts.len());
+    assert_eq!(batch.account_infos().len(), expected.accounts.len());
+
+    for ((meta, info), expected) in batch
+        .account_metas()
+        .iter()
+        .zip(batch.account_infos())
+        .zip(&expected.accounts)
+    {
+        assert_eq!(meta.pubkey, info.key());
+        assert_eq!(solana_pubkey(meta.pubkey), expected.pubkey);
+        assert_eq!(meta.is_signer, expected.is_signer);
+        assert_eq!(meta.is_writable, expected.is_writable);
+    }
+
+    batch.invoke().unwrap();
+}
+
+#[test]
+fn test_batch_builder_with_multisig() {
+    let mut source = RawAccount::new([1; 32], false);
+    let mut destination = RawAccount::new([2; 32], false);
+    let mut multisig = RawAccount::new([3; 32], false);
+    let mut signer1 = RawAccount::new([4; 32], true);
+    let mut signer2 = RawAccount::new([5; 32], true);
+
+    let (source, destination, multisig, signer1, signer2) = (
+        source.info(),
+        destination.info(),
+        multisig.info(),
+        signer1.info(),
+        signer2.info(),
+    );
+
+    let signers = [&signer1, &signer2];
+    let mut batch = BatchBuilder::<5, 16>::new();
+
+    batch
+        .push(&Transfer {
+            source: &source,
+            destination: &destination,
+            authority: &multisig,
+            multisig_signers: &signers,
+            amount: 42,
+        })
+        .unwrap();
+
+    let expected =
+        spl_token_interface::instruction::batch(vec![spl_token_interface::instruction::transfer(
+            &spl_token_interface::ID,
+            &solana_pubkey(source.key()),
+            &solana_pubkey(destination.key()),
+            &solana_pubkey(multisig.key()),
+            &[&solana_pubkey(signer1.key()), &solana_pubkey(signer2.key())],
+            42,
+        )
+        .unwrap()])
+        .unwrap();
+
+    assert_eq!(batch.data(), expected.data.as_slice());
+
+    for (meta, expected) in batch.account_metas().iter().zip(&expected.accounts) {
+        assert_eq!(solana_pubkey(meta.pubkey), expected.pubkey);
+        assert_eq!(meta.is_signer, expected.is_signer);
+        assert_eq!(meta.is_writable, expected.is_writable);
+    }
+}
+
+#[test]
+fn test_batch_builder_capacity() {
+    let mut source = RawAccount::new([1; 32], false);
+    let mut destination = RawAccount::new([2; 32], false);
+    let mut owner = RawAccount::new([3; 32], true);
+
+    let (source, destination, owner) = (source.info(), destination.info(), owner.info());
+
+    let transfer = Transfer {
+        source: &source,
+        destination: &destination,
+        authority: &owner,
+        multisig_signers: &[],
+        amount: 1,
+    };
+
+    // Not enough space for the accounts.
+
+    let mut batch = BatchBuilder::<2, 64>::new();
+    assert_eq!(batch.push(&transfer), Err(ProgramError::InvalidArgument));
+    assert!(batch.is_empty());
+
+    // Not enough space for the instruction data: 1 (discriminator) + 2 (header)
+    // + 9 (data).
+
+    let mut batch = BatchBuilder::<3, 11>::new();
+    assert_eq!(batch.push(&transfer), Err(ProgramError::InvalidArgument));
+    assert!(batch.is_empty());
+
+    let mut batch = BatchBuilder::<3, 12>::new();
+    assert_eq!(batch.push(&transfer), Ok(()));
+    assert_eq!(batch.data().len(), 12);
+
+    // Too many multisig signers.
+
+    let signers = [&owner; 12];
+    let mut batch = BatchBuilder::<16, 64>::new();
+    assert_eq!(
+        batch.push(&Transfer {
+            multisig_signers: &signers,
+            ..transfer
+        }),
+        Err(ProgramError::InvalidArgument)
+    );
+    assert!(batch.is_empty());
+}
+
+#[test]
+fn test_batch_builder_empty() {
+    let batch = BatchBuilder::<2, 8>::new();
+
+    assert_eq!(batch.data(), &[255]);
+    assert_eq!(batch.invoke(), Err(ProgramError::InvalidInstructionData));
+}
@@ -0,0 +1,140 @@
+//! Checks that the instruction table of the interface agrees with the IDL and
+//! the generated JS client.
+//!
+//! The IDL and the JS client live outside of the crate, so these checks only
+//! run from the repository and are skipped for the packaged crate.
+
+use {
+    pinocchio_token_interface::instruction::TokenInstruction,
+    serde_json::Value,
+    std::path::{Path, PathBuf},
+};
+
+/// Path of the IDL, relative to the root of the repository.
+const IDL: &str = "program/idl.json";
+
+/// Path of the generated JS instructions, relative to the root of the
+/// repository.
+const JS_INSTRUCTIONS: &str = "clients/js/src/generated/instructions";
+
+/// Returns the path of a file of the repository, or `None` when the crate is
+/// not built from the repository.
+fn repository_path(path: &str) -> Option<PathBuf> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+    root.join("Cargo.toml").exists().then(|| root.join(path))
+}
+
+/// Converts a `PascalCase` variant name to the `camelCase` used by the IDL.
+fn camel_case(name: &str) -> String {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .map(|first| first.to_ascii_lowercase().to_string() + chars.as_str())
+        .unwrap_or_default()
+}
+
+/// Converts a `PascalCase` variant name to the `SCREAMING_SNAKE_CASE` used by
+/// the generated JS constants.
+fn screaming_snake_case(name: &str) -> String {
+    let mut result = String::new();
+    let chars = name.chars().collect::<Vec<_>>();
+
+    for (i, c) in chars.iter().enumerate() {
+        let boundary = i > 0 && c.is_ascii_uppercase() && !chars[i - 1].is_ascii_uppercase();
+        if boundary {
+            result.push('_');
+        }
+        result.push(c.to_ascii_uppercase());
+    }
+
+    result
+}
+
+fn idl_instructions(path: &Path) -> Vec<(String, u8)> {
+    let idl: Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+    idl["program"]["instructions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|instruction| {
+            let name = instruction["name"].as_str().unwrap().to_string();
+            let discriminator = instruction["arguments"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|argument| argument["name"] == "discriminator")
+                .and_then(|argument| argument["defaultValue"]["number"].as_u64())
+                .unwrap();
+            (name, u8::try_from(discriminator).unwrap())
+        })
+        .collect()
+}
+
+#[test]
+fn test_table_is_consistent() {
+    for (index, instruction) in TokenInstruction::ALL.iter().enumerate() {
+        let discriminator = instruction.clone() as u8;
+
+        assert_eq!(
+            TokenInstruction::from_discriminator(discriminator).as_ref(),
+            Some(instruction)
+        );
+        assert!(TokenInstruction::ALL[..index]
+            .iter()
+            .all(|other| other.name() != instruction.name()));
+
+        let owner_checks = instruction.batch_owner_checks();
+        assert!(owner_checks.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    assert!(TokenInstruction::Batch.batch_owner_checks().is_empty());
+}
+
+#[test]
+fn test_table_matches_idl() {
+    let Some(path) = repository_path(IDL) else {
+        return;
+    };
+    let idl_instructions = idl_instructions(&path);
+
+    for (name, discriminator) in &idl_instructions {
+        let instruction = TokenInstruction::from_discriminator(*discriminator)
+            .unwrap_or_else(|| panic!("IDL instruction `{name}` ({discriminator}) is missing"));
+        assert_eq!(&camel_case(instruction.name()), name);
+    }
+
+    for instruction in TokenInstruction::ALL {
+        let name = camel_case(instruction.name());
+        assert!(
+            idl_instructions.contains(&(name.clone(), instruction.clone() as u8)),
+            "instruction `{name}` is missing from the IDL"
+        );
+    }
+}
+
+#[test]
+fn test_table_matches_js_client() {
+    let Some(directory) = repository_path(JS_INSTRUCTIONS) else {
+        return;
+    };
+
+    for instruction in TokenInstruction::ALL {
+        let name = instruction.name();
+        let path = directory.join(format!("{}.ts", camel_case(name)));
+
+        let source = std::fs::read_to_string(&path)
+            .unwrap_or_else(|_| panic!("{} is missing from the JS client", path.display()));
+
+        let constant = format!(
+            "export const {}_DISCRIMINATOR = {};",
+            screaming_snake_case(name),
+            instruction.clone() as u8
+        );
+        assert!(
+            source.contains(&constant),
+            "{} is missing `{constant}`",
+            path.display()
+        );
+    }
+}
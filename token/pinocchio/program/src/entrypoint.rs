@@ -5,12 +5,13 @@ use {
         slice::from_raw_parts,
     },
     pinocchio::{
-        account_info::AccountInfo,
+        account_info::{AccountInfo, MAX_PERMITTED_DATA_INCREASE},
         entrypoint::{deserialize, NON_DUP_MARKER},
         hint::likely,
         log::sol_log,
         no_allocator, nostd_panic_handler,
         program_error::{ProgramError, ToStr},
+        pubkey::Pubkey,
         ProgramResult, MAX_TX_ACCOUNTS, SUCCESS,
     },
     pinocchio_token_interface::{
@@ -25,83 +26,195 @@ no_allocator!();
 // Use the no_std panic handler.
 nostd_panic_handler!();
 
-/// Custom program entrypoint to give priority to `transfer` and
-/// `transfer_checked` instructions.
+/// Offset of the first account in the input buffer, right after the number
+/// of accounts.
+const ACCOUNT1_HEADER_OFFSET: usize = size_of::<u64>();
+
+/// Length of the serialized account header: duplicate marker, signer,
+/// writable and executable flags, padding, key, owner, lamports and data
+/// length.
+const ACCOUNT_HEADER_LEN: usize =
+    4 * size_of::<u8>() + size_of::<u32>() + 2 * size_of::<Pubkey>() + 2 * size_of::<u64>();
+
+/// Offset of the data length within the serialized account header.
+const ACCOUNT_DATA_LEN_OFFSET: usize = ACCOUNT_HEADER_LEN - size_of::<u64>();
+
+/// Length of a serialized duplicated account: duplicate index and padding.
+const DUPLICATE_ACCOUNT_LEN: usize = size_of::<u64>();
+
+/// Align a length to the next multiple of 8.
+#[inline(always)]
+const fn align(len: usize) -> usize {
+    (len + 7) & (!7)
+}
+
+/// Length of a serialized (non-duplicated) account with `data_len` bytes of
+/// data: header, data, realloc padding and rent epoch.
+const fn account_len(data_len: usize) -> usize {
+    align(ACCOUNT_HEADER_LEN + data_len + MAX_PERMITTED_DATA_INCREASE) + size_of::<u64>()
+}
+
+/// Offset of the account following the (non-duplicated) account at `offset`
+/// with `data_len` bytes of data.
+const fn next_account_offset(offset: usize, data_len: usize) -> usize {
+    offset + account_len(data_len)
+}
+
+/// Offset of the data length of the account at `offset`.
+const fn data_len_offset(offset: usize) -> usize {
+    offset + ACCOUNT_DATA_LEN_OFFSET
+}
+
+/// Checks whether the token account at `offset` requires a memo on incoming
+/// transfers.
+///
+/// # Safety
 ///
-/// The entrypoint prioritizes the transfer instruction by validating
-/// account data lengths and instruction data. When it can reliably
-/// determine that the instruction is a transfer, it will invoke the
-/// processor directly.
+/// The caller must guarantee that the account at `offset` is a
+/// (non-duplicated) account with `Account::LEN` bytes of data.
+#[inline(always)]
+unsafe fn is_memo_required(input: *const u8, offset: usize) -> bool {
+    // SAFETY: the account data has `Account::LEN` bytes and `Account` has an
+    // alignment of 1.
+    (*input.add(offset + ACCOUNT_HEADER_LEN).cast::<Account>()).is_memo_required()
+}
+
+/// Converts the result of a processor into the entrypoint return value.
+#[inline(always)]
+fn to_return_value(result: ProgramResult) -> u64 {
+    match result {
+        Ok(()) => SUCCESS,
+        Err(error) => {
+            log_error(&error);
+            error.into()
+        }
+    }
+}
+
+/// Custom program entrypoint to give priority to `transfer`,
+/// `transfer_checked`, `mint_to`, `burn` and `close_account` instructions.
+///
+/// The entrypoint prioritizes these instructions by validating account data
+/// lengths and instruction data. When it can reliably determine the
+/// instruction, it will invoke the processor directly.
 #[no_mangle]
 #[allow(clippy::arithmetic_side_effects)]
 pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
-    // Constants that apply to both `transfer` and `transfer_checked`.
-
-    /// Offset for the first account.
-    const ACCOUNT1_HEADER_OFFSET: usize = 0x0008;
+    // Constants that apply to all fast paths.
 
     /// Offset for the first account data length. This is
-    /// expected to be a token account (165 bytes).
-    const ACCOUNT1_DATA_LEN: usize = 0x0058;
+    /// expected to be a token account (165 bytes) or a mint
+    /// account for `mint_to` (82 bytes).
+    const ACCOUNT1_DATA_LEN: usize = data_len_offset(ACCOUNT1_HEADER_OFFSET);
 
-    /// Offset for the second account.
-    const ACCOUNT2_HEADER_OFFSET: usize = 0x2910;
+    /// Offset for the second account when the first account is a
+    /// token account.
+    const ACCOUNT2_HEADER_OFFSET: usize = next_account_offset(ACCOUNT1_HEADER_OFFSET, Account::LEN);
 
     /// Offset for the second account data length. This is
     /// expected to be a token account for `transfer` (165 bytes)
-    /// or a mint account for `transfer_checked` (82 bytes).
-    const ACCOUNT2_DATA_LEN: usize = 0x2960;
+    /// or a mint account for `transfer_checked` and `burn` (82 bytes).
+    const ACCOUNT2_DATA_LEN: usize = data_len_offset(ACCOUNT2_HEADER_OFFSET);
 
     // Constants that apply to `transfer_checked` (instruction 12).
 
     /// Offset for the third account.
-    const IX12_ACCOUNT3_HEADER_OFFSET: usize = 0x51c8;
+    const IX12_ACCOUNT3_HEADER_OFFSET: usize =
+        next_account_offset(ACCOUNT2_HEADER_OFFSET, Mint::LEN);
 
     /// Offset for the third account data length. This is
     /// expected to be a token account (165 bytes).
-    const IX12_ACCOUNT3_DATA_LEN: usize = 0x5218;
+    const IX12_ACCOUNT3_DATA_LEN: usize = data_len_offset(IX12_ACCOUNT3_HEADER_OFFSET);
 
     /// Offset for the fourth account.
-    const IX12_ACCOUNT4_HEADER_OFFSET: usize = 0x7ad0;
+    const IX12_ACCOUNT4_HEADER_OFFSET: usize =
+        next_account_offset(IX12_ACCOUNT3_HEADER_OFFSET, Account::LEN);
 
     /// Offset for the fourth account data length.
     ///
     /// This is expected to be an account with variable data
     /// length.
-    const IX12_ACCOUNT4_DATA_LEN: usize = 0x7b20;
+    const IX12_ACCOUNT4_DATA_LEN: usize = data_len_offset(IX12_ACCOUNT4_HEADER_OFFSET);
 
     /// Expected offset for the instruction data in the case the
     /// fourth (authority) account has zero data.
     ///
     /// This value is adjusted before it is used.
-    const IX12_EXPECTED_INSTRUCTION_DATA_LEN_OFFSET: usize = 0xa330;
+    const IX12_EXPECTED_INSTRUCTION_DATA_LEN_OFFSET: usize =
+        next_account_offset(IX12_ACCOUNT4_HEADER_OFFSET, 0);
 
     // Constants that apply to `transfer` (instruction 3).
 
     /// Offset for the third account.
+    const IX3_ACCOUNT3_HEADER_OFFSET: usize =
+        next_account_offset(ACCOUNT2_HEADER_OFFSET, Account::LEN);
+
+    /// Offset for the third account data length.
+    ///
+    /// This is expected to be an account with variable data
+    /// length.
+    const IX3_ACCOUNT3_DATA_LEN: usize = data_len_offset(IX3_ACCOUNT3_HEADER_OFFSET);
+
+    /// Expected offset for the instruction data in the case the
+    /// third (authority) account has zero data.
+    ///
+    /// This value is adjusted before it is used.
+    const IX3_INSTRUCTION_DATA_LEN_OFFSET: usize =
+        next_account_offset(IX3_ACCOUNT3_HEADER_OFFSET, 0);
+
+    // Constants that apply to `mint_to` (instruction 7).
+
+    /// Offset for the second account.
+    const IX7_ACCOUNT2_HEADER_OFFSET: usize =
+        next_account_offset(ACCOUNT1_HEADER_OFFSET, Mint::LEN);
+
+    /// Offset for the second account data length. This is
+    /// expected to be a token account (165 bytes).
+    const IX7_ACCOUNT2_DATA_LEN: usize = data_len_offset(IX7_ACCOUNT2_HEADER_OFFSET);
+
+    /// Offset for the third account.
+    const IX7_ACCOUNT3_HEADER_OFFSET: usize =
+        next_account_offset(IX7_ACCOUNT2_HEADER_OFFSET, Account::LEN);
+
+    /// Offset for the third account data length.
     ///
-    /// Note that this assumes that both first and second accounts
-    /// have zero data, which is being validated before the offset
-    /// is used.
-    const IX3_ACCOUNT3_HEADER_OFFSET: usize = 0x5218;
+    /// This is expected to be an account with variable data
+    /// length.
+    const IX7_ACCOUNT3_DATA_LEN: usize = data_len_offset(IX7_ACCOUNT3_HEADER_OFFSET);
+
+    /// Expected offset for the instruction data in the case the
+    /// third (authority) account has zero data.
+    ///
+    /// This value is adjusted before it is used.
+    const IX7_INSTRUCTION_DATA_LEN_OFFSET: usize =
+        next_account_offset(IX7_ACCOUNT3_HEADER_OFFSET, 0);
+
+    // Constants that apply to `burn` (instruction 8).
+
+    /// Offset for the third account.
+    const IX8_ACCOUNT3_HEADER_OFFSET: usize =
+        next_account_offset(ACCOUNT2_HEADER_OFFSET, Mint::LEN);
 
     /// Offset for the third account data length.
     ///
     /// This is expected to be an account with variable data
     /// length.
-    const IX3_ACCOUNT3_DATA_LEN: usize = 0x5268;
+    const IX8_ACCOUNT3_DATA_LEN: usize = data_len_offset(IX8_ACCOUNT3_HEADER_OFFSET);
 
     /// Expected offset for the instruction data in the case the
     /// third (authority) account has zero data.
     ///
     /// This value is adjusted before it is used.
-    const IX3_INSTRUCTION_DATA_LEN_OFFSET: usize = 0x7a78;
+    const IX8_INSTRUCTION_DATA_LEN_OFFSET: usize =
+        next_account_offset(IX8_ACCOUNT3_HEADER_OFFSET, 0);
 
-    /// Align an address to the next multiple of 8.
-    #[inline(always)]
-    fn align(input: u64) -> u64 {
-        (input + 7) & (!7)
-    }
+    // Constants that apply to `close_account` (instruction 9).
+
+    /// Expected offset for the third account in the case the
+    /// second (destination) account has zero data.
+    ///
+    /// This value is adjusted before it is used.
+    const IX9_ACCOUNT3_HEADER_OFFSET: usize = next_account_offset(ACCOUNT2_HEADER_OFFSET, 0);
 
     // Fast path for `transfer_checked`.
     //
@@ -109,6 +222,7 @@ pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
     //   1. source: must be a token account (165 length)
     //   2. mint: must be a mint account (82 length)
     //   3. destination: must be a token account (165 length)
+    //      that does not require memos
     //   4. authority: can be any account (variable length)
     //
     // Instruction data is expected to be at least 9 bytes
@@ -119,11 +233,12 @@ pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
         && (*input.add(ACCOUNT2_DATA_LEN).cast::<u64>() == Mint::LEN as u64)
         && (*input.add(IX12_ACCOUNT3_HEADER_OFFSET) == NON_DUP_MARKER)
         && (*input.add(IX12_ACCOUNT3_DATA_LEN).cast::<u64>() == Account::LEN as u64)
+        && !is_memo_required(input, IX12_ACCOUNT3_HEADER_OFFSET)
         && (*input.add(IX12_ACCOUNT4_HEADER_OFFSET) == NON_DUP_MARKER)
     {
         // The `authority` account can have variable data length.
         let account_4_data_len_aligned =
-            align(*input.add(IX12_ACCOUNT4_DATA_LEN).cast::<u64>()) as usize;
+            align(*input.add(IX12_ACCOUNT4_DATA_LEN).cast::<u64>() as usize);
         let offset = IX12_EXPECTED_INSTRUCTION_DATA_LEN_OFFSET + account_4_data_len_aligned;
 
         // Check that we have enough instruction data.
@@ -149,13 +264,7 @@ pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
                 #[cfg(feature = "logging")]
                 pinocchio::msg!("Instruction: TransferChecked");
 
-                return match process_transfer_checked(&accounts, instruction_data) {
-                    Ok(()) => SUCCESS,
-                    Err(error) => {
-                        log_error(&error);
-                        error.into()
-                    }
-                };
+                return to_return_value(process_transfer_checked(&accounts, instruction_data));
             }
         }
     }
@@ -164,6 +273,7 @@ pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
     // It expects 3 accounts:
     //   1. source: must be a token account (165 length)
     //   2. destination: must be a token account (165 length)
+    //      that does not require memos
     //   3. authority: can be any account (variable length)
     //
     // Instruction data is expected to be at least 8 bytes
@@ -172,11 +282,12 @@ pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
         && (*input.add(ACCOUNT1_DATA_LEN).cast::<u64>() == Account::LEN as u64)
         && (*input.add(ACCOUNT2_HEADER_OFFSET) == NON_DUP_MARKER)
         && (*input.add(ACCOUNT2_DATA_LEN).cast::<u64>() == Account::LEN as u64)
+        && !is_memo_required(input, ACCOUNT2_HEADER_OFFSET)
         && (*input.add(IX3_ACCOUNT3_HEADER_OFFSET) == NON_DUP_MARKER)
     {
         // The `authority` account can have variable data length.
         let account_3_data_len_aligned =
-            align(*input.add(IX3_ACCOUNT3_DATA_LEN).cast::<u64>()) as usize;
+            align(*input.add(IX3_ACCOUNT3_DATA_LEN).cast::<u64>() as usize);
         let offset = IX3_INSTRUCTION_DATA_LEN_OFFSET + account_3_data_len_aligned;
 
         // Check that we have enough instruction data.
@@ -199,13 +310,160 @@ pub unsafe extern "C" fn entrypoint(input: *mut u8) -> u64 {
                 #[cfg(feature = "logging")]
                 pinocchio::msg!("Instruction: Transfer");
 
-                return match process_transfer(&accounts, instruction_data) {
-                    Ok(()) => SUCCESS,
-                    Err(error) => {
-                        log_error(&error);
-                        error.into()
-                    }
+                return to_return_value(process_transfer(&accounts, instruction_data));
+            }
+        }
+    }
+    // Fast path for `mint_to`.
+    //
+    // It expects 3 accounts:
+    //   1. mint: must be a mint account (82 length)
+    //   2. destination: must be a token account (165 length)
+    //   3. authority: can be any account (variable length)
+    //
+    // Instruction data is expected to be at least 8 bytes
+    // and discriminator equal to 7.
+    else if *input == 3
+        && (*input.add(ACCOUNT1_DATA_LEN).cast::<u64>() == Mint::LEN as u64)
+        && (*input.add(IX7_ACCOUNT2_HEADER_OFFSET) == NON_DUP_MARKER)
+        && (*input.add(IX7_ACCOUNT2_DATA_LEN).cast::<u64>() == Account::LEN as u64)
+        && (*input.add(IX7_ACCOUNT3_HEADER_OFFSET) == NON_DUP_MARKER)
+    {
+        // The `authority` account can have variable data length.
+        let account_3_data_len_aligned =
+            align(*input.add(IX7_ACCOUNT3_DATA_LEN).cast::<u64>() as usize);
+        let offset = IX7_INSTRUCTION_DATA_LEN_OFFSET + account_3_data_len_aligned;
+
+        // Check that we have enough instruction data.
+        if likely(input.add(offset).cast::<u64>().read() >= 9) {
+            let discriminator = input.add(offset + size_of::<u64>()).cast::<u8>().read();
+
+            // Check for mint to discriminator.
+            if likely(discriminator == TokenInstruction::MintTo as u8) {
+                let instruction_data =
+                    unsafe { from_raw_parts(input.add(offset + 9), size_of::<u64>()) };
+
+                let accounts = unsafe {
+                    [
+                        transmute::<*mut u8, AccountInfo>(input.add(ACCOUNT1_HEADER_OFFSET)),
+                        transmute::<*mut u8, AccountInfo>(input.add(IX7_ACCOUNT2_HEADER_OFFSET)),
+                        transmute::<*mut u8, AccountInfo>(input.add(IX7_ACCOUNT3_HEADER_OFFSET)),
+                    ]
                 };
+
+                #[cfg(feature = "logging")]
+                pinocchio::msg!("Instruction: MintTo");
+
+                return to_return_value(process_mint_to(&accounts, instruction_data));
+            }
+        }
+    }
+    // Fast path for `burn`.
+    //
+    // It expects 3 accounts:
+    //   1. source: must be a token account (165 length)
+    //   2. mint: must be a mint account (82 length)
+    //   3. authority: can be any account (variable length)
+    //
+    // Instruction data is expected to be at least 8 bytes
+    // and discriminator equal to 8.
+    else if *input == 3
+        && (*input.add(ACCOUNT1_DATA_LEN).cast::<u64>() == Account::LEN as u64)
+        && (*input.add(ACCOUNT2_HEADER_OFFSET) == NON_DUP_MARKER)
+        && (*input.add(ACCOUNT2_DATA_LEN).cast::<u64>() == Mint::LEN as u64)
+        && (*input.add(IX8_ACCOUNT3_HEADER_OFFSET) == NON_DUP_MARKER)
+    {
+        // The `authority` account can have variable data length.
+        let account_3_data_len_aligned =
+            align(*input.add(IX8_ACCOUNT3_DATA_LEN).cast::<u64>() as usize);
+        let offset = IX8_INSTRUCTION_DATA_LEN_OFFSET + account_3_data_len_aligned;
+
+        // Check that we have enough instruction data.
+        if likely(input.add(offset).cast::<u64>().read() >= 9) {
+            let discriminator = input.add(offset + size_of::<u64>()).cast::<u8>().read();
+
+            // Check for burn discriminator.
+            if likely(discriminator == TokenInstruction::Burn as u8) {
+                let instruction_data =
+                    unsafe { from_raw_parts(input.add(offset + 9), size_of::<u64>()) };
+
+                let accounts = unsafe {
+                    [
+                        transmute::<*mut u8, AccountInfo>(input.add(ACCOUNT1_HEADER_OFFSET)),
+                        transmute::<*mut u8, AccountInfo>(input.add(ACCOUNT2_HEADER_OFFSET)),
+                        transmute::<*mut u8, AccountInfo>(input.add(IX8_ACCOUNT3_HEADER_OFFSET)),
+                    ]
+                };
+
+                #[cfg(feature = "logging")]
+                pinocchio::msg!("Instruction: Burn");
+
+                return to_return_value(process_burn(&accounts, instruction_data));
+            }
+        }
+    }
+    // Fast path for `close_account`.
+    //
+    // It expects 3 accounts:
+    //   1. source: must be a token account (165 length)
+    //   2. destination: can be any account (variable length)
+    //   3. authority: can be any account (variable length) or
+    //      the destination account
+    //
+    // Instruction data is expected to be at least 1 byte
+    // and discriminator equal to 9.
+    //
+    // Note that this is not an `else if` branch since the
+    // conditions overlap with the `transfer` and `burn` fast
+    // paths when the destination is a token or mint account.
+    if *input == 3
+        && (*input.add(ACCOUNT1_DATA_LEN).cast::<u64>() == Account::LEN as u64)
+        && (*input.add(ACCOUNT2_HEADER_OFFSET) == NON_DUP_MARKER)
+    {
+        // The `destination` account can have variable data length.
+        let account_2_data_len_aligned =
+            align(*input.add(ACCOUNT2_DATA_LEN).cast::<u64>() as usize);
+        let account_3_offset = IX9_ACCOUNT3_HEADER_OFFSET + account_2_data_len_aligned;
+
+        // The `authority` account is either a duplicate of the
+        // `destination` account or an account with variable data
+        // length; any other duplicate is handled by the generic path.
+        let authority = match *input.add(account_3_offset) {
+            NON_DUP_MARKER => {
+                let account_3_data_len_aligned =
+                    align(*input.add(data_len_offset(account_3_offset)).cast::<u64>() as usize);
+                Some((
+                    account_3_offset,
+                    next_account_offset(account_3_offset, 0) + account_3_data_len_aligned,
+                ))
+            }
+            1 => Some((
+                ACCOUNT2_HEADER_OFFSET,
+                account_3_offset + DUPLICATE_ACCOUNT_LEN,
+            )),
+            _ => None,
+        };
+
+        if let Some((authority_offset, offset)) = authority {
+            // Check that we have instruction data.
+            if likely(input.add(offset).cast::<u64>().read() >= 1) {
+                let discriminator = input.add(offset + size_of::<u64>()).cast::<u8>().read();
+
+                // Check for close account discriminator.
+                if likely(discriminator == TokenInstruction::CloseAccount as u8) {
+                    let accounts = unsafe {
+                        [
+                            transmute::<*mut u8, AccountInfo>(input.add(ACCOUNT1_HEADER_OFFSET)),
+                            transmute::<*mut u8, AccountInfo>(input.add(ACCOUNT2_HEADER_OFFSET)),
+                            transmute::<*mut u8, AccountInfo>(input.add(authority_offset)),
+                        ]
+                    };
+
+                    #[cfg(feature = "logging")]
+                    pinocchio::msg!("Instruction: CloseAccount");
+
+                    return to_return_value(process_close_account(&accounts));
+                }
             }
         }
     }
@@ -245,8 +503,7 @@ pub fn process_instruction(accounts: &[AccountInfo], instruction_data: &[u8]) ->
         return Err(TokenError::InvalidInstruction.into());
     };
 
-    let result = if *discriminator == 255 {
-        // 255 - Batch
+    let result = if *discriminator == TokenInstruction::Batch as u8 {
         #[cfg(feature = "logging")]
         pinocchio::msg!("Instruction: Batch");
 
@@ -287,92 +544,80 @@ pub(crate) fn inner_process_instruction(
         return Err(TokenError::InvalidInstruction.into());
     };
 
-    match *discriminator {
-        // 0 - InitializeMint
-        0 => {
+    match TokenInstruction::try_from(*discriminator)? {
+        TokenInstruction::InitializeMint => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMint");
 
             process_initialize_mint(accounts, instruction_data)
         }
-        // 1 - InitializeAccount
-        1 => {
+        TokenInstruction::InitializeAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeAccount");
 
             process_initialize_account(accounts)
         }
-        // 3 - Transfer
-        3 => {
+        TokenInstruction::Transfer => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Transfer");
 
             process_transfer(accounts, instruction_data)
         }
-        // 7 - MintTo
-        7 => {
+        TokenInstruction::MintTo => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: MintTo");
 
             process_mint_to(accounts, instruction_data)
         }
-        // 8 - Burn
-        8 => {
+        TokenInstruction::Burn => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Burn");
 
             process_burn(accounts, instruction_data)
         }
-        // 9 - CloseAccount
-        9 => {
+        TokenInstruction::CloseAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: CloseAccount");
 
             process_close_account(accounts)
         }
-        // 12 - TransferChecked
-        12 => {
+        TokenInstruction::TransferChecked => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: TransferChecked");
 
             process_transfer_checked(accounts, instruction_data)
         }
-        // 15 - BurnChecked
-        15 => {
+        TokenInstruction::BurnChecked => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: BurnChecked");
 
             process_burn_checked(accounts, instruction_data)
         }
-        // 17 - SyncNative
-        17 => {
+        TokenInstruction::SyncNative => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: SyncNative");
 
             process_sync_native(accounts)
         }
-        // 18 - InitializeAccount3
-        18 => {
+        TokenInstruction::InitializeAccount3 => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeAccount3");
 
             process_initialize_account3(accounts, instruction_data)
         }
-        // 20 - InitializeMint2
-        20 => {
+        TokenInstruction::InitializeMint2 => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMint2");
 
             process_initialize_mint2(accounts, instruction_data)
         }
-        // 22 - InitializeImmutableOwner
-        22 => {
+        TokenInstruction::InitializeImmutableOwner => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeImmutableOwner");
 
             process_initialize_immutable_owner(accounts)
         }
-        d => inner_process_remaining_instruction(accounts, instruction_data, d),
+        instruction => inner_process_remaining_instruction(accounts, instruction_data, instruction),
     }
 }
 
@@ -385,114 +630,166 @@ pub(crate) fn inner_process_instruction(
 fn inner_process_remaining_instruction(
     accounts: &[AccountInfo],
     instruction_data: &[u8],
-    discriminator: u8,
+    instruction: TokenInstruction,
 ) -> ProgramResult {
-    match discriminator {
-        // 2 - InitializeMultisig
-        2 => {
+    match instruction {
+        TokenInstruction::InitializeMultisig => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMultisig");
 
             process_initialize_multisig(accounts, instruction_data)
         }
-        // 4 - Approve
-        4 => {
+        TokenInstruction::Approve => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Approve");
 
             process_approve(accounts, instruction_data)
         }
-        // 5 - Revoke
-        5 => {
+        TokenInstruction::Revoke => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: Revoke");
 
             process_revoke(accounts)
         }
-        // 6 - SetAuthority
-        6 => {
+        TokenInstruction::SetAuthority => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: SetAuthority");
 
             process_set_authority(accounts, instruction_data)
         }
-        // 10 - FreezeAccount
-        10 => {
+        TokenInstruction::FreezeAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: FreezeAccount");
 
             process_freeze_account(accounts)
         }
-        // 11 - ThawAccount
-        11 => {
+        TokenInstruction::ThawAccount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: ThawAccount");
 
             process_thaw_account(accounts)
         }
-        // 13 - ApproveChecked
-        13 => {
+        TokenInstruction::ApproveChecked => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: ApproveChecked");
 
             process_approve_checked(accounts, instruction_data)
         }
-        // 14 - MintToChecked
-        14 => {
+        TokenInstruction::MintToChecked => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: MintToChecked");
 
             process_mint_to_checked(accounts, instruction_data)
         }
-        // 16 - InitializeAccount2
-        16 => {
+        TokenInstruction::InitializeAccount2 => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeAccount2");
 
             process_initialize_account2(accounts, instruction_data)
         }
-        // 19 - InitializeMultisig2
-        19 => {
+        TokenInstruction::InitializeMultisig2 => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: InitializeMultisig2");
 
             process_initialize_multisig2(accounts, instruction_data)
         }
-        // 21 - GetAccountDataSize
-        21 => {
+        TokenInstruction::GetAccountDataSize => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: GetAccountDataSize");
 
             process_get_account_data_size(accounts)
         }
-        // 23 - AmountToUiAmount
-        23 => {
+        TokenInstruction::AmountToUiAmount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: AmountToUiAmount");
 
             process_amount_to_ui_amount(accounts, instruction_data)
         }
-        // 24 - UiAmountToAmount
-        24 => {
+        TokenInstruction::UiAmountToAmount => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: UiAmountToAmount");
 
             process_ui_amount_to_amount(accounts, instruction_data)
         }
-        // 38 - WithdrawExcessLamports
-        38 => {
+        TokenInstruction::WithdrawExcessLamports => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: WithdrawExcessLamports");
 
             process_withdraw_excess_lamports(accounts)
         }
-        // 45 - UnwrapLamports
-        45 => {
+        TokenInstruction::UnwrapLamports => {
             #[cfg(feature = "logging")]
             pinocchio::msg!("Instruction: UnwrapLamports");
 
             process_unwrap_lamports(accounts, instruction_data)
         }
-        _ => Err(TokenError::InvalidInstruction.into()),
+        TokenInstruction::TransferMany => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: TransferMany");
+
+            process_transfer_many(accounts, instruction_data)
+        }
+        TokenInstruction::BurnAndClose => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: BurnAndClose");
+
+            process_burn_and_close(accounts)
+        }
+        TokenInstruction::SweepAndClose => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SweepAndClose");
+
+            process_sweep_and_close(accounts)
+        }
+        TokenInstruction::ApproveWithExpiry => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ApproveWithExpiry");
+
+            process_approve_with_expiry(accounts, instruction_data)
+        }
+        TokenInstruction::ApproveWithSpendingLimit => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ApproveWithSpendingLimit");
+
+            process_approve_with_spending_limit(accounts, instruction_data)
+        }
+        TokenInstruction::SetMemoRequired => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: SetMemoRequired");
+
+            process_set_memo_required(accounts, instruction_data)
+        }
+        TokenInstruction::PauseMint => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: PauseMint");
+
+            process_pause_mint(accounts)
+        }
+        TokenInstruction::ResumeMint => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: ResumeMint");
+
+            process_resume_mint(accounts)
+        }
+        TokenInstruction::CloseDelegation => {
+            #[cfg(feature = "logging")]
+            pinocchio::msg!("Instruction: CloseDelegation");
+
+            process_close_delegation(accounts)
+        }
+        // Instructions handled by `inner_process_instruction` and `process_instruction`.
+        TokenInstruction::InitializeMint
+        | TokenInstruction::InitializeAccount
+        | TokenInstruction::Transfer
+        | TokenInstruction::MintTo
+        | TokenInstruction::Burn
+        | TokenInstruction::CloseAccount
+        | TokenInstruction::TransferChecked
+        | TokenInstruction::BurnChecked
+        | TokenInstruction::SyncNative
+        | TokenInstruction::InitializeAccount3
+        | TokenInstruction::InitializeMint2
+        | TokenInstruction::InitializeImmutableOwner
+        | TokenInstruction::Batch => Err(TokenError::InvalidInstruction.into()),
     }
 }
@@ -0,0 +1,91 @@
+use {
+    super::{check_account_owner, validate_owner, U64_BYTES},
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::pubkey_eq,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::Account,
+            delegation::{Delegation, ExpiryType},
+            load_mut, load_mut_unchecked, Initializable,
+        },
+    },
+};
+
+pub fn process_approve_with_expiry(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // instruction data: expected u64 (8) + u8 (1) + u64/i64 (8)
+    let (amount, expiry_type, expiry) = if instruction_data.len() == 2 * U64_BYTES + 1 {
+        // SAFETY: `instruction_data` is `17` bytes long.
+        unsafe {
+            (
+                u64::from_le_bytes(*(instruction_data.as_ptr() as *const [u8; U64_BYTES])),
+                ExpiryType::try_from(*instruction_data.get_unchecked(U64_BYTES))
+                    .map_err(|_| TokenError::InvalidInstruction)?,
+                *(instruction_data.as_ptr().add(U64_BYTES + 1) as *const [u8; U64_BYTES]),
+            )
+        }
+    } else {
+        return Err(TokenError::InvalidInstruction.into());
+    };
+
+    let [source_account_info, delegation_info, delegate_info, owner_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the delegation account.
+
+    check_account_owner(delegation_info)?;
+
+    let is_exempt = Rent::get()?.is_exempt(delegation_info.lamports(), delegation_info.data_len());
+
+    // SAFETY: single mutable borrow to `delegation_info` account data; the
+    // length of the account data is validated by `load_mut_unchecked`.
+    let delegation =
+        unsafe { load_mut_unchecked::<Delegation>(delegation_info.borrow_mut_data_unchecked())? };
+
+    if delegation.is_initialized()? {
+        // A delegation account can only be reused for the same token account.
+        if !pubkey_eq(&delegation.account, source_account_info.key()) {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+    } else if !is_exempt {
+        return Err(TokenError::NotRentExempt.into());
+    }
+
+    // Validates the source account.
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized; additionally, the
+    // account cannot be the delegation account since it has a different length.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if source_account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_owner(&source_account.owner, owner_info, remaining)? };
+
+    // Records the delegation and sets it as the delegate.
+
+    delegation.set_initialized(true);
+    delegation.account = *source_account_info.key();
+    delegation.delegate = *delegate_info.key();
+    delegation.set_expiry(expiry_type, expiry);
+
+    source_account.set_delegate(delegation_info.key());
+    source_account.set_delegated_amount(amount);
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use {
+    super::{check_account_owner, unpack_amount, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        pubkey::pubkey_eq,
+        sysvars::{clock::Clock, rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::Account, load_mut, load_mut_unchecked, spending_limit::SpendingLimit,
+            Initializable,
+        },
+    },
+};
+
+pub fn process_approve_with_spending_limit(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let limit = unpack_amount(instruction_data)?;
+
+    let [source_account_info, spending_limit_info, delegate_info, owner_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Validates the spending limit account.
+
+    check_account_owner(spending_limit_info)?;
+
+    let is_exempt = Rent::get()?.is_exempt(
+        spending_limit_info.lamports(),
+        spending_limit_info.data_len(),
+    );
+
+    // SAFETY: single mutable borrow to `spending_limit_info` account data; the
+    // length of the account data is validated by `load_mut_unchecked`.
+    let spending_limit = unsafe {
+        load_mut_unchecked::<SpendingLimit>(spending_limit_info.borrow_mut_data_unchecked())?
+    };
+
+    if spending_limit.is_initialized()? {
+        // A spending limit account can only be reused for the same token account.
+        if !pubkey_eq(&spending_limit.account, source_account_info.key()) {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+    } else if !is_exempt {
+        return Err(TokenError::NotRentExempt.into());
+    }
+
+    // Validates the source account.
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized; additionally, the
+    // account cannot be the spending limit account since it has a different
+    // length.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if source_account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_owner(&source_account.owner, owner_info, remaining)? };
+
+    // Records the spending limit and sets it as the delegate.
+
+    spending_limit.set_initialized(true);
+    spending_limit.account = *source_account_info.key();
+    spending_limit.delegate = *delegate_info.key();
+    spending_limit.set_limit(limit);
+    spending_limit.set_epoch(Clock::get()?.epoch);
+    spending_limit.set_spent(0);
+
+    // The delegated amount is informational only, since the spending limit
+    // account tracks the amount spent.
+    source_account.set_delegate(spending_limit_info.key());
+    source_account.set_delegated_amount(limit);
+
+    Ok(())
+}
@@ -1,7 +1,19 @@
 use {
-    crate::{entrypoint::inner_process_instruction, processor::check_account_owner},
+    crate::{
+        entrypoint::inner_process_instruction,
+        processor::{
+            check_account_owner,
+            shared::transfer::{transfer, TransferAccounts},
+            unpack_amount, unpack_amount_and_decimals,
+        },
+    },
+    core::ptr,
     pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
-    pinocchio_token_interface::error::TokenError,
+    pinocchio_token_interface::{
+        error::TokenError,
+        instruction::TokenInstruction,
+        state::{account::Account, load_mut},
+    },
 };
 
 /// The size of the batch instruction header.
@@ -11,8 +23,20 @@ use {
 ///  * length of the instruction data
 const IX_HEADER_SIZE: usize = 2;
 
+/// Maximum number of instructions in a batch processed by the transfer fast
+/// path.
+const MAX_TRANSFER_INSTRUCTIONS: usize = 16;
+
+/// Maximum number of distinct token accounts referenced by a batch processed
+/// by the transfer fast path.
+const MAX_TRANSFER_ACCOUNTS: usize = 2 * MAX_TRANSFER_INSTRUCTIONS;
+
 #[allow(clippy::arithmetic_side_effects)]
 pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8]) -> ProgramResult {
+    if is_transfer_batch(accounts, instruction_data) {
+        return process_transfer_batch(accounts, instruction_data);
+    }
+
     loop {
         // Validates the instruction data and accounts offset.
 
@@ -50,48 +74,25 @@ pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8])
         // in a batch since ownership is only enforced by the runtime at the end of
         // the batch processing.
         //
-        // Instructions that do not appear in the list below do not require
-        // ownership checks since they either do not modify accounts or the ownership
-        // is already checked explicitly.
-        if let Some(&discriminator) = ix_data.first() {
-            match discriminator {
-                // 3 - Transfer
-                // 7 - MintTo
-                // 8 - Burn
-                // 14 - MintToChecked
-                // 15 - BurnChecked
-                3 | 7 | 8 | 14 | 15 => {
-                    let [a0, a1, ..] = ix_accounts else {
-                        return Err(ProgramError::NotEnoughAccountKeys);
-                    };
-                    check_account_owner(a0)?;
-                    check_account_owner(a1)?;
-                }
-                // 12 - TransferChecked
-                12 => {
-                    let [a0, _, a2, ..] = ix_accounts else {
-                        return Err(ProgramError::NotEnoughAccountKeys);
-                    };
-                    check_account_owner(a0)?;
-                    check_account_owner(a2)?;
+        // The accounts to check are declared in the instruction table; instructions
+        // without any entry either do not modify accounts or the ownership is
+        // already checked explicitly.
+        if let Some(instruction) = ix_data
+            .first()
+            .and_then(|&discriminator| TokenInstruction::from_discriminator(discriminator))
+        {
+            let owner_checks = instruction.batch_owner_checks();
+
+            if let Some(&last) = owner_checks.last() {
+                if ix_accounts.len() <= last {
+                    return Err(ProgramError::NotEnoughAccountKeys);
                 }
-                // 4 - Approve
-                // 5 - Revoke
-                // 6 - SetAuthority
-                // 9 - CloseAccount
-                // 10 - FreezeAccount
-                // 11 - ThawAccount
-                // 13 - ApproveChecked
-                // 22 - InitializeImmutableOwner
-                // 38 - WithdrawExcessLamports
-                // 45 - UnwrapLamports
-                4..=13 | 22 | 38 | 45 => {
-                    let [a0, ..] = ix_accounts else {
-                        return Err(ProgramError::NotEnoughAccountKeys);
-                    };
-                    check_account_owner(a0)?;
+
+                for &index in owner_checks {
+                    // SAFETY: The indices are in ascending order and the last one
+                    // was validated to be within the bounds of the accounts slice.
+                    check_account_owner(unsafe { ix_accounts.get_unchecked(index) })?;
                 }
-                _ => {}
             }
         }
 
@@ -108,3 +109,164 @@ pub fn process_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8])
 
     Ok(())
 }
+
+/// Checks whether the batch only contains `Transfer` and `TransferChecked`
+/// instructions.
+///
+/// Returns `false` for batches with invalid headers or not enough accounts so
+/// that the generic processing reports the error.
+#[allow(clippy::arithmetic_side_effects)]
+fn is_transfer_batch(mut accounts: &[AccountInfo], mut instruction_data: &[u8]) -> bool {
+    let mut instructions = 0;
+
+    while !instruction_data.is_empty() {
+        if instructions == MAX_TRANSFER_INSTRUCTIONS {
+            return false;
+        }
+
+        let [expected_accounts, data_len, discriminator, ..] = *instruction_data else {
+            return false;
+        };
+        let expected_accounts = expected_accounts as usize;
+        let data_offset = IX_HEADER_SIZE + data_len as usize;
+
+        let minimum_accounts = match TokenInstruction::from_discriminator(discriminator) {
+            Some(TokenInstruction::Transfer) => 3,
+            Some(TokenInstruction::TransferChecked) => 4,
+            _ => return false,
+        };
+
+        if instruction_data.len() < data_offset
+            || data_len == 0
+            || expected_accounts < minimum_accounts
+            || accounts.len() < expected_accounts
+        {
+            return false;
+        }
+
+        accounts = &accounts[expected_accounts..];
+        instruction_data = &instruction_data[data_offset..];
+        instructions += 1;
+    }
+
+    instructions > 0
+}
+
+/// Processes a batch of `Transfer` and `TransferChecked` instructions.
+///
+/// Token accounts are loaded once and their ownership is checked once, on
+/// the first instruction that references them; subsequent instructions reuse
+/// the loaded accounts. The batch must have been validated by
+/// [`is_transfer_batch`].
+#[allow(clippy::arithmetic_side_effects)]
+fn process_transfer_batch(
+    mut accounts: &[AccountInfo],
+    mut instruction_data: &[u8],
+) -> ProgramResult {
+    // Token accounts already loaded, identified by their data pointer.
+    let mut loaded = [ptr::null_mut::<Account>(); MAX_TRANSFER_ACCOUNTS];
+    let mut loaded_len = 0;
+
+    while !instruction_data.is_empty() {
+        // SAFETY: The instruction headers are already validated.
+        let (ix_accounts, ix_data) = unsafe {
+            let expected_accounts = *instruction_data.get_unchecked(0) as usize;
+            let data_offset = IX_HEADER_SIZE + *instruction_data.get_unchecked(1) as usize;
+            let ix = (
+                accounts.get_unchecked(..expected_accounts),
+                instruction_data.get_unchecked(IX_HEADER_SIZE..data_offset),
+            );
+
+            accounts = accounts.get_unchecked(expected_accounts..);
+            instruction_data = instruction_data.get_unchecked(data_offset..);
+            ix
+        };
+
+        // SAFETY: The instruction data is guaranteed to have the discriminator.
+        let (discriminator, ix_data) = unsafe { ix_data.split_first().unwrap_unchecked() };
+        let checked = *discriminator == TokenInstruction::TransferChecked as u8;
+
+        // SAFETY: The number of accounts is already validated for the instruction.
+        let (source_info, destination_info) = unsafe {
+            (
+                ix_accounts.get_unchecked(0),
+                ix_accounts.get_unchecked(if checked { 2 } else { 1 }),
+            )
+        };
+
+        // Ownership is only enforced by the runtime at the end of the batch
+        // processing, so token accounts are checked the first time they are used.
+        let source = find_loaded(&loaded[..loaded_len], source_info);
+        let destination = find_loaded(&loaded[..loaded_len], destination_info);
+
+        if source.is_none() {
+            check_account_owner(source_info)?;
+        }
+
+        if destination.is_none() {
+            check_account_owner(destination_info)?;
+        }
+
+        let (amount, expected_decimals) = if checked {
+            let (amount, decimals) = unpack_amount_and_decimals(ix_data)?;
+            (amount, Some(decimals))
+        } else {
+            (unpack_amount(ix_data)?, None)
+        };
+
+        let transfer_accounts = TransferAccounts::new(ix_accounts, expected_decimals)?;
+
+        let source = match source {
+            Some(source) => source,
+            None => {
+                // SAFETY: The account is not currently borrowed and `load_mut`
+                // validates that the account is initialized.
+                let source =
+                    unsafe { load_mut::<Account>(source_info.borrow_mut_data_unchecked())? }
+                        as *mut Account;
+                loaded[loaded_len] = source;
+                loaded_len += 1;
+                source
+            }
+        };
+
+        let destination = if source_info == destination_info {
+            None
+        } else {
+            Some(match destination {
+                Some(destination) => destination,
+                None => {
+                    // SAFETY: The account is not currently borrowed and
+                    // `load_mut` validates that the account is initialized.
+                    let destination = unsafe {
+                        load_mut::<Account>(destination_info.borrow_mut_data_unchecked())?
+                    } as *mut Account;
+                    loaded[loaded_len] = destination;
+                    loaded_len += 1;
+                    destination
+                }
+            })
+        };
+
+        // SAFETY: The pointers reference the data of distinct, initialized token
+        // accounts and no other reference to them is alive.
+        unsafe {
+            transfer(
+                &transfer_accounts,
+                &mut *source,
+                destination.map(|destination| &mut *destination),
+                amount,
+                true,
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the loaded token account for `account_info`, if any.
+#[inline(always)]
+fn find_loaded(loaded: &[*mut Account], account_info: &AccountInfo) -> Option<*mut Account> {
+    let data = account_info.data_ptr() as *mut Account;
+    loaded.iter().find(|&&account| account == data).copied()
+}
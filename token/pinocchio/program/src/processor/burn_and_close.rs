@@ -0,0 +1,102 @@
+use {
+    super::{check_account_owner, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::pubkey_eq, ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::{Account, INCINERATOR_ID},
+            load_mut,
+            mint::Mint,
+        },
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_burn_and_close(accounts: &[AccountInfo]) -> ProgramResult {
+    let [source_account_info, mint_info, destination_account_info, authority_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Comparing whether the AccountInfo's "point" to the same account or
+    // not - this is a faster comparison since it just checks the internal
+    // raw pointer.
+    if source_account_info == destination_account_info {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    {
+        // SAFETY: scoped mutable borrow to `source_account_info` account data and
+        // `load_mut` validates that the account is initialized.
+        let source_account =
+            unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+        // SAFETY: scoped mutable borrow to `mint_info` account data and
+        // `load_mut` validates that the mint is initialized; additionally, an
+        // account cannot be both a token account and a mint, so if duplicates are
+        // passed in, one of them will fail the `load_mut` check.
+        let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+
+        if source_account.is_frozen()? {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        if source_account.is_native() {
+            return Err(TokenError::NativeNotSupported.into());
+        }
+
+        if !pubkey_eq(mint_info.key(), &source_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        if mint.is_paused() {
+            return Err(TokenError::MintPaused.into());
+        }
+
+        let amount = source_account.amount();
+
+        if !source_account.is_owned_by_system_program_or_incinerator() {
+            let authority = source_account
+                .close_authority()
+                .unwrap_or(&source_account.owner);
+
+            // Burning the remaining balance requires the owner, so a close
+            // authority different than the owner can only close empty accounts.
+            if amount != 0 && !pubkey_eq(authority, &source_account.owner) {
+                return Err(TokenError::OwnerMismatch.into());
+            }
+
+            // SAFETY: `authority_info` is not currently borrowed.
+            unsafe { validate_owner(authority, authority_info, remaining)? };
+        } else if destination_account_info.key() != &INCINERATOR_ID {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // Burns the remaining balance.
+
+        if amount == 0 {
+            check_account_owner(mint_info)?;
+        } else {
+            source_account.set_amount(0);
+            // Note: The amount of a token account is always within the range of the
+            // mint supply (`u64`).
+            mint.set_supply(mint.supply() - amount);
+        }
+    }
+
+    // SAFETY: single mutable borrow to `destination_account_info` lamports and
+    // there are no "active" borrows of `source_account_info` account data.
+    unsafe {
+        // Moves the lamports to the destination account.
+        //
+        // Note: This is safe since the runtime checks for balanced instructions
+        // before and after each CPI and instruction, and the total lamports
+        // supply is bound to `u64::MAX`.
+        *destination_account_info.borrow_mut_lamports_unchecked() += source_account_info.lamports();
+        // Closes the source account.
+        source_account_info.close_unchecked();
+    }
+
+    Ok(())
+}
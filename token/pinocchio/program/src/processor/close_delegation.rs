@@ -0,0 +1,83 @@
+use {
+    super::{check_account_owner, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::pubkey_eq, ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::Account, delegation::Delegation, load, load_mut,
+            spending_limit::SpendingLimit, Transmutable,
+        },
+    },
+};
+
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_close_delegation(accounts: &[AccountInfo]) -> ProgramResult {
+    let [source_account_info, delegation_info, destination_account_info, owner_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(source_account_info)?;
+    check_account_owner(delegation_info)?;
+
+    if delegation_info == destination_account_info {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // SAFETY: scoped immutable borrows to `delegation_info` account data and
+    // `load` validates that the account is initialized.
+    let account = unsafe {
+        match delegation_info.data_len() {
+            SpendingLimit::LEN => {
+                load::<SpendingLimit>(delegation_info.borrow_data_unchecked())?.account
+            }
+            _ => load::<Delegation>(delegation_info.borrow_data_unchecked())?.account,
+        }
+    };
+
+    if !pubkey_eq(&account, source_account_info.key()) {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized; additionally, the
+    // account cannot be the delegation or spending limit account since they
+    // have a different length.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_owner(&source_account.owner, owner_info, remaining)? };
+
+    // Revokes the delegation if it is still the delegate of the account.
+    if source_account
+        .delegate()
+        .is_some_and(|delegate| pubkey_eq(delegate, delegation_info.key()))
+    {
+        if source_account.is_frozen()? {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        source_account.clear_delegate();
+        source_account.set_delegated_amount(0);
+    }
+
+    // SAFETY: single mutable borrow to `destination_account_info` lamports and
+    // there are no "active" borrows of `delegation_info` account data.
+    unsafe {
+        // Moves the lamports to the destination account.
+        //
+        // Note: This is safe since the runtime checks for balanced instructions
+        // before and after each CPI and instruction, and the total lamports
+        // supply is bound to `u64::MAX`.
+        *destination_account_info.borrow_mut_lamports_unchecked() += delegation_info.lamports();
+        // Closes the delegation or spending limit account.
+        delegation_info.close_unchecked();
+    }
+
+    Ok(())
+}
@@ -6,14 +6,19 @@ use {
         program_error::ProgramError,
         pubkey::{pubkey_eq, Pubkey},
         syscalls::sol_memcpy_,
+        sysvars::{clock::Clock, instructions::Instructions, Sysvar},
         ProgramResult,
     },
     pinocchio_token_interface::{
         error::TokenError,
+        instruction::TokenInstruction,
+        memo::is_memo_program,
         program::ID as TOKEN_PROGRAM_ID,
         state::{
-            load,
+            delegation::Delegation,
+            load, load_mut,
             multisig::{Multisig, MAX_SIGNERS},
+            spending_limit::SpendingLimit,
             Transmutable,
         },
     },
@@ -22,10 +27,14 @@ use {
 pub mod amount_to_ui_amount;
 pub mod approve;
 pub mod approve_checked;
+pub mod approve_with_expiry;
+pub mod approve_with_spending_limit;
 pub mod batch;
 pub mod burn;
+pub mod burn_and_close;
 pub mod burn_checked;
 pub mod close_account;
+pub mod close_delegation;
 pub mod freeze_account;
 pub mod get_account_data_size;
 pub mod initialize_account;
@@ -38,12 +47,17 @@ pub mod initialize_multisig;
 pub mod initialize_multisig2;
 pub mod mint_to;
 pub mod mint_to_checked;
+pub mod pause_mint;
+pub mod resume_mint;
 pub mod revoke;
 pub mod set_authority;
+pub mod set_memo_required;
+pub mod sweep_and_close;
 pub mod sync_native;
 pub mod thaw_account;
 pub mod transfer;
 pub mod transfer_checked;
+pub mod transfer_many;
 pub mod ui_amount_to_amount;
 pub mod unwrap_lamports;
 pub mod withdraw_excess_lamports;
@@ -52,8 +66,10 @@ pub mod shared;
 
 pub use {
     amount_to_ui_amount::process_amount_to_ui_amount, approve::process_approve,
-    approve_checked::process_approve_checked, batch::process_batch, burn::process_burn,
-    burn_checked::process_burn_checked, close_account::process_close_account,
+    approve_checked::process_approve_checked, approve_with_expiry::process_approve_with_expiry,
+    approve_with_spending_limit::process_approve_with_spending_limit, batch::process_batch,
+    burn::process_burn, burn_and_close::process_burn_and_close, burn_checked::process_burn_checked,
+    close_account::process_close_account, close_delegation::process_close_delegation,
     freeze_account::process_freeze_account, get_account_data_size::process_get_account_data_size,
     initialize_account::process_initialize_account,
     initialize_account2::process_initialize_account2,
@@ -62,10 +78,12 @@ pub use {
     initialize_mint::process_initialize_mint, initialize_mint2::process_initialize_mint2,
     initialize_multisig::process_initialize_multisig,
     initialize_multisig2::process_initialize_multisig2, mint_to::process_mint_to,
-    mint_to_checked::process_mint_to_checked, revoke::process_revoke,
-    set_authority::process_set_authority, sync_native::process_sync_native,
-    thaw_account::process_thaw_account, transfer::process_transfer,
-    transfer_checked::process_transfer_checked, ui_amount_to_amount::process_ui_amount_to_amount,
+    mint_to_checked::process_mint_to_checked, pause_mint::process_pause_mint,
+    resume_mint::process_resume_mint, revoke::process_revoke, set_authority::process_set_authority,
+    set_memo_required::process_set_memo_required, sweep_and_close::process_sweep_and_close,
+    sync_native::process_sync_native, thaw_account::process_thaw_account,
+    transfer::process_transfer, transfer_checked::process_transfer_checked,
+    transfer_many::process_transfer_many, ui_amount_to_amount::process_ui_amount_to_amount,
     unwrap_lamports::process_unwrap_lamports,
     withdraw_excess_lamports::process_withdraw_excess_lamports,
 };
@@ -144,6 +162,161 @@ unsafe fn validate_owner(
     Ok(())
 }
 
+/// Validates delegate signer(s) are present.
+///
+/// When the delegate is a delegation account, it must be a delegation of the
+/// token `account` that has not expired, and the signer accounts are expected
+/// to start with the actual delegate.
+///
+/// # Safety
+///
+/// The `delegate_account_info` will be immutable borrowed when it represents
+/// a delegation or multisig account, therefore it should not have any mutable
+/// borrows when calling this function.
+#[inline(always)]
+unsafe fn validate_delegate(
+    account: &Pubkey,
+    delegate_account_info: &AccountInfo,
+    signers: &[AccountInfo],
+) -> ProgramResult {
+    if unlikely(
+        delegate_account_info.data_len() == Delegation::LEN
+            && delegate_account_info.is_owned_by(&TOKEN_PROGRAM_ID),
+    ) {
+        // SAFETY: the caller guarantees that there are no mutable borrows of
+        // `delegate_account_info` account data and the `load` validates that
+        // the account is initialized.
+        let delegation =
+            unsafe { load::<Delegation>(delegate_account_info.borrow_data_unchecked())? };
+
+        if unlikely(!pubkey_eq(&delegation.account, account)) {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+
+        if delegation.is_expired(&Clock::get()?)? {
+            return Err(TokenError::DelegationExpired.into());
+        }
+
+        let [delegate_info, signers @ ..] = signers else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        // SAFETY: the caller guarantees that there are no mutable borrows of
+        // the signer accounts.
+        unsafe { validate_owner(&delegation.delegate, delegate_info, signers) }
+    } else {
+        // SAFETY: the caller guarantees that there are no mutable borrows of
+        // `delegate_account_info` account data.
+        unsafe { validate_owner(delegate_account_info.key(), delegate_account_info, signers) }
+    }
+}
+
+/// Checks that the `memos` instructions preceding the current top-level
+/// instruction in the transaction are memos.
+///
+/// Transfers made through a cross-program invocation are attributed to the
+/// memos preceding the top-level instruction of the calling program. The
+/// check fails when the top-level instruction is a `Batch` of the token
+/// program, since its memos could not be attributed to a single transfer.
+///
+/// The Instructions sysvar is expected to be the last of the `accounts`.
+#[inline(never)]
+fn check_memo(accounts: &[AccountInfo], memos: usize) -> ProgramResult {
+    let [.., instructions_info] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let instructions = Instructions::try_from(instructions_info)?;
+
+    let index = instructions.load_current_index() as usize;
+    let current = instructions.load_instruction_at(index)?;
+
+    if pubkey_eq(current.get_program_id(), &TOKEN_PROGRAM_ID)
+        && current.get_instruction_data().first() == Some(&(TokenInstruction::Batch as u8))
+    {
+        return Err(TokenError::NoMemo.into());
+    }
+
+    if index < memos {
+        return Err(TokenError::NoMemo.into());
+    }
+
+    for memo_index in index - memos..index {
+        let instruction = instructions.load_instruction_at(memo_index)?;
+
+        if !is_memo_program(instruction.get_program_id()) {
+            return Err(TokenError::NoMemo.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks whether the delegate account is a spending limit account.
+#[inline(always)]
+fn is_spending_limit(delegate_account_info: &AccountInfo) -> bool {
+    delegate_account_info.data_len() == SpendingLimit::LEN
+        && delegate_account_info.is_owned_by(&TOKEN_PROGRAM_ID)
+}
+
+/// Validates the delegate signer(s) of a spending limit account and records
+/// `debit` tokens as spent in the current epoch, after checking that `amount`
+/// tokens are within the amount left to spend.
+///
+/// The spending limit must be of the token `account`, and the signer accounts
+/// are expected to start with the actual delegate.
+///
+/// # Safety
+///
+/// The `spending_limit_info` account data will be mutably borrowed, therefore
+/// it should not have any other borrows when calling this function.
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+unsafe fn spend_allowance(
+    account: &Pubkey,
+    spending_limit_info: &AccountInfo,
+    signers: &[AccountInfo],
+    amount: u64,
+    debit: u64,
+) -> ProgramResult {
+    // SAFETY: the caller guarantees that there are no other borrows of
+    // `spending_limit_info` account data and the `load_mut` validates that
+    // the account is initialized.
+    let spending_limit =
+        unsafe { load_mut::<SpendingLimit>(spending_limit_info.borrow_mut_data_unchecked())? };
+
+    if unlikely(!pubkey_eq(&spending_limit.account, account)) {
+        return Err(TokenError::OwnerMismatch.into());
+    }
+
+    let [delegate_info, signers @ ..] = signers else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: the caller guarantees that there are no mutable borrows of the
+    // signer accounts.
+    unsafe { validate_owner(&spending_limit.delegate, delegate_info, signers)? };
+
+    let epoch = Clock::get()?.epoch;
+
+    if spending_limit.remaining(epoch) < amount {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    // The spending limit is only updated when tokens are spent, so it does
+    // not need to be writable otherwise.
+    if debit > 0 {
+        if spending_limit.epoch() != epoch {
+            spending_limit.set_epoch(epoch);
+            spending_limit.set_spent(0);
+        }
+        // Note: `debit` is at most `amount`, which is within the limit.
+        spending_limit.set_spent(spending_limit.spent() + debit);
+    }
+
+    Ok(())
+}
+
 /// Try to convert a UI representation of a token amount to its raw amount using
 /// the given decimals field
 #[allow(clippy::arithmetic_side_effects)]
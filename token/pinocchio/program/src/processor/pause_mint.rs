@@ -0,0 +1,72 @@
+use {
+    super::{check_account_owner, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo,
+        program_error::ProgramError,
+        sysvars::{rent::Rent, Sysvar},
+        ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            load_mut, load_mut_unchecked,
+            mint::{Mint, PAUSED_FREEZE_AUTHORITY},
+            mint_pause::MintPause,
+            Initializable,
+        },
+    },
+};
+
+#[inline(always)]
+pub fn process_pause_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    let [mint_info, mint_pause_info, authority_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(mint_info)?;
+    check_account_owner(mint_pause_info)?;
+
+    // SAFETY: single mutable borrow to `mint_info` account data and
+    // `load_mut` validates that the mint is initialized.
+    let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+
+    if mint.is_paused() {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    let freeze_authority = *mint
+        .freeze_authority()
+        .ok_or(TokenError::MintCannotFreeze)?;
+
+    // SAFETY: `authority_info` is not currently borrowed; a mint cannot
+    // be a multisignature account.
+    unsafe { validate_owner(&freeze_authority, authority_info, remaining)? };
+
+    // Validates the mint pause account.
+
+    let is_exempt = Rent::get()?.is_exempt(mint_pause_info.lamports(), mint_pause_info.data_len());
+
+    // SAFETY: single mutable borrow to `mint_pause_info` account data; the
+    // length of the account data is validated by `load_mut_unchecked`, which
+    // also guarantees that it is not the mint.
+    let mint_pause =
+        unsafe { load_mut_unchecked::<MintPause>(mint_pause_info.borrow_mut_data_unchecked())? };
+
+    if mint_pause.is_initialized()? {
+        return Err(TokenError::AlreadyInUse.into());
+    }
+
+    if !is_exempt {
+        return Err(TokenError::NotRentExempt.into());
+    }
+
+    // Records the freeze authority and pauses the mint.
+
+    mint_pause.set_initialized(true);
+    mint_pause.mint = *mint_info.key();
+    mint_pause.freeze_authority = freeze_authority;
+
+    mint.set_freeze_authority(&PAUSED_FREEZE_AUTHORITY);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use {
+    super::{check_account_owner, validate_owner},
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::pubkey_eq, ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{load, load_mut, mint::Mint, mint_pause::MintPause},
+    },
+};
+
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_resume_mint(accounts: &[AccountInfo]) -> ProgramResult {
+    let [mint_info, mint_pause_info, destination_account_info, authority_info, remaining @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    check_account_owner(mint_info)?;
+    check_account_owner(mint_pause_info)?;
+
+    if mint_pause_info == destination_account_info {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // SAFETY: single mutable borrow to `mint_info` account data and
+    // `load_mut` validates that the mint is initialized.
+    let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
+
+    if !mint.is_paused() {
+        return Err(TokenError::InvalidState.into());
+    }
+
+    {
+        // SAFETY: scoped immutable borrow to `mint_pause_info` account data and
+        // `load` validates that the account is initialized; the account cannot
+        // be the mint since it has a different length.
+        let mint_pause = unsafe { load::<MintPause>(mint_pause_info.borrow_data_unchecked())? };
+
+        if !pubkey_eq(&mint_pause.mint, mint_info.key()) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        // SAFETY: `authority_info` is not currently borrowed; a mint cannot
+        // be a multisignature account.
+        unsafe { validate_owner(&mint_pause.freeze_authority, authority_info, remaining)? };
+
+        // Restores the freeze authority, which resumes the mint.
+        mint.set_freeze_authority(&mint_pause.freeze_authority);
+    }
+
+    // SAFETY: single mutable borrow to `destination_account_info` lamports and
+    // there are no "active" borrows of `mint_pause_info` account data.
+    unsafe {
+        // Moves the lamports to the destination account.
+        //
+        // Note: This is safe since the runtime checks for balanced instructions
+        // before and after each CPI and instruction, and the total lamports
+        // supply is bound to `u64::MAX`.
+        *destination_account_info.borrow_mut_lamports_unchecked() += mint_pause_info.lamports();
+        // Closes the mint pause account.
+        mint_pause_info.close_unchecked();
+    }
+
+    Ok(())
+}
@@ -7,7 +7,12 @@ use {
     pinocchio_token_interface::{
         error::TokenError,
         instruction::AuthorityType,
-        state::{account::Account, load_mut, mint::Mint, Transmutable},
+        state::{
+            account::Account,
+            load_mut,
+            mint::{Mint, PAUSED_FREEZE_AUTHORITY},
+            Transmutable,
+        },
     },
 };
 
@@ -115,6 +120,10 @@ pub fn process_set_authority(accounts: &[AccountInfo], instruction_data: &[u8])
                 unsafe { validate_owner(freeze_authority, authority_info, remaining)? };
 
                 if let Some(authority) = new_authority {
+                    // Only `PauseMint` can set the freeze authority of a paused mint.
+                    if authority == &PAUSED_FREEZE_AUTHORITY {
+                        return Err(ProgramError::InvalidArgument);
+                    }
                     mint.set_freeze_authority(authority);
                 } else {
                     mint.clear_freeze_authority();
@@ -0,0 +1,44 @@
+use {
+    super::validate_owner,
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load_mut},
+    },
+};
+
+pub fn process_set_memo_required(
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    // instruction data: expected bool (1)
+    let required = match instruction_data.first() {
+        Some(0) => false,
+        Some(1) => true,
+        _ => return Err(TokenError::InvalidInstruction.into()),
+    };
+
+    let [account_info, owner_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // SAFETY: single mutable borrow to `account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let account = unsafe { load_mut::<Account>(account_info.borrow_mut_data_unchecked())? };
+
+    if account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    // The memo-required flag is stored in the native amount of the account.
+    if account.is_native() {
+        return Err(TokenError::NativeNotSupported.into());
+    }
+
+    // SAFETY: `owner_info` is not currently borrowed.
+    unsafe { validate_owner(&account.owner, owner_info, remaining)? };
+
+    account.set_memo_required(required);
+
+    Ok(())
+}
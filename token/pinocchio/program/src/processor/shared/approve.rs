@@ -70,6 +70,10 @@ pub fn process_approve(
         if unlikely(expected_decimals != mint.decimals) {
             return Err(TokenError::MintDecimalsMismatch.into());
         }
+
+        if mint.is_paused() {
+            return Err(TokenError::MintPaused.into());
+        }
     }
 
     // SAFETY: `owner_info` is not currently borrowed.
@@ -1,5 +1,7 @@
 use {
-    crate::processor::{check_account_owner, validate_owner},
+    crate::processor::{
+        check_account_owner, is_spending_limit, spend_allowance, validate_delegate, validate_owner,
+    },
     pinocchio::{
         account_info::AccountInfo, hint::likely, program_error::ProgramError, pubkey::pubkey_eq,
         ProgramResult,
@@ -49,6 +51,10 @@ pub fn process_burn(
         return Err(TokenError::MintMismatch.into());
     }
 
+    if mint.is_paused() {
+        return Err(TokenError::MintPaused.into());
+    }
+
     if let Some(expected_decimals) = expected_decimals {
         if expected_decimals != mint.decimals {
             return Err(TokenError::MintDecimalsMismatch.into());
@@ -57,9 +63,24 @@ pub fn process_burn(
 
     if likely(!source_account.is_owned_by_system_program_or_incinerator()) {
         match source_account.delegate() {
+            Some(delegate)
+                if pubkey_eq(authority_info.key(), delegate)
+                    && is_spending_limit(authority_info) =>
+            {
+                // SAFETY: `authority_info` is not currently borrowed.
+                unsafe {
+                    spend_allowance(
+                        source_account_info.key(),
+                        authority_info,
+                        remaining,
+                        amount,
+                        amount,
+                    )?
+                };
+            }
             Some(delegate) if pubkey_eq(authority_info.key(), delegate) => {
                 // SAFETY: `authority_info` is not currently borrowed.
-                unsafe { validate_owner(delegate, authority_info, remaining)? };
+                unsafe { validate_delegate(source_account_info.key(), authority_info, remaining)? };
 
                 let delegated_amount = source_account
                     .delegated_amount()
@@ -9,7 +9,11 @@ use {
     },
     pinocchio_token_interface::{
         error::TokenError,
-        state::{load_mut_unchecked, mint::Mint, Initializable},
+        state::{
+            load_mut_unchecked,
+            mint::{Mint, PAUSED_FREEZE_AUTHORITY},
+            Initializable,
+        },
     },
 };
 
@@ -81,6 +85,11 @@ pub fn process_initialize_mint(
         return Err(TokenError::NotRentExempt.into());
     }
 
+    // Only `PauseMint` can set the freeze authority of a paused mint.
+    if freeze_authority == Some(&PAUSED_FREEZE_AUTHORITY) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
     // Initialize the mint.
 
     mint.set_initialized();
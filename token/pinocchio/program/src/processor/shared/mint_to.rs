@@ -43,6 +43,10 @@ pub fn process_mint_to(
     // `load_mut` validates that the mint is initialized.
     let mint = unsafe { load_mut::<Mint>(mint_info.borrow_mut_data_unchecked())? };
 
+    if mint.is_paused() {
+        return Err(TokenError::MintPaused.into());
+    }
+
     if let Some(expected_decimals) = expected_decimals {
         if expected_decimals != mint.decimals {
             return Err(TokenError::MintDecimalsMismatch.into());
@@ -5,6 +5,7 @@
 
 pub mod approve;
 pub mod burn;
+pub mod close_account;
 pub mod initialize_account;
 pub mod initialize_mint;
 pub mod initialize_multisig;
@@ -1,71 +1,119 @@
 use {
-    crate::processor::{check_account_owner, validate_owner},
+    crate::processor::{
+        check_account_owner, check_memo, is_spending_limit, spend_allowance, validate_delegate,
+        validate_owner,
+    },
     pinocchio::{
         account_info::AccountInfo, hint::unlikely, program_error::ProgramError, pubkey::pubkey_eq,
         ProgramResult,
     },
     pinocchio_token_interface::{
         error::TokenError,
-        state::{account::Account, load, load_mut, load_mut_unchecked, mint::Mint},
+        state::{account::Account, load, load_mut, mint::Mint},
     },
 };
 
+/// Accounts of a `transfer` or `transfer_checked` instruction.
+pub struct TransferAccounts<'a> {
+    /// The source token account.
+    pub source: &'a AccountInfo,
+    /// The mint account and the expected decimals, only present for
+    /// `transfer_checked`.
+    pub mint: Option<(&'a AccountInfo, u8)>,
+    /// The destination token account.
+    pub destination: &'a AccountInfo,
+    /// The source account's owner or delegate.
+    pub authority: &'a AccountInfo,
+    /// The signer accounts of a multisignature authority.
+    pub signers: &'a [AccountInfo],
+}
+
+impl<'a> TransferAccounts<'a> {
+    /// Splits the instruction accounts.
+    ///
+    /// Accounts expected depend on whether we have the mint `decimals` or not;
+    /// when we have the mint `decimals`, we expect the mint account to be present.
+    #[inline(always)]
+    pub fn new(
+        accounts: &'a [AccountInfo],
+        expected_decimals: Option<u8>,
+    ) -> Result<Self, ProgramError> {
+        if let Some(decimals) = expected_decimals {
+            let [source, mint, destination, authority, signers @ ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            Ok(Self {
+                source,
+                mint: Some((mint, decimals)),
+                destination,
+                authority,
+                signers,
+            })
+        } else {
+            let [source, destination, authority, signers @ ..] = accounts else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+            Ok(Self {
+                source,
+                mint: None,
+                destination,
+                authority,
+                signers,
+            })
+        }
+    }
+}
+
 #[inline(always)]
-#[allow(clippy::arithmetic_side_effects)]
 pub fn process_transfer(
     accounts: &[AccountInfo],
     amount: u64,
     expected_decimals: Option<u8>,
 ) -> ProgramResult {
-    // Accounts expected depend on whether we have the mint `decimals` or not; when
-    // we have the mint `decimals`, we expect the mint account to be present.
-
-    let (
-        source_account_info,
-        expected_mint_info,
-        destination_account_info,
-        authority_info,
-        remaining,
-    ) = if let Some(decimals) = expected_decimals {
-        let [source_account_info, mint_info, destination_account_info, authority_info, remaining @ ..] =
-            accounts
-        else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-        (
-            source_account_info,
-            Some((mint_info, decimals)),
-            destination_account_info,
-            authority_info,
-            remaining,
-        )
-    } else {
-        let [source_account_info, destination_account_info, authority_info, remaining @ ..] =
-            accounts
-        else {
-            return Err(ProgramError::NotEnoughAccountKeys);
-        };
-        (
-            source_account_info,
-            None,
-            destination_account_info,
-            authority_info,
-            remaining,
-        )
-    };
+    let accounts = TransferAccounts::new(accounts, expected_decimals)?;
 
     // Validates source and destination accounts.
 
-    // SAFETY: single mutable borrow to `source_account_info` account data and
-    // `load_mut` validates that the account is initialized.
+    // SAFETY: single mutable borrow to `source` account data and `load_mut`
+    // validates that the account is initialized.
     let source_account =
-        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+        unsafe { load_mut::<Account>(accounts.source.borrow_mut_data_unchecked())? };
 
     // Comparing whether the AccountInfo's "point" to the same account or
     // not - this is a faster comparison since it just checks the internal
     // raw pointer.
-    let self_transfer = source_account_info == destination_account_info;
+    let destination_account = if unlikely(accounts.source == accounts.destination) {
+        None
+    } else {
+        // SAFETY: single mutable borrow to `destination` account data and
+        // `load_mut` validates that the account is initialized; additionally,
+        // the account is guaranteed to be different than `source`.
+        Some(unsafe { load_mut::<Account>(accounts.destination.borrow_mut_data_unchecked())? })
+    };
 
+    transfer(
+        &accounts,
+        source_account,
+        destination_account,
+        amount,
+        false,
+    )
+}
+
+/// Transfers `amount` tokens between already loaded token accounts.
+///
+/// The `destination_account` is `None` for self transfers. When
+/// `owners_checked` is `true`, the caller has already validated that both
+/// token accounts are owned by the token program.
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn transfer(
+    accounts: &TransferAccounts,
+    source_account: &mut Account,
+    destination_account: Option<&mut Account>,
+    amount: u64,
+    owners_checked: bool,
+) -> ProgramResult {
     // Implicitly validates that the account has enough tokens by calculating the
     // remaining amount - the amount is only updated on the account if the transfer
     // is successful.
@@ -79,22 +127,7 @@ pub fn process_transfer(
     //   - transfers to different accounts: we need to check that the source and
     //     destination accounts are not frozen, have the same mint, and the source
     //     account has enough tokens.
-    let remaining_amount = if unlikely(self_transfer) {
-        if source_account.is_frozen()? {
-            return Err(TokenError::AccountFrozen.into());
-        }
-
-        source_account
-            .amount()
-            .checked_sub(amount)
-            .ok_or(TokenError::InsufficientFunds)?
-    } else {
-        // SAFETY: scoped immutable borrow to `destination_account_info` account data
-        // and `load` validates that the account is initialized; additionally,
-        // the account is guaranteed to be different than `source_account_info`.
-        let destination_account =
-            unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())? };
-
+    let remaining_amount = if let Some(destination_account) = destination_account.as_deref() {
         if source_account.is_frozen()? || destination_account.is_frozen()? {
             return Err(TokenError::AccountFrozen.into());
         }
@@ -109,11 +142,20 @@ pub fn process_transfer(
         }
 
         remaining_amount
+    } else {
+        if source_account.is_frozen()? {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        source_account
+            .amount()
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?
     };
 
     // Validates the mint information.
 
-    if let Some((mint_info, decimals)) = expected_mint_info {
+    if let Some((mint_info, decimals)) = accounts.mint {
         if !pubkey_eq(mint_info.key(), &source_account.mint) {
             return Err(TokenError::MintMismatch.into());
         }
@@ -125,68 +167,147 @@ pub fn process_transfer(
         if decimals != mint.decimals {
             return Err(TokenError::MintDecimalsMismatch.into());
         }
+
+        if mint.is_paused() {
+            return Err(TokenError::MintPaused.into());
+        }
+    }
+
+    // Validates the memo of transfers into accounts that require one.
+
+    if let Some(destination_account) = destination_account.as_deref() {
+        if unlikely(destination_account.is_memo_required()) {
+            check_memo(accounts.signers, 1)?;
+        }
     }
 
     // Validates the authority (delegate or owner).
 
-    if source_account.delegate() == Some(authority_info.key()) {
+    // Self-transfers do not debit the source account.
+    let debit = if destination_account.is_some() {
+        Some(amount)
+    } else {
+        None
+    };
+
+    validate_authority(
+        accounts.source,
+        source_account,
+        accounts.authority,
+        accounts.signers,
+        amount,
+        debit,
+    )?;
+
+    match destination_account {
+        Some(destination_account) if amount != 0 => {
+            // Moves the tokens.
+
+            source_account.set_amount(remaining_amount);
+
+            credit(
+                accounts.source,
+                source_account.is_native(),
+                accounts.destination,
+                destination_account,
+                amount,
+            );
+        }
+        _ if !owners_checked => {
+            // Validates the token accounts owner since we are not writing
+            // to these account.
+            check_account_owner(accounts.source)?;
+            check_account_owner(accounts.destination)?;
+        }
+        _ => (),
+    }
+
+    Ok(())
+}
+
+/// Validates the owner or delegate of the source account for a transfer of
+/// `amount` tokens, of which `debit` tokens leave the source account.
+///
+/// The `debit` is `None` when all tokens are transferred to the source
+/// account itself, in which case the delegated amount is not updated.
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn validate_authority(
+    source_account_info: &AccountInfo,
+    source_account: &mut Account,
+    authority_info: &AccountInfo,
+    signers: &[AccountInfo],
+    amount: u64,
+    debit: Option<u64>,
+) -> ProgramResult {
+    if source_account.delegate() == Some(authority_info.key()) && is_spending_limit(authority_info)
+    {
+        // SAFETY: `authority_info` is not currently borrowed.
+        unsafe {
+            spend_allowance(
+                source_account_info.key(),
+                authority_info,
+                signers,
+                amount,
+                debit.unwrap_or(0),
+            )
+        }
+    } else if source_account.delegate() == Some(authority_info.key()) {
         // SAFETY: `authority_info` is not currently borrowed.
-        unsafe { validate_owner(authority_info.key(), authority_info, remaining)? };
+        unsafe { validate_delegate(source_account_info.key(), authority_info, signers)? };
 
-        let delegated_amount = source_account
-            .delegated_amount()
-            .checked_sub(amount)
-            .ok_or(TokenError::InsufficientFunds)?;
+        if source_account.delegated_amount() < amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
 
-        if !self_transfer {
+        if let Some(debit) = debit {
+            // Note: The delegated amount is already validated to be greater than
+            // or equal to `amount`, which includes `debit`.
+            let delegated_amount = source_account.delegated_amount() - debit;
             source_account.set_delegated_amount(delegated_amount);
 
             if delegated_amount == 0 {
                 source_account.clear_delegate();
             }
         }
+
+        Ok(())
     } else {
         // SAFETY: `authority_info` is not currently borrowed.
-        unsafe { validate_owner(&source_account.owner, authority_info, remaining)? };
+        unsafe { validate_owner(&source_account.owner, authority_info, signers) }
     }
+}
 
-    if self_transfer || amount == 0 {
-        // Validates the token accounts owner since we are not writing
-        // to these account.
-        check_account_owner(source_account_info)?;
-        check_account_owner(destination_account_info)?;
-    } else {
-        // Moves the tokens.
-
-        source_account.set_amount(remaining_amount);
-
-        // SAFETY: single mutable borrow to `destination_account_info` account data; the
-        // account is guaranteed to be initialized and different than
-        // `source_account_info`; it was also already validated to be a token
-        // account.
-        let destination_account = unsafe {
-            load_mut_unchecked::<Account>(destination_account_info.borrow_mut_data_unchecked())?
-        };
-        // Note: The amount of a token account is always within the range of the
-        // mint supply (`u64`).
-        destination_account.set_amount(destination_account.amount() + amount);
-
-        if source_account.is_native() {
-            // SAFETY: single mutable borrow to `source_account_info` lamports.
-            let source_lamports = unsafe { source_account_info.borrow_mut_lamports_unchecked() };
-            // Note: The amount of a source token account is already validated and the
-            // `lamports` on the account is always greater than `amount`.
-            *source_lamports -= amount;
-
-            // SAFETY: single mutable borrow to `destination_account_info` lamports; the
-            // account is already validated to be different from
-            // `source_account_info`.
-            let destination_lamports =
-                unsafe { destination_account_info.borrow_mut_lamports_unchecked() };
-            // Note: The total lamports supply is bound to `u64::MAX`.
-            *destination_lamports += amount;
-        }
-    }
+/// Credits `amount` tokens, already debited from the source account, to the
+/// destination account, moving the same amount of lamports for native
+/// accounts.
+///
+/// The destination account must be different than the source account.
+#[inline(always)]
+#[allow(clippy::arithmetic_side_effects)]
+pub fn credit(
+    source_account_info: &AccountInfo,
+    is_native: bool,
+    destination_account_info: &AccountInfo,
+    destination_account: &mut Account,
+    amount: u64,
+) {
+    // Note: The amount of a token account is always within the range of the
+    // mint supply (`u64`).
+    destination_account.set_amount(destination_account.amount() + amount);
 
-    Ok(())
+    if is_native {
+        // SAFETY: single mutable borrow to `source_account_info` lamports.
+        let source_lamports = unsafe { source_account_info.borrow_mut_lamports_unchecked() };
+        // Note: The amount of a source token account is already validated and the
+        // `lamports` on the account is always greater than `amount`.
+        *source_lamports -= amount;
+
+        // SAFETY: single mutable borrow to `destination_account_info` lamports; the
+        // account is already validated to be different from `source_account_info`.
+        let destination_lamports =
+            unsafe { destination_account_info.borrow_mut_lamports_unchecked() };
+        // Note: The total lamports supply is bound to `u64::MAX`.
+        *destination_lamports += amount;
+    }
 }
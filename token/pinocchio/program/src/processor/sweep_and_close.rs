@@ -0,0 +1,61 @@
+use {
+    super::shared::{self, transfer::TransferAccounts},
+    pinocchio::{account_info::AccountInfo, program_error::ProgramError, ProgramResult},
+    pinocchio_token_interface::state::{account::Account, load_mut},
+};
+
+pub fn process_sweep_and_close(accounts: &[AccountInfo]) -> ProgramResult {
+    let [source_account_info, destination_account_info, recipient_info, authority_info, signers @ ..] =
+        accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    // Comparing whether the AccountInfo's "point" to the same account or
+    // not - this is a faster comparison since it just checks the internal
+    // raw pointer.
+    if source_account_info == destination_account_info {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // Transfers the whole balance to the destination account.
+
+    {
+        let transfer_accounts = TransferAccounts {
+            source: source_account_info,
+            mint: None,
+            destination: destination_account_info,
+            authority: authority_info,
+            signers,
+        };
+
+        // SAFETY: scoped mutable borrow to `source_account_info` account data and
+        // `load_mut` validates that the account is initialized.
+        let source_account =
+            unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+        // SAFETY: scoped mutable borrow to `destination_account_info` account data
+        // and `load_mut` validates that the account is initialized; additionally,
+        // the account is guaranteed to be different than `source_account_info`.
+        let destination_account =
+            unsafe { load_mut::<Account>(destination_account_info.borrow_mut_data_unchecked())? };
+
+        let amount = source_account.amount();
+
+        shared::transfer::transfer(
+            &transfer_accounts,
+            source_account,
+            Some(destination_account),
+            amount,
+            false,
+        )?;
+    }
+
+    // Closes the (now empty) source account.
+
+    shared::close_account::close_account(
+        source_account_info,
+        recipient_info,
+        authority_info,
+        signers,
+    )
+}
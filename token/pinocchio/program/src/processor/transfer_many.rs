@@ -0,0 +1,159 @@
+use {
+    super::{
+        check_account_owner, check_memo,
+        shared::transfer::{credit, validate_authority},
+        U64_BYTES,
+    },
+    pinocchio::{
+        account_info::AccountInfo, program_error::ProgramError, pubkey::pubkey_eq, ProgramResult,
+    },
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{account::Account, load, load_mut, load_mut_unchecked},
+    },
+};
+
+#[allow(clippy::arithmetic_side_effects)]
+pub fn process_transfer_many(accounts: &[AccountInfo], instruction_data: &[u8]) -> ProgramResult {
+    // instruction data: expected [u64 (8); N]
+    if instruction_data.is_empty() || instruction_data.len() % U64_BYTES != 0 {
+        return Err(TokenError::InvalidInstruction.into());
+    }
+
+    let amounts = instruction_data
+        .chunks_exact(U64_BYTES)
+        // SAFETY: Each chunk is guaranteed to have `U64_BYTES` bytes.
+        .map(|amount| unsafe { u64::from_le_bytes(*(amount.as_ptr() as *const [u8; U64_BYTES])) });
+
+    let destinations = instruction_data.len() / U64_BYTES;
+
+    let [source_account_info, authority_info, remaining @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if remaining.len() < destinations {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    // SAFETY: The number of accounts was validated above.
+    let (destination_infos, remaining) = unsafe {
+        (
+            remaining.get_unchecked(..destinations),
+            remaining.get_unchecked(destinations..),
+        )
+    };
+
+    // Validates the source account.
+
+    check_account_owner(source_account_info)?;
+
+    // SAFETY: single mutable borrow to `source_account_info` account data and
+    // `load_mut` validates that the account is initialized.
+    let source_account =
+        unsafe { load_mut::<Account>(source_account_info.borrow_mut_data_unchecked())? };
+
+    if source_account.is_frozen()? {
+        return Err(TokenError::AccountFrozen.into());
+    }
+
+    let mut total_amount = 0u64;
+    // Amount of tokens leaving the source account, excluding the ones
+    // "transferred" to the source account itself.
+    let mut debited_amount = 0u64;
+    let mut self_transfer = true;
+
+    for (destination_account_info, amount) in destination_infos.iter().zip(amounts.clone()) {
+        total_amount = total_amount
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        // Comparing whether the AccountInfo's "point" to the same account or
+        // not - this is a faster comparison since it just checks the internal
+        // raw pointer.
+        if source_account_info != destination_account_info {
+            debited_amount += amount;
+            self_transfer = false;
+        }
+    }
+
+    if source_account.amount() < total_amount {
+        return Err(TokenError::InsufficientFunds.into());
+    }
+
+    // Validates the destination accounts.
+
+    // Number of transfers into accounts that require a memo.
+    let mut memos = 0;
+
+    for destination_account_info in destination_infos {
+        check_account_owner(destination_account_info)?;
+
+        if source_account_info != destination_account_info {
+            // SAFETY: scoped immutable borrow to `destination_account_info` account
+            // data and `load` validates that the account is initialized;
+            // additionally, the account is guaranteed to be different than
+            // `source_account_info`.
+            let destination_account =
+                unsafe { load::<Account>(destination_account_info.borrow_data_unchecked())? };
+
+            if destination_account.is_frozen()? {
+                return Err(TokenError::AccountFrozen.into());
+            }
+
+            if !pubkey_eq(&source_account.mint, &destination_account.mint) {
+                return Err(TokenError::MintMismatch.into());
+            }
+
+            if destination_account.is_memo_required() {
+                memos += 1;
+            }
+        }
+    }
+
+    if memos > 0 {
+        check_memo(remaining, memos)?;
+    }
+
+    // Validates the authority (delegate or owner).
+
+    validate_authority(
+        source_account_info,
+        source_account,
+        authority_info,
+        remaining,
+        total_amount,
+        (!self_transfer).then_some(debited_amount),
+    )?;
+
+    // Moves the tokens.
+
+    // Note: The amount of the source account is already validated to be greater
+    // than or equal to `total_amount`, which includes `debited_amount`.
+    source_account.set_amount(source_account.amount() - debited_amount);
+
+    let is_native = source_account.is_native();
+
+    for (destination_account_info, amount) in destination_infos.iter().zip(amounts) {
+        if source_account_info == destination_account_info || amount == 0 {
+            continue;
+        }
+
+        // SAFETY: single mutable borrow to `destination_account_info` account data;
+        // the account is guaranteed to be initialized and different than
+        // `source_account_info`; it was also already validated to be a token
+        // account.
+        let destination_account = unsafe {
+            load_mut_unchecked::<Account>(destination_account_info.borrow_mut_data_unchecked())?
+        };
+
+        credit(
+            source_account_info,
+            is_native,
+            destination_account_info,
+            destination_account,
+            amount,
+        );
+    }
+
+    Ok(())
+}
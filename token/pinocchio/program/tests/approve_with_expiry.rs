@@ -0,0 +1,510 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::result::Check,
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::Account as TokenAccount,
+            account_state::AccountState,
+            delegation::{Delegation, ExpiryType},
+            load_mut_unchecked,
+            mint::Mint,
+            Transmutable,
+        },
+    },
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_interface::state::Expiry,
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let space = size_of::<TokenAccount>();
+    let lamports = Rent::default().minimum_balance(space);
+
+    let mut data: Vec<u8> = vec![0u8; space];
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(data.as_mut_slice()).unwrap() };
+    token.set_account_state(AccountState::Initialized);
+    token.mint = *mint.as_array();
+    token.owner = *owner.as_array();
+    token.set_amount(amount);
+
+    Account {
+        lamports,
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn create_delegation_account(
+    account: &Pubkey,
+    delegate: &Pubkey,
+    expiry_type: ExpiryType,
+    expiry: [u8; 8],
+) -> Account {
+    let mut data = vec![0u8; Delegation::LEN];
+    let delegation = unsafe { load_mut_unchecked::<Delegation>(data.as_mut_slice()).unwrap() };
+
+    delegation.set_initialized(true);
+    delegation.account = *account.as_array();
+    delegation.delegate = *delegate.as_array();
+    delegation.set_expiry(expiry_type, expiry);
+
+    Account {
+        lamports: Rent::default().minimum_balance(Delegation::LEN),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn approve_with_expiry() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    // uninitialized delegation account
+    let delegation_key = Pubkey::new_unique();
+    let delegation_account = Account {
+        lamports: Rent::default().minimum_balance(Delegation::LEN),
+        data: vec![0u8; Delegation::LEN],
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    };
+
+    let instruction = spl_token_interface::instruction::approve_with_expiry(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &delegation_key,
+        &delegate_key,
+        &owner_key,
+        &[],
+        100,
+        Expiry::Slot(10),
+    )
+    .unwrap();
+
+    // It should succeed to approve the delegation account as the delegate.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (delegation_key, delegation_account),
+            (delegate_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&source_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.delegate, Some(delegation_key).into());
+    assert_eq!(token_account.delegated_amount, 100);
+
+    let account = result.get_account(&delegation_key).unwrap();
+    let delegation = spl_token_interface::state::Delegation::unpack(&account.data).unwrap();
+    assert_eq!(delegation.account, source_account_key);
+    assert_eq!(delegation.delegate, delegate_key);
+    assert_eq!(delegation.expiry, Expiry::Slot(10));
+}
+
+#[test]
+fn transfer_and_burn_with_expired_delegation() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+
+    let mut mint_account = create_mint_account(owner_key, None, 0, &TOKEN_PROGRAM_ID);
+    let mint = unsafe { load_mut_unchecked::<Mint>(&mut mint_account.data).unwrap() };
+    mint.set_supply(1_000);
+
+    let delegation_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint_key, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(delegation_key.as_array());
+    token.set_delegated_amount(100);
+
+    let delegation_account = create_delegation_account(
+        &source_account_key,
+        &delegate_key,
+        ExpiryType::Slot,
+        10u64.to_le_bytes(),
+    );
+
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account = create_token_account(&mint_key, &owner_key, 0);
+
+    let accounts = [
+        (source_account_key, source_account),
+        (mint_key, mint_account),
+        (destination_account_key, destination_account),
+        (delegation_key, delegation_account),
+        (delegate_key, Account::default()),
+    ];
+
+    let transfer = spl_token_interface::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &delegation_key,
+        &[&delegate_key],
+        10,
+    )
+    .unwrap();
+    let burn = spl_token_interface::instruction::burn(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &mint_key,
+        &delegation_key,
+        &[&delegate_key],
+        10,
+    )
+    .unwrap();
+
+    let mut mollusk = mollusk();
+
+    // It should succeed to transfer and burn before the expiry.
+
+    mollusk.warp_to_slot(9);
+
+    mollusk.process_and_validate_instruction(&transfer, &accounts, &[Check::success()]);
+    mollusk.process_and_validate_instruction(&burn, &accounts, &[Check::success()]);
+
+    // It should fail to transfer and burn once the expiry is reached.
+
+    mollusk.warp_to_slot(10);
+
+    mollusk.process_and_validate_instruction(
+        &transfer,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            TokenError::DelegationExpired as u32,
+        ))],
+    );
+    mollusk.process_and_validate_instruction(
+        &burn,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            TokenError::DelegationExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn transfer_with_unix_timestamp_expiry() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let delegation_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(delegation_key.as_array());
+    token.set_delegated_amount(100);
+
+    let delegation_account = create_delegation_account(
+        &source_account_key,
+        &delegate_key,
+        ExpiryType::UnixTimestamp,
+        1_000i64.to_le_bytes(),
+    );
+
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account = create_token_account(&mint, &owner_key, 0);
+
+    let accounts = [
+        (source_account_key, source_account),
+        (destination_account_key, destination_account),
+        (delegation_key, delegation_account),
+        (delegate_key, Account::default()),
+    ];
+
+    let mut instruction = spl_token_interface::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &delegation_key,
+        &[&delegate_key],
+        10,
+    )
+    .unwrap();
+
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.unix_timestamp = 999;
+
+    // It should succeed to transfer before the expiry.
+
+    mollusk.process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+
+    // It should fail to transfer without the delegate signature.
+
+    instruction.accounts[3].is_signer = false;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+
+    // It should fail to transfer once the expiry is reached.
+
+    instruction.accounts[3].is_signer = true;
+    mollusk.sysvars.clock.unix_timestamp = 1_000;
+
+    mollusk.process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            TokenError::DelegationExpired as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_approve_with_expiry_with_delegation_in_use() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    // delegation account of a different token account
+    let delegation_key = Pubkey::new_unique();
+    let delegation_account = create_delegation_account(
+        &Pubkey::new_unique(),
+        &delegate_key,
+        ExpiryType::Slot,
+        u64::MAX.to_le_bytes(),
+    );
+
+    let instruction = spl_token_interface::instruction::approve_with_expiry(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &delegation_key,
+        &delegate_key,
+        &owner_key,
+        &[],
+        100,
+        Expiry::Slot(10),
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (delegation_key, delegation_account),
+            (delegate_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::AlreadyInUse as u32,
+        ))],
+    );
+}
+
+#[test]
+fn close_delegation() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let delegation_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(delegation_key.as_array());
+    token.set_delegated_amount(100);
+
+    let delegation_account = create_delegation_account(
+        &source_account_key,
+        &delegate_key,
+        ExpiryType::Slot,
+        u64::MAX.to_le_bytes(),
+    );
+    let delegation_lamports = delegation_account.lamports;
+
+    let destination_key = Pubkey::new_unique();
+
+    let instruction = spl_token_interface::instruction::close_delegation(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &delegation_key,
+        &destination_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed to close the delegation account, which reclaims its
+    // lamports and revokes the delegation.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (delegation_key, delegation_account),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&delegation_key).closed().build(),
+            Check::account(&destination_key)
+                .lamports(delegation_lamports)
+                .build(),
+        ],
+    );
+
+    let account = result.get_account(&source_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.delegate, None.into());
+    assert_eq!(token_account.delegated_amount, 0);
+}
+
+#[test]
+fn close_delegation_after_approve() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let delegation_key = Pubkey::new_unique();
+
+    // source account approved to a different delegate after the delegation
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(delegate_key.as_array());
+    token.set_delegated_amount(100);
+
+    let delegation_account = create_delegation_account(
+        &source_account_key,
+        &delegate_key,
+        ExpiryType::Slot,
+        u64::MAX.to_le_bytes(),
+    );
+    let delegation_lamports = delegation_account.lamports;
+
+    let destination_key = Pubkey::new_unique();
+
+    let instruction = spl_token_interface::instruction::close_delegation(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &delegation_key,
+        &destination_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed to close the delegation account without changing the
+    // current delegate.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (delegation_key, delegation_account),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&delegation_key).closed().build(),
+            Check::account(&destination_key)
+                .lamports(delegation_lamports)
+                .build(),
+        ],
+    );
+
+    let account = result.get_account(&source_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.delegate, Some(delegate_key).into());
+    assert_eq!(token_account.delegated_amount, 100);
+}
+
+#[test]
+fn fail_close_delegation_with_invalid_accounts() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let delegation_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    let close_delegation = |owner: &Pubkey| {
+        spl_token_interface::instruction::close_delegation(
+            &TOKEN_PROGRAM_ID,
+            &source_account_key,
+            &delegation_key,
+            &destination_key,
+            owner,
+            &[],
+        )
+        .unwrap()
+    };
+
+    // It should fail to close the delegation account of a different token
+    // account.
+
+    let delegation_account = create_delegation_account(
+        &Pubkey::new_unique(),
+        &delegate_key,
+        ExpiryType::Slot,
+        u64::MAX.to_le_bytes(),
+    );
+
+    mollusk().process_and_validate_instruction(
+        &close_delegation(&owner_key),
+        &[
+            (source_account_key, source_account.clone()),
+            (delegation_key, delegation_account),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+
+    // It should fail to close the delegation account as the delegate.
+
+    let delegation_account = create_delegation_account(
+        &source_account_key,
+        &delegate_key,
+        ExpiryType::Slot,
+        u64::MAX.to_le_bytes(),
+    );
+
+    mollusk().process_and_validate_instruction(
+        &close_delegation(&delegate_key),
+        &[
+            (source_account_key, source_account),
+            (delegation_key, delegation_account),
+            (destination_key, Account::default()),
+            (delegate_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::OwnerMismatch as u32,
+        ))],
+    );
+}
@@ -0,0 +1,359 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::result::Check,
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
+            mint::Mint, spending_limit::SpendingLimit, Transmutable,
+        },
+    },
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let space = size_of::<TokenAccount>();
+    let lamports = Rent::default().minimum_balance(space);
+
+    let mut data: Vec<u8> = vec![0u8; space];
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(data.as_mut_slice()).unwrap() };
+    token.set_account_state(AccountState::Initialized);
+    token.mint = *mint.as_array();
+    token.owner = *owner.as_array();
+    token.set_amount(amount);
+
+    Account {
+        lamports,
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn create_spending_limit_account(
+    account: &Pubkey,
+    delegate: &Pubkey,
+    limit: u64,
+    epoch: u64,
+    spent: u64,
+) -> Account {
+    let mut data = vec![0u8; SpendingLimit::LEN];
+    let spending_limit =
+        unsafe { load_mut_unchecked::<SpendingLimit>(data.as_mut_slice()).unwrap() };
+
+    spending_limit.set_initialized(true);
+    spending_limit.account = *account.as_array();
+    spending_limit.delegate = *delegate.as_array();
+    spending_limit.set_limit(limit);
+    spending_limit.set_epoch(epoch);
+    spending_limit.set_spent(spent);
+
+    Account {
+        lamports: Rent::default().minimum_balance(SpendingLimit::LEN),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn approve_with_spending_limit() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    // uninitialized spending limit account
+    let spending_limit_key = Pubkey::new_unique();
+    let spending_limit_account = Account {
+        lamports: Rent::default().minimum_balance(SpendingLimit::LEN),
+        data: vec![0u8; SpendingLimit::LEN],
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    };
+
+    let instruction = spl_token_interface::instruction::approve_with_spending_limit(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &spending_limit_key,
+        &delegate_key,
+        &owner_key,
+        &[],
+        100,
+    )
+    .unwrap();
+
+    // It should succeed to approve the spending limit account as the delegate.
+
+    let mut mollusk = mollusk();
+    mollusk.sysvars.clock.epoch = 5;
+
+    let result = mollusk.process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (spending_limit_key, spending_limit_account),
+            (delegate_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&source_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.delegate, Some(spending_limit_key).into());
+    assert_eq!(token_account.delegated_amount, 100);
+
+    let account = result.get_account(&spending_limit_key).unwrap();
+    let spending_limit = spl_token_interface::state::SpendingLimit::unpack(&account.data).unwrap();
+    assert_eq!(spending_limit.account, source_account_key);
+    assert_eq!(spending_limit.delegate, delegate_key);
+    assert_eq!(spending_limit.limit, 100);
+    assert_eq!(spending_limit.epoch, 5);
+    assert_eq!(spending_limit.spent, 0);
+}
+
+#[test]
+fn transfer_with_spending_limit_refills_per_epoch() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let spending_limit_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(spending_limit_key.as_array());
+    token.set_delegated_amount(100);
+
+    // 80 tokens already spent in epoch 0
+    let spending_limit_account =
+        create_spending_limit_account(&source_account_key, &delegate_key, 100, 0, 80);
+
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account = create_token_account(&mint, &owner_key, 0);
+
+    let mut transfer = spl_token_interface::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &spending_limit_key,
+        &[&delegate_key],
+        50,
+    )
+    .unwrap();
+    transfer.accounts[2].is_writable = true;
+
+    let accounts = [
+        (source_account_key, source_account),
+        (destination_account_key, destination_account),
+        (spending_limit_key, spending_limit_account),
+        (delegate_key, Account::default()),
+    ];
+
+    let mut mollusk = mollusk();
+
+    // It should fail to transfer more than the amount left in the epoch.
+
+    mollusk.process_and_validate_instruction(
+        &transfer,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            TokenError::InsufficientFunds as u32,
+        ))],
+    );
+
+    // It should succeed to transfer once the spending limit refills in a
+    // new epoch.
+
+    mollusk.sysvars.clock.epoch = 1;
+
+    let result =
+        mollusk.process_and_validate_instruction(&transfer, &accounts, &[Check::success()]);
+
+    let account = result.get_account(&spending_limit_key).unwrap();
+    let spending_limit = spl_token_interface::state::SpendingLimit::unpack(&account.data).unwrap();
+    assert_eq!(spending_limit.epoch, 1);
+    assert_eq!(spending_limit.spent, 50);
+
+    // The delegate is not cleared since the delegated amount is not consumed.
+
+    let account = result.get_account(&source_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 950);
+    assert_eq!(token_account.delegate, Some(spending_limit_key).into());
+    assert_eq!(token_account.delegated_amount, 100);
+}
+
+#[test]
+fn self_transfer_does_not_consume_spending_limit() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let spending_limit_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(spending_limit_key.as_array());
+    token.set_delegated_amount(100);
+
+    let spending_limit_account =
+        create_spending_limit_account(&source_account_key, &delegate_key, 100, 0, 0);
+
+    // The spending limit account is not writable, since it is not updated.
+    let instruction = spl_token_interface::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &source_account_key,
+        &spending_limit_key,
+        &[&delegate_key],
+        100,
+    )
+    .unwrap();
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (spending_limit_key, spending_limit_account),
+            (delegate_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&spending_limit_key).unwrap();
+    let spending_limit = spl_token_interface::state::SpendingLimit::unpack(&account.data).unwrap();
+    assert_eq!(spending_limit.spent, 0);
+}
+
+#[test]
+fn fail_burn_over_spending_limit() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let spending_limit_key = Pubkey::new_unique();
+
+    let mut mint_account = create_mint_account(owner_key, None, 0, &TOKEN_PROGRAM_ID);
+    let mint = unsafe { load_mut_unchecked::<Mint>(&mut mint_account.data).unwrap() };
+    mint.set_supply(1_000);
+
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint_key, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(spending_limit_key.as_array());
+    token.set_delegated_amount(100);
+
+    let spending_limit_account =
+        create_spending_limit_account(&source_account_key, &delegate_key, 100, 0, 0);
+
+    let mut instruction = spl_token_interface::instruction::burn(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &mint_key,
+        &spending_limit_key,
+        &[&delegate_key],
+        101,
+    )
+    .unwrap();
+    instruction.accounts[2].is_writable = true;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (mint_key, mint_account),
+            (spending_limit_key, spending_limit_account),
+            (delegate_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::InsufficientFunds as u32,
+        ))],
+    );
+}
+
+#[test]
+fn close_spending_limit() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let spending_limit_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint, &owner_key, 1_000);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(spending_limit_key.as_array());
+    token.set_delegated_amount(100);
+
+    let spending_limit_account =
+        create_spending_limit_account(&source_account_key, &delegate_key, 100, 0, 40);
+    let spending_limit_lamports = spending_limit_account.lamports;
+
+    let destination_key = Pubkey::new_unique();
+
+    let close_spending_limit = |owner: &Pubkey| {
+        spl_token_interface::instruction::close_delegation(
+            &TOKEN_PROGRAM_ID,
+            &source_account_key,
+            &spending_limit_key,
+            &destination_key,
+            owner,
+            &[],
+        )
+        .unwrap()
+    };
+
+    // It should fail to close the spending limit account as the delegate.
+
+    mollusk().process_and_validate_instruction(
+        &close_spending_limit(&delegate_key),
+        &[
+            (source_account_key, source_account.clone()),
+            (spending_limit_key, spending_limit_account.clone()),
+            (destination_key, Account::default()),
+            (delegate_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::OwnerMismatch as u32,
+        ))],
+    );
+
+    // It should succeed to close the spending limit account as the owner,
+    // which reclaims its lamports and revokes the delegation.
+
+    let result = mollusk().process_and_validate_instruction(
+        &close_spending_limit(&owner_key),
+        &[
+            (source_account_key, source_account),
+            (spending_limit_key, spending_limit_account),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&spending_limit_key).closed().build(),
+            Check::account(&destination_key)
+                .lamports(spending_limit_lamports)
+                .build(),
+        ],
+    );
+
+    let account = result.get_account(&source_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.delegate, None.into());
+    assert_eq!(token_account.delegated_amount, 0);
+}
@@ -5,6 +5,7 @@ use {
     agave_feature_set::FeatureSet,
     mollusk_svm::{result::Check, Mollusk},
     pinocchio_token_interface::{
+        error::TokenError,
         native_mint,
         state::{
             account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
@@ -12,7 +13,6 @@ use {
         },
     },
     solana_account::Account,
-    solana_instruction::{AccountMeta, Instruction},
     solana_keypair::Keypair,
     solana_program_error::ProgramError,
     solana_program_pack::Pack,
@@ -25,32 +25,6 @@ use {
     solana_transaction::Transaction,
 };
 
-fn batch_instruction(instructions: Vec<Instruction>) -> Result<Instruction, ProgramError> {
-    // Create a `Vec` of ordered `AccountMeta`s
-    let mut accounts: Vec<AccountMeta> = vec![];
-    // Start with the batch discriminator
-    let mut data: Vec<u8> = vec![0xff];
-
-    for instruction in instructions {
-        // Error out on non-token IX.
-        if instruction.program_id.ne(&spl_token_interface::ID) {
-            return Err(ProgramError::IncorrectProgramId);
-        }
-
-        data.push(instruction.accounts.len() as u8);
-        data.push(instruction.data.len() as u8);
-
-        data.extend_from_slice(&instruction.data);
-        accounts.extend_from_slice(&instruction.accounts);
-    }
-
-    Ok(Instruction {
-        program_id: spl_token_interface::ID,
-        data,
-        accounts,
-    })
-}
-
 #[tokio::test]
 async fn batch_initialize_mint_transfer_close() {
     let context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
@@ -171,7 +145,7 @@ async fn batch_initialize_mint_transfer_close() {
     )
     .unwrap();
 
-    let batch_ix = batch_instruction(vec![
+    let batch_ix = spl_token_interface::instruction::batch(vec![
         initialize_mint_ix,
         initialize_mint_with_freeze_authority_ix,
         intialize_owner_a_ta_a,
@@ -350,21 +324,200 @@ async fn batch_transfer() {
     let destination_account =
         create_token_account(&mint_key, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
 
-    let instruction = batch_instruction(vec![spl_token_interface::instruction::transfer(
+    let instruction =
+        spl_token_interface::instruction::batch(vec![spl_token_interface::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &source_account_key,
+            &destination_account_key,
+            &authority_key,
+            &[],
+            500_000_000,
+        )
+        .unwrap()])
+        .unwrap();
+
+    // Expected to succeed.
+
+    mollusk().process_and_validate_instruction_chain(
+        &[(&instruction, &[Check::success(), Check::all_rent_exempt()])],
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (
+                authority_key,
+                Account {
+                    lamports: Rent::default().minimum_balance(0),
+                    ..Default::default()
+                },
+            ),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn batch_transfer_with_shared_accounts() {
+    let authority_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+
+    let mint_key = Pubkey::new_unique();
+    let mint = create_mint(&mint_authority_key, 1_000_000_000, 6, &TOKEN_PROGRAM_ID);
+
+    // source account
+    //   - amount: 1_000_000_000
+    //   - mint: mint_key
+    //   - is_native: false
+    //   - program_id: TOKEN_PROGRAM_ID
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(
+        &mint_key,
+        &authority_key,
+        false,
+        1_000_000_000,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    // Account A and B
+    //   - amount: 0
+    //   - mint: mint_key
+    //   - is_native: false
+    //   - program_id: TOKEN_PROGRAM_ID
+    let account_a_key = Pubkey::new_unique();
+    let account_a = create_token_account(&mint_key, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
+    let account_b_key = Pubkey::new_unique();
+    let account_b = create_token_account(&mint_key, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
+
+    // Batch instruction where accounts appear in several instructions
+    //   - transfer 300 from source to account A
+    //   - transfer 200 from source to account B (checked)
+    //   - transfer 100 from account A to account B
+    //   - transfer 100 from account B to account B
+    let instruction = spl_token_interface::instruction::batch(vec![
+        spl_token_interface::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &source_account_key,
+            &account_a_key,
+            &authority_key,
+            &[],
+            300,
+        )
+        .unwrap(),
+        spl_token_interface::instruction::transfer_checked(
+            &TOKEN_PROGRAM_ID,
+            &source_account_key,
+            &mint_key,
+            &account_b_key,
+            &authority_key,
+            &[],
+            200,
+            6,
+        )
+        .unwrap(),
+        spl_token_interface::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &account_a_key,
+            &account_b_key,
+            &authority_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        spl_token_interface::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &account_b_key,
+            &account_b_key,
+            &authority_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+    ])
+    .unwrap();
+
+    // Expected to succeed.
+
+    let source_amount = 999_999_500u64.to_le_bytes();
+    let account_a_amount = 200u64.to_le_bytes();
+    let account_b_amount = 300u64.to_le_bytes();
+
+    mollusk().process_and_validate_instruction_chain(
+        &[(
+            &instruction,
+            &[
+                Check::success(),
+                Check::account(&source_account_key)
+                    .data_slice(64, &source_amount)
+                    .build(),
+                Check::account(&account_a_key)
+                    .data_slice(64, &account_a_amount)
+                    .build(),
+                Check::account(&account_b_key)
+                    .data_slice(64, &account_b_amount)
+                    .build(),
+                Check::all_rent_exempt(),
+            ],
+        )],
+        &[
+            (source_account_key, source_account),
+            (account_a_key, account_a),
+            (account_b_key, account_b),
+            (mint_key, mint),
+            (
+                authority_key,
+                Account {
+                    lamports: Rent::default().minimum_balance(0),
+                    ..Default::default()
+                },
+            ),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn batch_fail_transfer_with_insufficient_funds() {
+    let authority_key = Pubkey::new_unique();
+    let mint_key = Pubkey::new_unique();
+
+    // source account
+    //   - amount: 1_000
+    //   - mint: mint_key
+    //   - is_native: false
+    //   - program_id: TOKEN_PROGRAM_ID
+    let source_account_key = Pubkey::new_unique();
+    let source_account =
+        create_token_account(&mint_key, &authority_key, false, 1_000, &TOKEN_PROGRAM_ID);
+
+    // destination account
+    //   - amount: 0
+    //   - mint: mint_key
+    //   - is_native: false
+    //   - program_id: TOKEN_PROGRAM_ID
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account =
+        create_token_account(&mint_key, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
+
+    let transfer = spl_token_interface::instruction::transfer(
         &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
         &[],
-        500_000_000,
+        600,
     )
-    .unwrap()])
     .unwrap();
 
-    // Expected to succeed.
+    let instruction =
+        spl_token_interface::instruction::batch(vec![transfer.clone(), transfer]).unwrap();
+
+    // Expected to fail since the second transfer exceeds the remaining amount.
 
     mollusk().process_and_validate_instruction_chain(
-        &[(&instruction, &[Check::success(), Check::all_rent_exempt()])],
+        &[(
+            &instruction,
+            &[
+                Check::err(ProgramError::Custom(TokenError::InsufficientFunds as u32)),
+                Check::all_rent_exempt(),
+            ],
+        )],
         &[
             (source_account_key, source_account),
             (destination_account_key, destination_account),
@@ -408,16 +561,17 @@ async fn batch_fail_transfer_with_invalid_program_owner() {
     let destination_account =
         create_token_account(&native_mint, &authority_key, true, 0, &TOKEN_PROGRAM_ID);
 
-    let instruction = batch_instruction(vec![spl_token_interface::instruction::transfer(
-        &TOKEN_PROGRAM_ID,
-        &source_account_key,
-        &destination_account_key,
-        &authority_key,
-        &[],
-        500_000_000,
-    )
-    .unwrap()])
-    .unwrap();
+    let instruction =
+        spl_token_interface::instruction::batch(vec![spl_token_interface::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &source_account_key,
+            &destination_account_key,
+            &authority_key,
+            &[],
+            500_000_000,
+        )
+        .unwrap()])
+        .unwrap();
 
     // Expected to fail since source account has an invalid program owner.
 
@@ -474,17 +628,19 @@ async fn batch_fail_transfer_checked_with_invalid_program_owner() {
     let destination_account =
         create_token_account(&native_mint_key, &authority_key, true, 0, &TOKEN_PROGRAM_ID);
 
-    let instruction = batch_instruction(vec![spl_token_interface::instruction::transfer_checked(
-        &TOKEN_PROGRAM_ID,
-        &source_account_key,
-        &native_mint_key,
-        &destination_account_key,
-        &authority_key,
-        &[],
-        500_000_000,
-        9,
-    )
-    .unwrap()])
+    let instruction = spl_token_interface::instruction::batch(vec![
+        spl_token_interface::instruction::transfer_checked(
+            &TOKEN_PROGRAM_ID,
+            &source_account_key,
+            &native_mint_key,
+            &destination_account_key,
+            &authority_key,
+            &[],
+            500_000_000,
+            9,
+        )
+        .unwrap(),
+    ])
     .unwrap();
 
     // Expected to fail since source account has an invalid program owner.
@@ -552,7 +708,7 @@ async fn batch_fail_swap_tokens_with_invalid_program_owner() {
     // Batch instruction to swap tokens
     //   - transfer 300 from account A to account B
     //   - transfer 300 from account C to account A
-    let instruction = batch_instruction(vec![
+    let instruction = spl_token_interface::instruction::batch(vec![
         spl_token_interface::instruction::sync_native(&TOKEN_PROGRAM_ID, &account_b_key).unwrap(),
         spl_token_interface::instruction::sync_native(&TOKEN_PROGRAM_ID, &account_c_key).unwrap(),
         spl_token_interface::instruction::transfer(
@@ -601,6 +757,87 @@ async fn batch_fail_swap_tokens_with_invalid_program_owner() {
     );
 }
 
+#[tokio::test]
+async fn batch_fail_transfers_with_invalid_program_owner() {
+    let mint_key = Pubkey::new_unique();
+    let invalid_program_id = Pubkey::new_from_array([2; 32]);
+    let authority_key = Pubkey::new_unique();
+
+    // Account A
+    //   - amount: 1_000
+    //   - mint: mint_key
+    //   - is_native: false
+    //   - program_id: TOKEN_PROGRAM_ID
+    let account_a_key = Pubkey::new_unique();
+    let account_a =
+        create_token_account(&mint_key, &authority_key, false, 1_000, &TOKEN_PROGRAM_ID);
+
+    // Account B
+    //   - amount: 0
+    //   - mint: mint_key
+    //   - is_native: false
+    //   - program_id: TOKEN_PROGRAM_ID
+    let account_b_key = Pubkey::new_unique();
+    let account_b = create_token_account(&mint_key, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
+
+    // Account C
+    //   - amount: 0
+    //   - mint: mint_key
+    //   - is_native: false
+    //   - program_id: invalid_program_id
+    let account_c_key = Pubkey::new_unique();
+    let account_c = create_token_account(&mint_key, &authority_key, false, 0, &invalid_program_id);
+
+    // Batch instruction with transfers only
+    //   - transfer 300 from account A to account B
+    //   - transfer 300 from account B to account C
+    let instruction = spl_token_interface::instruction::batch(vec![
+        spl_token_interface::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &account_a_key,
+            &account_b_key,
+            &authority_key,
+            &[],
+            300,
+        )
+        .unwrap(),
+        spl_token_interface::instruction::transfer(
+            &TOKEN_PROGRAM_ID,
+            &account_b_key,
+            &account_c_key,
+            &authority_key,
+            &[],
+            300,
+        )
+        .unwrap(),
+    ])
+    .unwrap();
+
+    // Expected to fail since account C has an invalid program owner.
+
+    mollusk().process_and_validate_instruction_chain(
+        &[(
+            &instruction,
+            &[
+                Check::err(ProgramError::IncorrectProgramId),
+                Check::all_rent_exempt(),
+            ],
+        )],
+        &[
+            (account_a_key, account_a),
+            (account_b_key, account_b),
+            (account_c_key, account_c),
+            (
+                authority_key,
+                Account {
+                    lamports: Rent::default().minimum_balance(0),
+                    ..Default::default()
+                },
+            ),
+        ],
+    );
+}
+
 #[tokio::test]
 async fn batch_fail_mint_to_with_invalid_program_owner() {
     let invalid_program_id = Pubkey::new_from_array([2; 32]);
@@ -625,7 +862,7 @@ async fn batch_fail_mint_to_with_invalid_program_owner() {
     let account_b_key = Pubkey::new_unique();
     let account_b = create_token_account(&mint_key, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
 
-    let instruction = batch_instruction(vec![
+    let instruction = spl_token_interface::instruction::batch(vec![
         spl_token_interface::instruction::mint_to(
             &TOKEN_PROGRAM_ID,
             &mint_key,
@@ -702,7 +939,7 @@ async fn batch_fail_burn_with_invalid_program_owner() {
         &invalid_program_id,
     );
 
-    let instruction = batch_instruction(vec![
+    let instruction = spl_token_interface::instruction::batch(vec![
         spl_token_interface::instruction::mint_to(
             &TOKEN_PROGRAM_ID,
             &mint_key,
@@ -0,0 +1,236 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::result::Check,
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::{Account as TokenAccount, INCINERATOR_ID},
+            account_state::AccountState,
+            load_mut_unchecked,
+            mint::Mint,
+        },
+    },
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::system_program,
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let space = size_of::<TokenAccount>();
+    let lamports = Rent::default().minimum_balance(space);
+
+    let mut data: Vec<u8> = vec![0u8; space];
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(data.as_mut_slice()).unwrap() };
+    token.set_account_state(AccountState::Initialized);
+    token.mint = *mint.as_array();
+    token.owner = *owner.as_array();
+    token.set_amount(amount);
+
+    Account {
+        lamports,
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn create_mint(supply: u64) -> Account {
+    let mut mint = create_mint_account(Pubkey::new_unique(), None, 4, &TOKEN_PROGRAM_ID);
+    unsafe { load_mut_unchecked::<Mint>(&mut mint.data).unwrap() }.set_supply(supply);
+    mint
+}
+
+#[test]
+fn burn_and_close() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    // token account:
+    //   - amount: 1_000
+    let account_key = Pubkey::new_unique();
+    let account = create_token_account(&mint_key, &owner_key, 1_000);
+    let account_lamports = account.lamports;
+
+    let instruction = spl_token_interface::instruction::burn_and_close(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &mint_key,
+        &destination_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed to burn the balance and close the account.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (account_key, account),
+            (mint_key, create_mint(1_500)),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .lamports(0)
+                .data(&[])
+                .owner(&system_program::id())
+                .build(),
+            Check::account(&destination_key)
+                .lamports(account_lamports)
+                .build(),
+        ],
+    );
+
+    let mint = result.get_account(&mint_key).unwrap();
+    let mint = spl_token_interface::state::Mint::unpack(&mint.data).unwrap();
+    assert_eq!(mint.supply, 500);
+}
+
+#[test]
+fn fail_burn_and_close_frozen_account() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let account_key = Pubkey::new_unique();
+    let mut account = create_token_account(&mint_key, &owner_key, 1_000);
+    unsafe { load_mut_unchecked::<TokenAccount>(&mut account.data).unwrap() }
+        .set_account_state(AccountState::Frozen);
+
+    let instruction = spl_token_interface::instruction::burn_and_close(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &mint_key,
+        &destination_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (account_key, account),
+            (mint_key, create_mint(1_000)),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::AccountFrozen as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_burn_and_close_with_close_authority_and_balance() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let close_authority_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    // token account:
+    //   - close authority: different than the owner
+    let account_key = Pubkey::new_unique();
+    let mut account = create_token_account(&mint_key, &owner_key, 1_000);
+    unsafe { load_mut_unchecked::<TokenAccount>(&mut account.data).unwrap() }
+        .set_close_authority(close_authority_key.as_array());
+
+    let instruction = spl_token_interface::instruction::burn_and_close(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &mint_key,
+        &destination_key,
+        &close_authority_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should fail since only the owner can burn the remaining balance.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (account_key, account),
+            (mint_key, create_mint(1_000)),
+            (destination_key, Account::default()),
+            (close_authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::OwnerMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn burn_and_close_incinerator_owned_account() {
+    let mint_key = Pubkey::new_unique();
+    let incinerator_key = Pubkey::new_from_array(INCINERATOR_ID);
+    let authority_key = Pubkey::new_unique();
+
+    // token account:
+    //   - owner: incinerator
+    let account_key = Pubkey::new_unique();
+    let account = create_token_account(&mint_key, &incinerator_key, 1_000);
+
+    let accounts = |destination_key: Pubkey| {
+        [
+            (account_key, account.clone()),
+            (mint_key, create_mint(1_000)),
+            (destination_key, Account::default()),
+            (authority_key, Account::default()),
+        ]
+    };
+
+    // It should fail to close the account to a destination other than the
+    // incinerator.
+
+    let destination_key = Pubkey::new_unique();
+    let instruction = spl_token_interface::instruction::burn_and_close(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &mint_key,
+        &destination_key,
+        &authority_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &accounts(destination_key),
+        &[Check::err(ProgramError::InvalidAccountData)],
+    );
+
+    // It should succeed to close the account to the incinerator without
+    // the owner signature.
+
+    let mut instruction = spl_token_interface::instruction::burn_and_close(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &mint_key,
+        &incinerator_key,
+        &authority_key,
+        &[],
+    )
+    .unwrap();
+    instruction.accounts[3].is_signer = false;
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &accounts(incinerator_key),
+        &[Check::success()],
+    );
+}
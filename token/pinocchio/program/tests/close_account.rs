@@ -63,3 +63,84 @@ async fn close_account() {
     let token_account = context.banks_client.get_account(account).await.unwrap();
     assert!(token_account.is_none());
 }
+
+#[tokio::test]
+async fn close_account_to_token_account() {
+    let mut context = ProgramTest::new("pinocchio_token_program", TOKEN_PROGRAM_ID, None)
+        .start_with_context()
+        .await;
+
+    // Given a mint account.
+
+    let mint_authority = Keypair::new();
+
+    let mint = mint::initialize(
+        &mut context,
+        mint_authority.pubkey(),
+        None,
+        &TOKEN_PROGRAM_ID,
+    )
+    .await
+    .unwrap();
+
+    // And two token accounts.
+
+    let owner = Keypair::new();
+
+    let account =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+    let destination =
+        account::initialize(&mut context, &mint, &owner.pubkey(), &TOKEN_PROGRAM_ID).await;
+
+    let account_lamports = context
+        .banks_client
+        .get_account(account)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let destination_lamports = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // When we close the account sending the lamports to the other token
+    // account.
+
+    let close_account_ix = spl_token_interface::instruction::close_account(
+        &spl_token_interface::ID,
+        &account,
+        &destination,
+        &owner.pubkey(),
+        &[],
+    )
+    .unwrap();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[close_account_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &owner],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the account must not exist and the destination must have
+    // received its lamports.
+
+    let token_account = context.banks_client.get_account(account).await.unwrap();
+    assert!(token_account.is_none());
+
+    let destination_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        destination_account.lamports,
+        destination_lamports + account_lamports
+    );
+}
@@ -0,0 +1,961 @@
+//! Compute unit benchmarks for p-token.
+//!
+//! Every instruction is executed once with a successful set of accounts and
+//! the consumed compute units are compared against the baseline recorded in
+//! `tests/compute_units.json`. The test fails when any benchmark consumes
+//! more than the baseline plus a tolerance (a percentage, set with
+//! `CU_TOLERANCE`; defaults to `1`), or when the baseline is missing a
+//! benchmark or has one that no longer exists.
+//!
+//! To update the baseline, run the test with `UPDATE_CU_BASELINE=1`.
+//!
+//! The baseline comparison is ignored by default since it needs a baseline
+//! recorded from the SBF build; record it with `pnpm p-token:cu:update` and
+//! run the comparison with `pnpm p-token:cu`.
+//!
+//! Instructions with a fast path in the entrypoint are also measured through
+//! the generic path, so the savings of each fast path can be verified.
+
+mod setup;
+
+use {
+    crate::setup::{mollusk::mollusk, TOKEN_PROGRAM_ID},
+    mollusk_svm::result::ProgramResult,
+    solana_account::Account,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_option::COption,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_interface::{
+        instruction::{self, AuthorityType},
+        native_mint,
+        state::{
+            Account as TokenAccount, AccountState, Delegation, Expiry, Mint, MintPause, Multisig,
+            SpendingLimit, PAUSED_MINT_FREEZE_AUTHORITY,
+        },
+    },
+    std::{collections::BTreeMap, fs},
+};
+
+/// Path of the baseline file.
+const BASELINE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/compute_units.json");
+
+/// Default tolerance (in percent) for regressions.
+const DEFAULT_TOLERANCE: f64 = 1.0;
+
+const MINT: Pubkey = Pubkey::new_from_array([1; 32]);
+const SOURCE: Pubkey = Pubkey::new_from_array([2; 32]);
+const DESTINATION: Pubkey = Pubkey::new_from_array([3; 32]);
+const DESTINATION_2: Pubkey = Pubkey::new_from_array([12; 32]);
+const OWNER: Pubkey = Pubkey::new_from_array([4; 32]);
+const AUTHORITY: Pubkey = Pubkey::new_from_array([5; 32]);
+const DELEGATE: Pubkey = Pubkey::new_from_array([6; 32]);
+const MULTISIG: Pubkey = Pubkey::new_from_array([7; 32]);
+const DELEGATION: Pubkey = Pubkey::new_from_array([13; 32]);
+const SPENDING_LIMIT: Pubkey = Pubkey::new_from_array([14; 32]);
+const MINT_PAUSE: Pubkey = Pubkey::new_from_array([15; 32]);
+const SIGNERS: [Pubkey; 3] = [
+    Pubkey::new_from_array([8; 32]),
+    Pubkey::new_from_array([9; 32]),
+    Pubkey::new_from_array([10; 32]),
+];
+
+/// Instructions handled by a fast path in the entrypoint.
+///
+/// Each of them is also benchmarked as `<name>_generic`, with an extra
+/// (ignored) account that forces the instruction through the generic
+/// entrypoint path.
+const FAST_PATHS: [&str; 6] = [
+    "transfer",
+    "transfer_checked",
+    "mint_to",
+    "burn",
+    "close_account",
+    "close_account_to_authority",
+];
+
+/// A single benchmarked instruction.
+#[derive(Clone)]
+struct Benchmark {
+    name: String,
+    instruction: Instruction,
+    accounts: Vec<(Pubkey, Account)>,
+}
+
+impl Benchmark {
+    fn new(name: &str, instruction: Instruction, accounts: Vec<(Pubkey, Account)>) -> Self {
+        Self {
+            name: name.to_string(),
+            instruction,
+            accounts,
+        }
+    }
+
+    /// Returns a copy of the benchmark that skips the entrypoint fast paths.
+    fn generic(&self) -> Self {
+        let extra = Pubkey::new_from_array([11; 32]);
+        let mut benchmark = self.clone();
+
+        benchmark.name = format!("{}_generic", self.name);
+        benchmark
+            .instruction
+            .accounts
+            .push(AccountMeta::new_readonly(extra, false));
+        benchmark.accounts.push((extra, system_account()));
+        benchmark
+    }
+}
+
+/// Creates an account owned by the token program holding `state`.
+fn program_account<T: Pack>(state: &T) -> Account {
+    let mut data = vec![0; T::LEN];
+    state.pack_into_slice(&mut data);
+
+    Account {
+        lamports: Rent::default().minimum_balance(T::LEN),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+/// Creates an uninitialized account owned by the token program.
+fn uninitialized_account(len: usize) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(len),
+        data: vec![0; len],
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn system_account() -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(0),
+        ..Default::default()
+    }
+}
+
+fn mint(supply: u64, freeze_authority: COption<Pubkey>) -> Account {
+    program_account(&Mint {
+        mint_authority: COption::Some(AUTHORITY),
+        supply,
+        decimals: 6,
+        is_initialized: true,
+        freeze_authority,
+    })
+}
+
+fn token_account(owner: &Pubkey, amount: u64) -> TokenAccount {
+    TokenAccount {
+        mint: MINT,
+        owner: *owner,
+        amount,
+        state: AccountState::Initialized,
+        ..Default::default()
+    }
+}
+
+fn multisig() -> Account {
+    let mut signers = [Pubkey::default(); 11];
+    signers[..SIGNERS.len()].copy_from_slice(&SIGNERS);
+
+    program_account(&Multisig {
+        m: 2,
+        n: SIGNERS.len() as u8,
+        is_initialized: true,
+        signers,
+    })
+}
+
+fn signer_accounts() -> Vec<(Pubkey, Account)> {
+    SIGNERS
+        .iter()
+        .map(|signer| (*signer, system_account()))
+        .collect()
+}
+
+fn benchmarks() -> Vec<Benchmark> {
+    let rent_sysvar = mollusk().sysvars.keyed_account_for_rent_sysvar();
+    let multisig_signers = [&SIGNERS[0], &SIGNERS[1]];
+
+    let transfer_accounts = vec![
+        (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+        (DESTINATION, program_account(&token_account(&OWNER, 0))),
+        (OWNER, system_account()),
+    ];
+    let transfer_checked_accounts = vec![
+        (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+        (MINT, mint(1_000, COption::None)),
+        (DESTINATION, program_account(&token_account(&OWNER, 0))),
+        (OWNER, system_account()),
+    ];
+    let mut multisig_transfer_accounts = vec![
+        (SOURCE, program_account(&token_account(&MULTISIG, 1_000))),
+        (DESTINATION, program_account(&token_account(&MULTISIG, 0))),
+        (MULTISIG, multisig()),
+    ];
+    multisig_transfer_accounts.extend(signer_accounts());
+
+    let transfer =
+        instruction::transfer(&TOKEN_PROGRAM_ID, &SOURCE, &DESTINATION, &OWNER, &[], 100).unwrap();
+    let transfer_checked = instruction::transfer_checked(
+        &TOKEN_PROGRAM_ID,
+        &SOURCE,
+        &MINT,
+        &DESTINATION,
+        &OWNER,
+        &[],
+        100,
+        6,
+    )
+    .unwrap();
+    let mint_to =
+        instruction::mint_to(&TOKEN_PROGRAM_ID, &MINT, &DESTINATION, &AUTHORITY, &[], 100).unwrap();
+    let burn = instruction::burn(&TOKEN_PROGRAM_ID, &SOURCE, &MINT, &OWNER, &[], 1_000).unwrap();
+    let close_account =
+        instruction::close_account(&TOKEN_PROGRAM_ID, &SOURCE, &DESTINATION, &OWNER, &[]).unwrap();
+
+    let mut delegated = token_account(&OWNER, 1_000);
+    delegated.delegate = COption::Some(DELEGATE);
+    delegated.delegated_amount = 100;
+
+    let mut frozen = token_account(&OWNER, 1_000);
+    frozen.state = AccountState::Frozen;
+
+    let mut native = token_account(&OWNER, 1_000);
+    native.mint = native_mint::ID;
+    native.is_native = COption::Some(Rent::default().minimum_balance(TokenAccount::LEN));
+    let mut native_account = program_account(&native);
+    native_account.lamports += 2_000;
+
+    let mut excess_mint = mint(0, COption::None);
+    excess_mint.lamports += 1_000;
+
+    let mut benchmarks = vec![
+        Benchmark::new(
+            "initialize_mint",
+            instruction::initialize_mint(&TOKEN_PROGRAM_ID, &MINT, &AUTHORITY, None, 6).unwrap(),
+            vec![
+                (MINT, uninitialized_account(Mint::LEN)),
+                rent_sysvar.clone(),
+            ],
+        ),
+        Benchmark::new(
+            "initialize_mint2",
+            instruction::initialize_mint2(&TOKEN_PROGRAM_ID, &MINT, &AUTHORITY, None, 6).unwrap(),
+            vec![(MINT, uninitialized_account(Mint::LEN))],
+        ),
+        Benchmark::new(
+            "initialize_account",
+            instruction::initialize_account(&TOKEN_PROGRAM_ID, &SOURCE, &MINT, &OWNER).unwrap(),
+            vec![
+                (SOURCE, uninitialized_account(TokenAccount::LEN)),
+                (MINT, mint(0, COption::None)),
+                (OWNER, system_account()),
+                rent_sysvar.clone(),
+            ],
+        ),
+        Benchmark::new(
+            "initialize_account2",
+            instruction::initialize_account2(&TOKEN_PROGRAM_ID, &SOURCE, &MINT, &OWNER).unwrap(),
+            vec![
+                (SOURCE, uninitialized_account(TokenAccount::LEN)),
+                (MINT, mint(0, COption::None)),
+                rent_sysvar.clone(),
+            ],
+        ),
+        Benchmark::new(
+            "initialize_account3",
+            instruction::initialize_account3(&TOKEN_PROGRAM_ID, &SOURCE, &MINT, &OWNER).unwrap(),
+            vec![
+                (SOURCE, uninitialized_account(TokenAccount::LEN)),
+                (MINT, mint(0, COption::None)),
+            ],
+        ),
+        Benchmark::new(
+            "initialize_multisig",
+            instruction::initialize_multisig(
+                &TOKEN_PROGRAM_ID,
+                &MULTISIG,
+                &SIGNERS.iter().collect::<Vec<_>>(),
+                2,
+            )
+            .unwrap(),
+            [
+                vec![
+                    (MULTISIG, uninitialized_account(Multisig::LEN)),
+                    rent_sysvar.clone(),
+                ],
+                signer_accounts(),
+            ]
+            .concat(),
+        ),
+        Benchmark::new(
+            "initialize_multisig2",
+            instruction::initialize_multisig2(
+                &TOKEN_PROGRAM_ID,
+                &MULTISIG,
+                &SIGNERS.iter().collect::<Vec<_>>(),
+                2,
+            )
+            .unwrap(),
+            [
+                vec![(MULTISIG, uninitialized_account(Multisig::LEN))],
+                signer_accounts(),
+            ]
+            .concat(),
+        ),
+        Benchmark::new(
+            "initialize_immutable_owner",
+            instruction::initialize_immutable_owner(&TOKEN_PROGRAM_ID, &SOURCE).unwrap(),
+            vec![(SOURCE, uninitialized_account(TokenAccount::LEN))],
+        ),
+        // Handled by the `transfer` fast path in the entrypoint.
+        Benchmark::new("transfer", transfer.clone(), transfer_accounts.clone()),
+        Benchmark::new(
+            "transfer_multisig",
+            instruction::transfer(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &DESTINATION,
+                &MULTISIG,
+                &multisig_signers,
+                100,
+            )
+            .unwrap(),
+            multisig_transfer_accounts.clone(),
+        ),
+        // Handled by the `transfer_checked` fast path in the entrypoint.
+        Benchmark::new(
+            "transfer_checked",
+            transfer_checked.clone(),
+            transfer_checked_accounts.clone(),
+        ),
+        Benchmark::new(
+            "approve",
+            instruction::approve(&TOKEN_PROGRAM_ID, &SOURCE, &DELEGATE, &OWNER, &[], 100).unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (DELEGATE, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "approve_checked",
+            instruction::approve_checked(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &MINT,
+                &DELEGATE,
+                &OWNER,
+                &[],
+                100,
+                6,
+            )
+            .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (MINT, mint(1_000, COption::None)),
+                (DELEGATE, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "revoke",
+            instruction::revoke(&TOKEN_PROGRAM_ID, &SOURCE, &OWNER, &[]).unwrap(),
+            vec![
+                (SOURCE, program_account(&delegated)),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "set_authority",
+            instruction::set_authority(
+                &TOKEN_PROGRAM_ID,
+                &MINT,
+                Some(&OWNER),
+                AuthorityType::MintTokens,
+                &AUTHORITY,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                (MINT, mint(0, COption::None)),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "mint_to",
+            mint_to.clone(),
+            vec![
+                (MINT, mint(0, COption::None)),
+                (DESTINATION, program_account(&token_account(&OWNER, 0))),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "mint_to_checked",
+            instruction::mint_to_checked(
+                &TOKEN_PROGRAM_ID,
+                &MINT,
+                &DESTINATION,
+                &AUTHORITY,
+                &[],
+                100,
+                6,
+            )
+            .unwrap(),
+            vec![
+                (MINT, mint(0, COption::None)),
+                (DESTINATION, program_account(&token_account(&OWNER, 0))),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "burn",
+            burn.clone(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (MINT, mint(1_000, COption::None)),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "burn_checked",
+            instruction::burn_checked(&TOKEN_PROGRAM_ID, &SOURCE, &MINT, &OWNER, &[], 100, 6)
+                .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (MINT, mint(1_000, COption::None)),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "close_account",
+            close_account.clone(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 0))),
+                (DESTINATION, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        // The authority also receives the lamports, which is handled by the
+        // `close_account` fast path.
+        Benchmark::new(
+            "close_account_to_authority",
+            instruction::close_account(&TOKEN_PROGRAM_ID, &SOURCE, &OWNER, &OWNER, &[]).unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 0))),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "freeze_account",
+            instruction::freeze_account(&TOKEN_PROGRAM_ID, &SOURCE, &MINT, &AUTHORITY, &[])
+                .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (MINT, mint(1_000, COption::Some(AUTHORITY))),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "thaw_account",
+            instruction::thaw_account(&TOKEN_PROGRAM_ID, &SOURCE, &MINT, &AUTHORITY, &[]).unwrap(),
+            vec![
+                (SOURCE, program_account(&frozen)),
+                (MINT, mint(1_000, COption::Some(AUTHORITY))),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "sync_native",
+            instruction::sync_native(&TOKEN_PROGRAM_ID, &SOURCE).unwrap(),
+            vec![(SOURCE, native_account.clone())],
+        ),
+        Benchmark::new(
+            "get_account_data_size",
+            instruction::get_account_data_size(&TOKEN_PROGRAM_ID, &MINT).unwrap(),
+            vec![(MINT, mint(0, COption::None))],
+        ),
+        Benchmark::new(
+            "amount_to_ui_amount",
+            instruction::amount_to_ui_amount(&TOKEN_PROGRAM_ID, &MINT, 1_234_567).unwrap(),
+            vec![(MINT, mint(0, COption::None))],
+        ),
+        Benchmark::new(
+            "ui_amount_to_amount",
+            instruction::ui_amount_to_amount(&TOKEN_PROGRAM_ID, &MINT, "1.234567").unwrap(),
+            vec![(MINT, mint(0, COption::None))],
+        ),
+        Benchmark::new(
+            "withdraw_excess_lamports",
+            instruction::withdraw_excess_lamports(
+                &TOKEN_PROGRAM_ID,
+                &MINT,
+                &DESTINATION,
+                &AUTHORITY,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                (MINT, excess_mint),
+                (DESTINATION, system_account()),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "unwrap_lamports",
+            instruction::unwrap_lamports(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &DESTINATION,
+                &OWNER,
+                &[],
+                Some(500),
+            )
+            .unwrap(),
+            vec![
+                (SOURCE, native_account),
+                (DESTINATION, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "transfer_many",
+            instruction::transfer_many(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &[(&DESTINATION, 100), (&DESTINATION_2, 200)],
+                &OWNER,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (OWNER, system_account()),
+                (DESTINATION, program_account(&token_account(&OWNER, 0))),
+                (DESTINATION_2, program_account(&token_account(&OWNER, 0))),
+            ],
+        ),
+        Benchmark::new(
+            "burn_and_close",
+            instruction::burn_and_close(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &MINT,
+                &DESTINATION,
+                &OWNER,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (MINT, mint(1_000, COption::None)),
+                (DESTINATION, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "sweep_and_close",
+            instruction::sweep_and_close(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &DESTINATION,
+                &DESTINATION_2,
+                &OWNER,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (DESTINATION, program_account(&token_account(&OWNER, 0))),
+                (DESTINATION_2, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "approve_with_expiry",
+            instruction::approve_with_expiry(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &DELEGATION,
+                &DELEGATE,
+                &OWNER,
+                &[],
+                500,
+                Expiry::Slot(u64::MAX),
+            )
+            .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (DELEGATION, uninitialized_account(Delegation::LEN)),
+                (DELEGATE, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "transfer_with_delegation",
+            instruction::transfer(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &DESTINATION,
+                &DELEGATION,
+                &[&DELEGATE],
+                100,
+            )
+            .unwrap(),
+            vec![
+                (
+                    SOURCE,
+                    program_account(&TokenAccount {
+                        delegate: COption::Some(DELEGATION),
+                        delegated_amount: 500,
+                        ..token_account(&OWNER, 1_000)
+                    }),
+                ),
+                (DESTINATION, program_account(&token_account(&OWNER, 0))),
+                (
+                    DELEGATION,
+                    program_account(&Delegation {
+                        is_initialized: true,
+                        account: SOURCE,
+                        delegate: DELEGATE,
+                        expiry: Expiry::Slot(u64::MAX),
+                    }),
+                ),
+                (DELEGATE, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "close_delegation",
+            instruction::close_delegation(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &DELEGATION,
+                &DESTINATION,
+                &OWNER,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                (
+                    SOURCE,
+                    program_account(&TokenAccount {
+                        delegate: COption::Some(DELEGATION),
+                        delegated_amount: 500,
+                        ..token_account(&OWNER, 1_000)
+                    }),
+                ),
+                (
+                    DELEGATION,
+                    program_account(&Delegation {
+                        is_initialized: true,
+                        account: SOURCE,
+                        delegate: DELEGATE,
+                        expiry: Expiry::Slot(u64::MAX),
+                    }),
+                ),
+                (DESTINATION, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "approve_with_spending_limit",
+            instruction::approve_with_spending_limit(
+                &TOKEN_PROGRAM_ID,
+                &SOURCE,
+                &SPENDING_LIMIT,
+                &DELEGATE,
+                &OWNER,
+                &[],
+                500,
+            )
+            .unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (SPENDING_LIMIT, uninitialized_account(SpendingLimit::LEN)),
+                (DELEGATE, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "transfer_with_spending_limit",
+            {
+                let mut instruction = instruction::transfer(
+                    &TOKEN_PROGRAM_ID,
+                    &SOURCE,
+                    &DESTINATION,
+                    &SPENDING_LIMIT,
+                    &[&DELEGATE],
+                    100,
+                )
+                .unwrap();
+                // The spending limit is updated by the transfer.
+                instruction.accounts[2].is_writable = true;
+                instruction
+            },
+            vec![
+                (
+                    SOURCE,
+                    program_account(&TokenAccount {
+                        delegate: COption::Some(SPENDING_LIMIT),
+                        delegated_amount: 500,
+                        ..token_account(&OWNER, 1_000)
+                    }),
+                ),
+                (DESTINATION, program_account(&token_account(&OWNER, 0))),
+                (
+                    SPENDING_LIMIT,
+                    program_account(&SpendingLimit {
+                        is_initialized: true,
+                        account: SOURCE,
+                        delegate: DELEGATE,
+                        limit: 500,
+                        epoch: 0,
+                        spent: 0,
+                    }),
+                ),
+                (DELEGATE, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "set_memo_required",
+            instruction::set_memo_required(&TOKEN_PROGRAM_ID, &DESTINATION, &OWNER, &[], true)
+                .unwrap(),
+            vec![
+                (DESTINATION, program_account(&token_account(&OWNER, 0))),
+                (OWNER, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "pause_mint",
+            instruction::pause_mint(&TOKEN_PROGRAM_ID, &MINT, &MINT_PAUSE, &AUTHORITY, &[])
+                .unwrap(),
+            vec![
+                (MINT, mint(1_000, COption::Some(AUTHORITY))),
+                (MINT_PAUSE, uninitialized_account(MintPause::LEN)),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "resume_mint",
+            instruction::resume_mint(
+                &TOKEN_PROGRAM_ID,
+                &MINT,
+                &MINT_PAUSE,
+                &DESTINATION,
+                &AUTHORITY,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                (
+                    MINT,
+                    mint(1_000, COption::Some(PAUSED_MINT_FREEZE_AUTHORITY)),
+                ),
+                (
+                    MINT_PAUSE,
+                    program_account(&MintPause {
+                        is_initialized: true,
+                        mint: MINT,
+                        freeze_authority: AUTHORITY,
+                    }),
+                ),
+                (DESTINATION, system_account()),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        // Batch compositions.
+        Benchmark::new(
+            "batch_transfer",
+            instruction::batch(vec![transfer.clone()]).unwrap(),
+            transfer_accounts.clone(),
+        ),
+        Benchmark::new(
+            "batch_transfer_x2",
+            instruction::batch(vec![transfer.clone(), transfer.clone()]).unwrap(),
+            transfer_accounts.clone(),
+        ),
+        Benchmark::new(
+            "batch_transfer_x8",
+            instruction::batch(vec![transfer.clone(); 8]).unwrap(),
+            transfer_accounts,
+        ),
+        Benchmark::new(
+            "batch_transfer_checked_x2",
+            instruction::batch(vec![transfer_checked.clone(), transfer_checked]).unwrap(),
+            transfer_checked_accounts,
+        ),
+        Benchmark::new(
+            "batch_initialize_account3_mint_to",
+            instruction::batch(vec![
+                instruction::initialize_account3(&TOKEN_PROGRAM_ID, &DESTINATION, &MINT, &OWNER)
+                    .unwrap(),
+                mint_to,
+            ])
+            .unwrap(),
+            vec![
+                (DESTINATION, uninitialized_account(TokenAccount::LEN)),
+                (MINT, mint(0, COption::None)),
+                (AUTHORITY, system_account()),
+            ],
+        ),
+        Benchmark::new(
+            "batch_burn_close_account",
+            instruction::batch(vec![burn, close_account]).unwrap(),
+            vec![
+                (SOURCE, program_account(&token_account(&OWNER, 1_000))),
+                (MINT, mint(1_000, COption::None)),
+                (DESTINATION, system_account()),
+                (OWNER, system_account()),
+            ],
+        ),
+    ];
+
+    let generic = benchmarks
+        .iter()
+        .filter(|benchmark| FAST_PATHS.contains(&benchmark.name.as_str()))
+        .map(Benchmark::generic)
+        .collect::<Vec<_>>();
+    benchmarks.extend(generic);
+
+    benchmarks
+}
+
+/// Executes the benchmarks, mapping their names to the consumed compute
+/// units.
+fn measure() -> BTreeMap<String, u64> {
+    let mollusk = mollusk();
+
+    benchmarks()
+        .into_iter()
+        .map(|benchmark| {
+            let result = mollusk.process_instruction(&benchmark.instruction, &benchmark.accounts);
+            assert_eq!(
+                result.program_result,
+                ProgramResult::Success,
+                "benchmark {} failed",
+                benchmark.name
+            );
+            (benchmark.name, result.compute_units_consumed)
+        })
+        .collect()
+}
+
+/// Reads the baseline file, mapping benchmark names to compute units.
+fn read_baseline() -> BTreeMap<String, u64> {
+    fs::read_to_string(BASELINE)
+        .map(|contents| serde_json::from_str(&contents).expect("valid baseline file"))
+        .unwrap_or_default()
+}
+
+/// Writes the baseline file, one benchmark per line sorted by name.
+fn write_baseline(measured: &BTreeMap<String, u64>) {
+    let entries = measured
+        .iter()
+        .map(|(name, units)| format!("  \"{name}\": {units}"))
+        .collect::<Vec<_>>();
+    fs::write(BASELINE, format!("{{\n{}\n}}\n", entries.join(",\n")))
+        .expect("baseline file written");
+}
+
+#[test]
+#[ignore = "requires a baseline recorded with `pnpm p-token:cu:update`"]
+fn compute_units() {
+    let tolerance = std::env::var("CU_TOLERANCE")
+        .map(|value| value.parse::<f64>().expect("valid CU_TOLERANCE"))
+        .unwrap_or(DEFAULT_TOLERANCE);
+    let measured = measure();
+
+    if std::env::var("UPDATE_CU_BASELINE").is_ok() {
+        write_baseline(&measured);
+        println!("Baseline updated: {BASELINE}");
+        return;
+    }
+
+    let baseline = read_baseline();
+    let mut regressions = Vec::new();
+    let mut outdated = Vec::new();
+
+    println!(
+        "{:<36} {:>10} {:>10} {:>8} {:>9}  status",
+        "instruction", "baseline", "measured", "delta", "delta %"
+    );
+
+    for (name, units) in &measured {
+        let Some(expected) = baseline.get(name) else {
+            println!(
+                "{name:<36} {:>10} {units:>10} {:>8} {:>9}  MISSING",
+                "-", "-", "-"
+            );
+            outdated.push(name.as_str());
+            continue;
+        };
+
+        let delta = *units as i64 - *expected as i64;
+        let percent = delta as f64 * 100.0 / *expected as f64;
+        let status = if percent > tolerance {
+            regressions.push(name.as_str());
+            "REGRESSION"
+        } else if delta < 0 {
+            "improved"
+        } else {
+            "ok"
+        };
+
+        println!("{name:<36} {expected:>10} {units:>10} {delta:>+8} {percent:>+8.2}%  {status}");
+    }
+
+    for (name, expected) in baseline
+        .iter()
+        .filter(|(name, _)| !measured.contains_key(*name))
+    {
+        println!(
+            "{name:<36} {expected:>10} {:>10} {:>8} {:>9}  REMOVED",
+            "-", "-", "-"
+        );
+        outdated.push(name.as_str());
+    }
+
+    assert!(
+        outdated.is_empty(),
+        "the baseline is out of date for: {}; run the test with UPDATE_CU_BASELINE=1 to \
+         update it",
+        outdated.join(", ")
+    );
+
+    assert!(
+        regressions.is_empty(),
+        "compute units regressed beyond {tolerance}% for: {}",
+        regressions.join(", ")
+    );
+}
+
+#[test]
+fn fast_paths_consume_fewer_compute_units() {
+    let measured = measure();
+
+    println!(
+        "{:<36} {:>10} {:>10} {:>8}",
+        "instruction", "generic", "fast path", "saved"
+    );
+
+    for name in FAST_PATHS {
+        let fast = measured[name];
+        let generic = measured[&format!("{name}_generic")];
+
+        println!(
+            "{name:<36} {generic:>10} {fast:>10} {:>8}",
+            generic as i64 - fast as i64
+        );
+        assert!(
+            fast < generic,
+            "{name}: fast path consumed {fast} CUs, generic path {generic} CUs"
+        );
+    }
+}
@@ -0,0 +1,112 @@
+//! Replays the fuzz fixtures under `target/fixtures` against p-token.
+//!
+//! Fixtures are generated from the SPL Token tests, either with
+//! `pnpm fixtures:generate` or directly with cargo:
+//!
+//! ```sh
+//! EJECT_FUZZ_FIXTURES=target/fixtures cargo test-sbf \
+//!     --manifest-path program/Cargo.toml --features mollusk-svm/fuzz
+//! ```
+//!
+//! Each fixture is expected to produce the same program result, return data
+//! and resulting accounts; compute units are reported but not compared. The
+//! fixtures directory can be overridden with `FIXTURES_DIR`.
+//!
+//! The replay is ignored by default since it needs the generated fixtures;
+//! run it with `pnpm fixtures:replay`. It fails if no fixture is found.
+
+mod setup;
+
+use {
+    mollusk_svm::result::{Compare, Config, InstructionResult},
+    mollusk_svm_fuzz_fixture::Fixture,
+    setup::mollusk::mollusk,
+    std::{
+        fs,
+        path::{Path, PathBuf},
+    },
+};
+
+const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../../target/fixtures");
+
+/// Collects the fixture files (`*.fix`) under `dir`, sorted by path.
+fn fixture_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = fs::read_dir(dir) else {
+        return files;
+    };
+
+    for path in entries.filter_map(|entry| entry.ok().map(|entry| entry.path())) {
+        if path.is_dir() {
+            files.extend(fixture_files(&path));
+        } else if path.extension().is_some_and(|extension| extension == "fix") {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    files
+}
+
+#[test]
+#[ignore = "requires fixtures generated with `pnpm fixtures:generate`"]
+fn replay_fixtures() {
+    let dir = std::env::var("FIXTURES_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(FIXTURES_DIR));
+    let files = fixture_files(&dir);
+
+    assert!(!files.is_empty(), "no fixtures found in {}", dir.display());
+
+    let mut mollusk = mollusk();
+    let config = Config {
+        panic: false,
+        verbose: true,
+    };
+    let mut failures = Vec::new();
+    let mut total_delta = 0i64;
+
+    println!(
+        "{:<6} {:<60} {:>10} {:>10} {:>8}",
+        "status", "fixture", "expected", "p-token", "delta"
+    );
+
+    for file in &files {
+        let fixture = Fixture::load_from_blob_file(&file.to_string_lossy());
+        let expected = InstructionResult::from(&fixture.output);
+        let result = mollusk.process_fixture(&fixture);
+
+        let passed = expected.compare_with_config(&result, &Compare::everything_but_cus(), &config);
+        let delta = result.compute_units_consumed as i64 - expected.compute_units_consumed as i64;
+        total_delta += delta;
+
+        let name = file.strip_prefix(&dir).unwrap_or(file).display();
+        println!(
+            "{:<6} {:<60} {:>10} {:>10} {:>+8}",
+            if passed { "ok" } else { "FAILED" },
+            name,
+            expected.compute_units_consumed,
+            result.compute_units_consumed,
+            delta
+        );
+
+        if !passed {
+            failures.push(name.to_string());
+        }
+    }
+
+    println!(
+        "{} fixtures: {} passed, {} failed, total CU delta {:+}",
+        files.len(),
+        files.len() - failures.len(),
+        failures.len(),
+        total_delta
+    );
+
+    assert!(
+        failures.is_empty(),
+        "{} fixture(s) failed:\n{}",
+        failures.len(),
+        failures.join("\n")
+    );
+}
@@ -0,0 +1,483 @@
+mod setup;
+
+use {
+    crate::setup::{
+        mollusk::{create_mint_account, mollusk},
+        TOKEN_PROGRAM_ID,
+    },
+    mollusk_svm::result::Check,
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::Account as TokenAccount,
+            account_state::AccountState,
+            load_mut_unchecked,
+            mint::{Mint, PAUSED_FREEZE_AUTHORITY},
+            mint_pause::MintPause,
+            Transmutable,
+        },
+    },
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    spl_token_interface::{instruction::AuthorityType, state::PAUSED_MINT_FREEZE_AUTHORITY},
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let space = size_of::<TokenAccount>();
+    let lamports = Rent::default().minimum_balance(space);
+
+    let mut data: Vec<u8> = vec![0u8; space];
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(data.as_mut_slice()).unwrap() };
+    token.set_account_state(AccountState::Initialized);
+    token.mint = *mint.as_array();
+    token.owner = *owner.as_array();
+    token.set_amount(amount);
+
+    Account {
+        lamports,
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn create_paused_mint_account(mint_authority: Pubkey) -> Account {
+    let mut mint_account = create_mint_account(
+        mint_authority,
+        Some(Pubkey::new_from_array(PAUSED_FREEZE_AUTHORITY)),
+        2,
+        &TOKEN_PROGRAM_ID,
+    );
+    let mint = unsafe { load_mut_unchecked::<Mint>(&mut mint_account.data).unwrap() };
+    mint.set_supply(1_000);
+    mint_account
+}
+
+fn create_mint_pause_account(mint: &Pubkey, freeze_authority: &Pubkey) -> Account {
+    let mut data = vec![0u8; MintPause::LEN];
+    let mint_pause = unsafe { load_mut_unchecked::<MintPause>(data.as_mut_slice()).unwrap() };
+
+    mint_pause.set_initialized(true);
+    mint_pause.mint = *mint.as_array();
+    mint_pause.freeze_authority = *freeze_authority.as_array();
+
+    Account {
+        lamports: Rent::default().minimum_balance(MintPause::LEN),
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn pause_mint() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let freeze_authority_key = Pubkey::new_unique();
+
+    let mint_account = create_mint_account(
+        mint_authority_key,
+        Some(freeze_authority_key),
+        2,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    // uninitialized mint pause account
+    let mint_pause_key = Pubkey::new_unique();
+    let mint_pause_account = Account {
+        lamports: Rent::default().minimum_balance(MintPause::LEN),
+        data: vec![0u8; MintPause::LEN],
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    };
+
+    let instruction = spl_token_interface::instruction::pause_mint(
+        &TOKEN_PROGRAM_ID,
+        &mint_key,
+        &mint_pause_key,
+        &freeze_authority_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed to pause the mint with the freeze authority.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (mint_key, mint_account),
+            (mint_pause_key, mint_pause_account),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    // The paused mint is still a valid mint, with the paused freeze
+    // authority in place of the freeze authority.
+
+    let account = result.get_account(&mint_key).unwrap();
+    assert!(spl_token_interface::state::is_paused_mint(&account.data));
+
+    let mint = spl_token_interface::state::Mint::unpack(&account.data).unwrap();
+    assert!(mint.is_paused());
+    assert_eq!(
+        mint.freeze_authority,
+        Some(PAUSED_MINT_FREEZE_AUTHORITY).into()
+    );
+
+    // And the mint pause account records the freeze authority.
+
+    let account = result.get_account(&mint_pause_key).unwrap();
+    let mint_pause = spl_token_interface::state::MintPause::unpack(&account.data).unwrap();
+    assert_eq!(mint_pause.mint, mint_key);
+    assert_eq!(mint_pause.freeze_authority, freeze_authority_key);
+}
+
+#[test]
+fn fail_pause_mint_without_freeze_authority() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+
+    let mint_account = create_mint_account(mint_authority_key, None, 2, &TOKEN_PROGRAM_ID);
+
+    let mint_pause_key = Pubkey::new_unique();
+    let mint_pause_account = Account {
+        lamports: Rent::default().minimum_balance(MintPause::LEN),
+        data: vec![0u8; MintPause::LEN],
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    };
+
+    let instruction = spl_token_interface::instruction::pause_mint(
+        &TOKEN_PROGRAM_ID,
+        &mint_key,
+        &mint_pause_key,
+        &mint_authority_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (mint_key, mint_account),
+            (mint_pause_key, mint_pause_account),
+            (mint_authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintCannotFreeze as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_pause_mint_with_initialized_mint_pause() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let freeze_authority_key = Pubkey::new_unique();
+
+    let mint_account = create_mint_account(
+        mint_authority_key,
+        Some(freeze_authority_key),
+        2,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    let mint_pause_key = Pubkey::new_unique();
+    let mint_pause_account = create_mint_pause_account(&mint_key, &Pubkey::new_unique());
+
+    let instruction = spl_token_interface::instruction::pause_mint(
+        &TOKEN_PROGRAM_ID,
+        &mint_key,
+        &mint_pause_key,
+        &freeze_authority_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (mint_key, mint_account),
+            (mint_pause_key, mint_pause_account),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::AlreadyInUse as u32,
+        ))],
+    );
+}
+
+#[test]
+fn resume_mint() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let freeze_authority_key = Pubkey::new_unique();
+
+    let mint_account = create_paused_mint_account(mint_authority_key);
+
+    let mint_pause_key = Pubkey::new_unique();
+    let mint_pause_account = create_mint_pause_account(&mint_key, &freeze_authority_key);
+    let mint_pause_lamports = mint_pause_account.lamports;
+
+    let destination_key = Pubkey::new_unique();
+
+    let instruction = spl_token_interface::instruction::resume_mint(
+        &TOKEN_PROGRAM_ID,
+        &mint_key,
+        &mint_pause_key,
+        &destination_key,
+        &freeze_authority_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed to resume the mint with the recorded freeze
+    // authority.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (mint_key, mint_account),
+            (mint_pause_key, mint_pause_account),
+            (destination_key, Account::default()),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint_pause_key).closed().build(),
+            Check::account(&destination_key)
+                .lamports(mint_pause_lamports)
+                .build(),
+        ],
+    );
+
+    // The freeze authority of the mint is restored.
+
+    let account = result.get_account(&mint_key).unwrap();
+    assert!(!spl_token_interface::state::is_paused_mint(&account.data));
+
+    let mint = spl_token_interface::state::Mint::unpack(&account.data).unwrap();
+    assert_eq!(mint.freeze_authority, Some(freeze_authority_key).into());
+}
+
+#[test]
+fn fail_resume_mint_with_other_mint_pause() {
+    let mint_key = Pubkey::new_unique();
+    let freeze_authority_key = Pubkey::new_unique();
+
+    let mint_account = create_paused_mint_account(Pubkey::new_unique());
+
+    // mint pause account of another mint with the same freeze authority
+    let mint_pause_key = Pubkey::new_unique();
+    let mint_pause_account =
+        create_mint_pause_account(&Pubkey::new_unique(), &freeze_authority_key);
+
+    let destination_key = Pubkey::new_unique();
+
+    let instruction = spl_token_interface::instruction::resume_mint(
+        &TOKEN_PROGRAM_ID,
+        &mint_key,
+        &mint_pause_key,
+        &destination_key,
+        &freeze_authority_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (mint_key, mint_account),
+            (mint_pause_key, mint_pause_account),
+            (destination_key, Account::default()),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_set_freeze_authority_to_paused_freeze_authority() {
+    let mint_key = Pubkey::new_unique();
+    let freeze_authority_key = Pubkey::new_unique();
+
+    let mint_account = create_mint_account(
+        Pubkey::new_unique(),
+        Some(freeze_authority_key),
+        2,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    let instruction = spl_token_interface::instruction::set_authority(
+        &TOKEN_PROGRAM_ID,
+        &mint_key,
+        Some(&PAUSED_MINT_FREEZE_AUTHORITY),
+        AuthorityType::FreezeAccount,
+        &freeze_authority_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should fail to pause the mint without a mint pause account.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (mint_key, mint_account),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::InvalidArgument)],
+    );
+}
+
+#[test]
+fn fail_transfer_checked_paused_mint() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let mint_account = create_paused_mint_account(owner_key);
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint_key, &owner_key, 1_000);
+
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account = create_token_account(&mint_key, &owner_key, 0);
+
+    let instruction = spl_token_interface::instruction::transfer_checked(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &mint_key,
+        &destination_account_key,
+        &owner_key,
+        &[],
+        100,
+        2,
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (mint_key, mint_account),
+            (destination_account_key, destination_account),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintPaused as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_mint_to_paused_mint() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+
+    let mint_account = create_paused_mint_account(mint_authority_key);
+
+    let account_key = Pubkey::new_unique();
+    let account = create_token_account(&mint_key, &Pubkey::new_unique(), 0);
+
+    let instruction = spl_token_interface::instruction::mint_to(
+        &TOKEN_PROGRAM_ID,
+        &mint_key,
+        &account_key,
+        &mint_authority_key,
+        &[],
+        100,
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (mint_key, mint_account),
+            (account_key, account),
+            (mint_authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintPaused as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_burn_paused_mint() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let mint_account = create_paused_mint_account(Pubkey::new_unique());
+
+    let account_key = Pubkey::new_unique();
+    let account = create_token_account(&mint_key, &owner_key, 1_000);
+
+    let instruction = spl_token_interface::instruction::burn(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &mint_key,
+        &owner_key,
+        &[],
+        100,
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (account_key, account),
+            (mint_key, mint_account),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintPaused as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_approve_checked_paused_mint() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+
+    let mint_account = create_paused_mint_account(Pubkey::new_unique());
+
+    let account_key = Pubkey::new_unique();
+    let account = create_token_account(&mint_key, &owner_key, 1_000);
+
+    let instruction = spl_token_interface::instruction::approve_checked(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &mint_key,
+        &delegate_key,
+        &owner_key,
+        &[],
+        100,
+        2,
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (account_key, account),
+            (mint_key, mint_account),
+            (delegate_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintPaused as u32,
+        ))],
+    );
+}
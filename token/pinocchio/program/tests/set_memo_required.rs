@@ -0,0 +1,434 @@
+mod setup;
+
+use {
+    crate::setup::{mollusk::mollusk, TOKEN_PROGRAM_ID},
+    mollusk_svm::result::Check,
+    pinocchio_token_interface::{
+        error::TokenError,
+        state::{
+            account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
+        },
+    },
+    solana_account::Account,
+    solana_instruction::{AccountMeta, BorrowedAccountMeta, BorrowedInstruction, Instruction},
+    solana_instructions_sysvar::construct_instructions_data,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    let space = size_of::<TokenAccount>();
+    let lamports = Rent::default().minimum_balance(space);
+
+    let mut data: Vec<u8> = vec![0u8; space];
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(data.as_mut_slice()).unwrap() };
+    token.set_account_state(AccountState::Initialized);
+    token.mint = *mint.as_array();
+    token.owner = *owner.as_array();
+    token.set_amount(amount);
+
+    Account {
+        lamports,
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+fn create_instructions_sysvar(instructions: &[&Instruction], current_index: u16) -> Account {
+    let instructions = instructions
+        .iter()
+        .map(|instruction| BorrowedInstruction {
+            program_id: &instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| BorrowedAccountMeta {
+                    pubkey: &meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: &instruction.data,
+        })
+        .collect::<Vec<_>>();
+
+    let mut data = construct_instructions_data(&instructions);
+    let len = data.len();
+    data[len - 2..].copy_from_slice(&current_index.to_le_bytes());
+
+    Account {
+        lamports: 1,
+        data,
+        owner: solana_sdk_ids::sysvar::ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn set_memo_required() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let account_key = Pubkey::new_unique();
+    let account = create_token_account(&mint, &owner_key, 0);
+
+    let instruction = spl_token_interface::instruction::set_memo_required(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &owner_key,
+        &[],
+        true,
+    )
+    .unwrap();
+
+    // It should succeed to require memos on incoming transfers.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[(account_key, account), (owner_key, Account::default())],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    assert!(spl_token_interface::state::is_memo_required_account(
+        &account.data
+    ));
+
+    // The flag is not visible in the unpacked account.
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert!(!token_account.is_native());
+}
+
+#[test]
+fn fail_set_memo_required_native_account() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let account_key = Pubkey::new_unique();
+    let mut account = create_token_account(&mint, &owner_key, 0);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut account.data).unwrap() };
+    token.set_native(true);
+    token.set_native_amount(1_000);
+
+    let instruction = spl_token_interface::instruction::set_memo_required(
+        &TOKEN_PROGRAM_ID,
+        &account_key,
+        &owner_key,
+        &[],
+        true,
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[(account_key, account), (owner_key, Account::default())],
+        &[Check::err(ProgramError::Custom(
+            TokenError::NativeNotSupported as u32,
+        ))],
+    );
+}
+
+#[test]
+fn transfer_requires_memo() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    let destination_account_key = Pubkey::new_unique();
+    let mut destination_account = create_token_account(&mint, &owner_key, 0);
+    let token =
+        unsafe { load_mut_unchecked::<TokenAccount>(&mut destination_account.data).unwrap() };
+    token.set_memo_required(true);
+
+    let mut transfer = spl_token_interface::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &owner_key,
+        &[],
+        100,
+    )
+    .unwrap();
+
+    let mollusk = mollusk();
+
+    // It should fail to transfer without the Instructions sysvar.
+
+    mollusk.process_and_validate_instruction(
+        &transfer,
+        &[
+            (source_account_key, source_account.clone()),
+            (destination_account_key, destination_account.clone()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::NotEnoughAccountKeys)],
+    );
+
+    transfer.accounts.push(AccountMeta::new_readonly(
+        solana_instructions_sysvar::ID,
+        false,
+    ));
+
+    // It should fail to transfer when the previous instruction is not a memo.
+
+    let other = Instruction::new_with_bytes(Pubkey::new_unique(), b"memo", vec![]);
+
+    mollusk.process_and_validate_instruction(
+        &transfer,
+        &[
+            (source_account_key, source_account.clone()),
+            (destination_account_key, destination_account.clone()),
+            (owner_key, Account::default()),
+            (
+                solana_instructions_sysvar::ID,
+                create_instructions_sysvar(&[&other, &transfer], 1),
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(TokenError::NoMemo as u32))],
+    );
+
+    // It should succeed to transfer when the previous instruction is a memo.
+
+    let memo = Instruction::new_with_bytes(
+        pinocchio_token_interface::memo::ID.into(),
+        b"deposit",
+        vec![],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &transfer,
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (owner_key, Account::default()),
+            (
+                solana_instructions_sysvar::ID,
+                create_instructions_sysvar(&[&memo, &transfer], 1),
+            ),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&destination_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 100);
+}
+
+#[test]
+fn fail_transfer_in_batch_with_memo() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    let destination_account_key = Pubkey::new_unique();
+    let mut destination_account = create_token_account(&mint, &owner_key, 0);
+    let token =
+        unsafe { load_mut_unchecked::<TokenAccount>(&mut destination_account.data).unwrap() };
+    token.set_memo_required(true);
+
+    let mut transfer = spl_token_interface::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &owner_key,
+        &[],
+        100,
+    )
+    .unwrap();
+    transfer.accounts.push(AccountMeta::new_readonly(
+        solana_instructions_sysvar::ID,
+        false,
+    ));
+
+    let memo = Instruction::new_with_bytes(
+        pinocchio_token_interface::memo::ID.into(),
+        b"deposit",
+        vec![],
+    );
+
+    // It should fail to transfer as part of a batch, even when the batch is
+    // preceded by a memo, since the memo cannot be attributed to a single
+    // transfer.
+
+    let batch = spl_token_interface::instruction::batch(vec![transfer]).unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &batch,
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (owner_key, Account::default()),
+            (
+                solana_instructions_sysvar::ID,
+                create_instructions_sysvar(&[&memo, &batch], 1),
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(TokenError::NoMemo as u32))],
+    );
+}
+
+#[test]
+fn transfer_through_cpi_requires_memo() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    let destination_account_key = Pubkey::new_unique();
+    let mut destination_account = create_token_account(&mint, &owner_key, 0);
+    let token =
+        unsafe { load_mut_unchecked::<TokenAccount>(&mut destination_account.data).unwrap() };
+    token.set_memo_required(true);
+
+    let mut transfer = spl_token_interface::instruction::transfer(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &owner_key,
+        &[],
+        100,
+    )
+    .unwrap();
+    transfer.accounts.push(AccountMeta::new_readonly(
+        solana_instructions_sysvar::ID,
+        false,
+    ));
+
+    // The transfer is invoked by the top-level instruction of another program.
+    let caller = Instruction::new_with_bytes(Pubkey::new_unique(), b"deposit", vec![]);
+
+    let mollusk = mollusk();
+
+    // It should fail to transfer when the calling instruction is not preceded
+    // by a memo.
+
+    let other = Instruction::new_with_bytes(Pubkey::new_unique(), b"memo", vec![]);
+
+    mollusk.process_and_validate_instruction(
+        &transfer,
+        &[
+            (source_account_key, source_account.clone()),
+            (destination_account_key, destination_account.clone()),
+            (owner_key, Account::default()),
+            (
+                solana_instructions_sysvar::ID,
+                create_instructions_sysvar(&[&other, &caller], 1),
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(TokenError::NoMemo as u32))],
+    );
+
+    // It should succeed to transfer when the calling instruction is preceded
+    // by a memo.
+
+    let memo = Instruction::new_with_bytes(
+        pinocchio_token_interface::memo::ID.into(),
+        b"deposit",
+        vec![],
+    );
+
+    let result = mollusk.process_and_validate_instruction(
+        &transfer,
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (owner_key, Account::default()),
+            (
+                solana_instructions_sysvar::ID,
+                create_instructions_sysvar(&[&memo, &caller], 1),
+            ),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&destination_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 100);
+}
+
+#[test]
+fn transfer_many_requires_memo_per_transfer() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, 1_000);
+
+    let destination_keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut destination_account = create_token_account(&mint, &owner_key, 0);
+    let token =
+        unsafe { load_mut_unchecked::<TokenAccount>(&mut destination_account.data).unwrap() };
+    token.set_memo_required(true);
+
+    let mut transfer_many = spl_token_interface::instruction::transfer_many(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &[(&destination_keys[0], 100), (&destination_keys[1], 200)],
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+    transfer_many.accounts.push(AccountMeta::new_readonly(
+        solana_instructions_sysvar::ID,
+        false,
+    ));
+
+    let memo = Instruction::new_with_bytes(
+        pinocchio_token_interface::memo::ID.into(),
+        b"deposit",
+        vec![],
+    );
+
+    let mollusk = mollusk();
+
+    // It should fail to transfer into two accounts that require memos with a
+    // single memo.
+
+    mollusk.process_and_validate_instruction(
+        &transfer_many,
+        &[
+            (source_account_key, source_account.clone()),
+            (destination_keys[0], destination_account.clone()),
+            (destination_keys[1], destination_account.clone()),
+            (owner_key, Account::default()),
+            (
+                solana_instructions_sysvar::ID,
+                create_instructions_sysvar(&[&memo, &transfer_many], 1),
+            ),
+        ],
+        &[Check::err(ProgramError::Custom(TokenError::NoMemo as u32))],
+    );
+
+    // It should succeed to transfer when each transfer has its own memo.
+
+    let result = mollusk.process_and_validate_instruction(
+        &transfer_many,
+        &[
+            (source_account_key, source_account),
+            (destination_keys[0], destination_account.clone()),
+            (destination_keys[1], destination_account),
+            (owner_key, Account::default()),
+            (
+                solana_instructions_sysvar::ID,
+                create_instructions_sysvar(&[&memo, &memo, &transfer_many], 2),
+            ),
+        ],
+        &[Check::success()],
+    );
+
+    for (key, amount) in destination_keys.iter().zip([100, 200]) {
+        let account = result.get_account(key).unwrap();
+        let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+        assert_eq!(token_account.amount, amount);
+    }
+}
@@ -0,0 +1,231 @@
+mod setup;
+
+use {
+    crate::setup::{mollusk::mollusk, TOKEN_PROGRAM_ID},
+    mollusk_svm::result::Check,
+    pinocchio_token_interface::{
+        error::TokenError,
+        native_mint,
+        state::{
+            account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
+        },
+    },
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::system_program,
+};
+
+fn create_token_account(mint: &Pubkey, owner: &Pubkey, is_native: bool, amount: u64) -> Account {
+    let space = size_of::<TokenAccount>();
+    let mut lamports = Rent::default().minimum_balance(space);
+
+    let mut data: Vec<u8> = vec![0u8; space];
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(data.as_mut_slice()).unwrap() };
+    token.set_account_state(AccountState::Initialized);
+    token.mint = *mint.as_array();
+    token.owner = *owner.as_array();
+    token.set_amount(amount);
+    token.set_native(is_native);
+
+    if is_native {
+        token.set_native_amount(lamports);
+        lamports = lamports.saturating_add(amount);
+    }
+
+    Account {
+        lamports,
+        data,
+        owner: TOKEN_PROGRAM_ID,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn sweep_and_close() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let recipient_key = Pubkey::new_unique();
+
+    // source account:
+    //   - amount: 1_000
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, false, 1_000);
+    let source_lamports = source_account.lamports;
+
+    // destination account:
+    //   - amount: 10
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account = create_token_account(&mint, &owner_key, false, 10);
+
+    let instruction = spl_token_interface::instruction::sweep_and_close(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &recipient_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed to move the balance and close the source account.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (recipient_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&source_account_key)
+                .lamports(0)
+                .data(&[])
+                .owner(&system_program::id())
+                .build(),
+            Check::account(&recipient_key)
+                .lamports(source_lamports)
+                .build(),
+        ],
+    );
+
+    let account = result.get_account(&destination_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 1_010);
+}
+
+#[test]
+fn sweep_and_close_native() {
+    let native_mint = Pubkey::new_from_array(native_mint::ID);
+    let owner_key = Pubkey::new_unique();
+    let recipient_key = Pubkey::new_unique();
+
+    // native account:
+    //   - amount: 2_000_000_000
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&native_mint, &owner_key, true, 2_000_000_000);
+    let rent_lamports = source_account.lamports - 2_000_000_000;
+
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account = create_token_account(&native_mint, &owner_key, true, 0);
+    let destination_lamports = destination_account.lamports;
+
+    let instruction = spl_token_interface::instruction::sweep_and_close(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &recipient_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should move the wrapped lamports to the destination and the rent
+    // lamports to the recipient.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (recipient_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&source_account_key).lamports(0).build(),
+            Check::account(&destination_account_key)
+                .lamports(destination_lamports + 2_000_000_000)
+                .build(),
+            Check::account(&recipient_key)
+                .lamports(rent_lamports)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn fail_sweep_and_close_with_close_authority() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let recipient_key = Pubkey::new_unique();
+
+    // source account:
+    //   - close authority: different than the owner
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account = create_token_account(&mint, &owner_key, false, 1_000);
+    unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() }
+        .set_close_authority(Pubkey::new_unique().as_array());
+
+    let destination_account_key = Pubkey::new_unique();
+    let destination_account = create_token_account(&mint, &owner_key, false, 0);
+
+    let instruction = spl_token_interface::instruction::sweep_and_close(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &recipient_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should fail since the owner can transfer the tokens but cannot close
+    // the account.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (recipient_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::OwnerMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_sweep_and_close_with_frozen_destination() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let recipient_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(&mint, &owner_key, false, 1_000);
+
+    let destination_account_key = Pubkey::new_unique();
+    let mut destination_account = create_token_account(&mint, &owner_key, false, 0);
+    unsafe { load_mut_unchecked::<TokenAccount>(&mut destination_account.data).unwrap() }
+        .set_account_state(AccountState::Frozen);
+
+    let instruction = spl_token_interface::instruction::sweep_and_close(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &destination_account_key,
+        &recipient_key,
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_account_key, destination_account),
+            (recipient_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::AccountFrozen as u32,
+        ))],
+    );
+}
@@ -0,0 +1,336 @@
+mod setup;
+
+use {
+    crate::setup::TOKEN_PROGRAM_ID,
+    mollusk_svm::{result::Check, Mollusk},
+    pinocchio_token_interface::{
+        error::TokenError,
+        native_mint,
+        state::{
+            account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
+        },
+    },
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::bpf_loader_upgradeable,
+};
+
+fn create_token_account(
+    mint: &Pubkey,
+    owner: &Pubkey,
+    is_native: bool,
+    amount: u64,
+    program_owner: &Pubkey,
+) -> Account {
+    let space = size_of::<TokenAccount>();
+    let mut lamports = Rent::default().minimum_balance(space);
+
+    let mut data: Vec<u8> = vec![0u8; space];
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(data.as_mut_slice()).unwrap() };
+    token.set_account_state(AccountState::Initialized);
+    token.mint = *mint.as_array();
+    token.owner = *owner.as_array();
+    token.set_amount(amount);
+    token.set_native(is_native);
+
+    if is_native {
+        token.set_native_amount(lamports);
+        lamports = lamports.saturating_add(amount);
+    }
+
+    Account {
+        lamports,
+        data,
+        owner: *program_owner,
+        executable: false,
+        ..Default::default()
+    }
+}
+
+/// Creates a Mollusk instance with the default feature set.
+fn mollusk() -> Mollusk {
+    let mut mollusk = Mollusk::default();
+    mollusk.add_program(
+        &TOKEN_PROGRAM_ID,
+        "pinocchio_token_program",
+        &bpf_loader_upgradeable::id(),
+    );
+    mollusk
+}
+
+/// Returns the token amount of `key` in the resulting accounts.
+fn amount(result: &mollusk_svm::result::InstructionResult, key: &Pubkey) -> u64 {
+    let account = result.get_account(key).unwrap();
+    spl_token_interface::state::Account::unpack(&account.data)
+        .unwrap()
+        .amount
+}
+
+#[test]
+fn transfer_many() {
+    let mint = Pubkey::new_unique();
+    let authority_key = Pubkey::new_unique();
+
+    // source account:
+    //   - amount: 1_000
+    let source_account_key = Pubkey::new_unique();
+    let source_account =
+        create_token_account(&mint, &authority_key, false, 1_000, &TOKEN_PROGRAM_ID);
+
+    let destination_a_key = Pubkey::new_unique();
+    let destination_a = create_token_account(&mint, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
+    let destination_b_key = Pubkey::new_unique();
+    let destination_b = create_token_account(&mint, &authority_key, false, 10, &TOKEN_PROGRAM_ID);
+
+    // Destination B appears twice and the source account is also a
+    // destination.
+    let instruction = spl_token_interface::instruction::transfer_many(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &[
+            (&destination_a_key, 100),
+            (&destination_b_key, 200),
+            (&source_account_key, 50),
+            (&destination_b_key, 300),
+        ],
+        &authority_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed to transfer 600 tokens out of the source account.
+
+    let result = mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_a_key, destination_a),
+            (destination_b_key, destination_b),
+            (authority_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    assert_eq!(amount(&result, &source_account_key), 400);
+    assert_eq!(amount(&result, &destination_a_key), 100);
+    assert_eq!(amount(&result, &destination_b_key), 510);
+}
+
+#[test]
+fn transfer_many_native() {
+    let native_mint = Pubkey::new_from_array(native_mint::ID);
+    let authority_key = Pubkey::new_unique();
+
+    // native account:
+    //   - amount: 2_000_000_000
+    let source_account_key = Pubkey::new_unique();
+    let source_account = create_token_account(
+        &native_mint,
+        &authority_key,
+        true,
+        2_000_000_000,
+        &TOKEN_PROGRAM_ID,
+    );
+    let source_lamports = source_account.lamports;
+
+    let destination_a_key = Pubkey::new_unique();
+    let destination_a =
+        create_token_account(&native_mint, &authority_key, true, 0, &TOKEN_PROGRAM_ID);
+    let destination_a_lamports = destination_a.lamports;
+    let destination_b_key = Pubkey::new_unique();
+    let destination_b =
+        create_token_account(&native_mint, &authority_key, true, 0, &TOKEN_PROGRAM_ID);
+    let destination_b_lamports = destination_b.lamports;
+
+    let instruction = spl_token_interface::instruction::transfer_many(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &[
+            (&destination_a_key, 500_000_000),
+            (&destination_b_key, 1_000_000_000),
+        ],
+        &authority_key,
+        &[],
+    )
+    .unwrap();
+
+    // It should succeed and move the lamports with the tokens.
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_a_key, destination_a),
+            (destination_b_key, destination_b),
+            (authority_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&source_account_key)
+                .lamports(source_lamports - 1_500_000_000)
+                .build(),
+            Check::account(&destination_a_key)
+                .lamports(destination_a_lamports + 500_000_000)
+                .build(),
+            Check::account(&destination_b_key)
+                .lamports(destination_b_lamports + 1_000_000_000)
+                .build(),
+        ],
+    );
+}
+
+#[test]
+fn transfer_many_with_delegate() {
+    let mint = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+
+    // source account:
+    //   - amount: 1_000
+    //   - delegated amount: 300
+    let source_account_key = Pubkey::new_unique();
+    let mut source_account =
+        create_token_account(&mint, &owner_key, false, 1_000, &TOKEN_PROGRAM_ID);
+    let token = unsafe { load_mut_unchecked::<TokenAccount>(&mut source_account.data).unwrap() };
+    token.set_delegate(delegate_key.as_array());
+    token.set_delegated_amount(300);
+
+    let destination_a_key = Pubkey::new_unique();
+    let destination_a = create_token_account(&mint, &owner_key, false, 0, &TOKEN_PROGRAM_ID);
+    let destination_b_key = Pubkey::new_unique();
+    let destination_b = create_token_account(&mint, &owner_key, false, 0, &TOKEN_PROGRAM_ID);
+
+    let accounts = [
+        (source_account_key, source_account),
+        (destination_a_key, destination_a),
+        (destination_b_key, destination_b),
+        (delegate_key, Account::default()),
+    ];
+
+    // It should fail to transfer more than the delegated amount in total.
+
+    let instruction = spl_token_interface::instruction::transfer_many(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &[(&destination_a_key, 200), (&destination_b_key, 200)],
+        &delegate_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::Custom(
+            TokenError::InsufficientFunds as u32,
+        ))],
+    );
+
+    // It should succeed to transfer the delegated amount and clear the
+    // delegate.
+
+    let instruction = spl_token_interface::instruction::transfer_many(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &[(&destination_a_key, 100), (&destination_b_key, 200)],
+        &delegate_key,
+        &[],
+    )
+    .unwrap();
+
+    let result =
+        mollusk().process_and_validate_instruction(&instruction, &accounts, &[Check::success()]);
+
+    let account = result.get_account(&source_account_key).unwrap();
+    let token_account = spl_token_interface::state::Account::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 700);
+    assert_eq!(token_account.delegated_amount, 0);
+    assert!(token_account.delegate.is_none());
+}
+
+#[test]
+fn fail_transfer_many_with_mint_mismatch() {
+    let mint = Pubkey::new_unique();
+    let authority_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account =
+        create_token_account(&mint, &authority_key, false, 1_000, &TOKEN_PROGRAM_ID);
+
+    let destination_a_key = Pubkey::new_unique();
+    let destination_a = create_token_account(&mint, &authority_key, false, 0, &TOKEN_PROGRAM_ID);
+
+    // destination account of a different mint.
+    let destination_b_key = Pubkey::new_unique();
+    let destination_b = create_token_account(
+        &Pubkey::new_unique(),
+        &authority_key,
+        false,
+        0,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    let instruction = spl_token_interface::instruction::transfer_many(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &[(&destination_a_key, 100), (&destination_b_key, 100)],
+        &authority_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_a_key, destination_a),
+            (destination_b_key, destination_b),
+            (authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::Custom(
+            TokenError::MintMismatch as u32,
+        ))],
+    );
+}
+
+#[test]
+fn fail_transfer_many_with_invalid_destination_owner() {
+    let mint = Pubkey::new_unique();
+    let authority_key = Pubkey::new_unique();
+
+    let source_account_key = Pubkey::new_unique();
+    let source_account =
+        create_token_account(&mint, &authority_key, false, 1_000, &TOKEN_PROGRAM_ID);
+
+    // destination account owned by a different program.
+    let destination_key = Pubkey::new_unique();
+    let destination = create_token_account(
+        &mint,
+        &authority_key,
+        false,
+        0,
+        &Pubkey::new_from_array([2; 32]),
+    );
+
+    let instruction = spl_token_interface::instruction::transfer_many(
+        &TOKEN_PROGRAM_ID,
+        &source_account_key,
+        &[(&destination_key, 0)],
+        &authority_key,
+        &[],
+    )
+    .unwrap();
+
+    mollusk().process_and_validate_instruction(
+        &instruction,
+        &[
+            (source_account_key, source_account),
+            (destination_key, destination),
+            (authority_key, Account::default()),
+        ],
+        &[Check::err(ProgramError::IncorrectProgramId)],
+    );
+}
@@ -5,14 +5,13 @@ use {
     mollusk_svm::{result::Check, Mollusk},
     pinocchio_token_interface::{
         error::TokenError,
-        instruction::TokenInstruction,
         native_mint,
         state::{
             account::Account as TokenAccount, account_state::AccountState, load_mut_unchecked,
         },
     },
     solana_account::Account,
-    solana_instruction::{error::InstructionError, AccountMeta, Instruction},
+    solana_instruction::error::InstructionError,
     solana_program_error::ProgramError,
     solana_program_pack::Pack,
     solana_pubkey::Pubkey,
@@ -63,35 +62,6 @@ fn mollusk() -> Mollusk {
     mollusk
 }
 
-fn unwrap_lamports_instruction(
-    source: &Pubkey,
-    destination: &Pubkey,
-    authority: &Pubkey,
-    amount: Option<u64>,
-) -> Result<Instruction, ProgramError> {
-    let accounts = vec![
-        AccountMeta::new(*source, false),
-        AccountMeta::new(*destination, false),
-        AccountMeta::new_readonly(*authority, true),
-    ];
-
-    // Start with the batch discriminator
-    let mut data: Vec<u8> = vec![TokenInstruction::UnwrapLamports as u8];
-
-    if let Some(amount) = amount {
-        data.push(1);
-        data.extend_from_slice(&amount.to_le_bytes());
-    } else {
-        data.push(0);
-    }
-
-    Ok(Instruction {
-        program_id: spl_token_interface::ID,
-        data,
-        accounts,
-    })
-}
-
 #[test]
 fn unwrap_lamports() {
     let native_mint = Pubkey::new_from_array(native_mint::ID);
@@ -109,10 +79,12 @@ fn unwrap_lamports() {
         &TOKEN_PROGRAM_ID,
     );
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         None,
     )
     .unwrap();
@@ -164,10 +136,12 @@ fn unwrap_lamports_with_amount() {
         &TOKEN_PROGRAM_ID,
     );
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         Some(2_000_000_000),
     )
     .unwrap();
@@ -219,10 +193,12 @@ fn fail_unwrap_lamports_with_insufficient_funds() {
         &TOKEN_PROGRAM_ID,
     );
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         Some(2_000_000_000),
     )
     .unwrap();
@@ -260,10 +236,12 @@ fn unwrap_lamports_with_parial_amount() {
         &TOKEN_PROGRAM_ID,
     );
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         Some(1_000_000_000),
     )
     .unwrap();
@@ -318,10 +296,12 @@ fn fail_unwrap_lamports_with_invalid_authority() {
         &TOKEN_PROGRAM_ID,
     );
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &fake_authority_key, // <-- wrong authority
+        &[],
         Some(2_000_000_000),
     )
     .unwrap();
@@ -360,10 +340,12 @@ fn fail_unwrap_lamports_with_non_native_account() {
     );
     source_account.lamports += 2_000_000_000;
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         Some(1_000_000_000),
     )
     .unwrap();
@@ -400,10 +382,12 @@ fn unwrap_lamports_with_self_transfer() {
         &TOKEN_PROGRAM_ID,
     );
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &source_account_key, // <-- destination same as source
         &authority_key,
+        &[],
         Some(1_000_000_000),
     )
     .unwrap();
@@ -455,10 +439,12 @@ fn fail_unwrap_lamports_with_invalid_native_account() {
     );
     source_account.lamports += 2_000_000_000;
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         Some(1_000_000_000),
     )
     .unwrap();
@@ -501,10 +487,12 @@ fn unwrap_lamports_to_native_account() {
     let destination_account =
         create_token_account(&native_mint, &authority_key, true, 0, &TOKEN_PROGRAM_ID);
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         None,
     )
     .unwrap();
@@ -579,10 +567,12 @@ fn unwrap_lamports_to_token_account() {
         &TOKEN_PROGRAM_ID,
     );
 
-    let instruction = unwrap_lamports_instruction(
+    let instruction = spl_token_interface::instruction::unwrap_lamports(
+        &TOKEN_PROGRAM_ID,
         &source_account_key,
         &destination_account_key,
         &authority_key,
+        &[],
         None,
     )
     .unwrap();
@@ -5,11 +5,15 @@ use {
         amount_to_ui_amount_string_trimmed,
         error::TokenError,
         instruction::{is_valid_signer_index, AuthorityType, TokenInstruction, MAX_SIGNERS},
-        state::{Account, AccountState, Mint, Multisig},
+        state::{
+            is_memo_required_account, Account, AccountState, Delegation, Expiry, Mint, MintPause,
+            Multisig, SpendingLimit, ACCOUNT_MEMO_REQUIRED_INDEX, PAUSED_MINT_FREEZE_AUTHORITY,
+        },
         try_ui_amount_into_amount,
     },
     solana_account_info::{next_account_info, AccountInfo},
     solana_cpi::set_return_data,
+    solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked},
     solana_msg::msg,
     solana_program_error::{ProgramError, ProgramResult},
     solana_program_memory::sol_memcmp,
@@ -18,9 +22,17 @@ use {
     solana_pubkey::{Pubkey, PUBKEY_BYTES},
     solana_rent::Rent,
     solana_sdk_ids::system_program,
-    solana_sysvar::{Sysvar, SysvarSerialize},
+    solana_sysvar::{clock::Clock, Sysvar, SysvarSerialize},
+    spl_token_interface::memo::is_memo_program,
 };
 
+/// The size of the header of each instruction in a batch.
+///
+/// The header of each instruction consists of two `u8` values:
+///  * number of the accounts
+///  * length of the instruction data
+const BATCH_IX_HEADER_SIZE: usize = 2;
+
 /// Program state handler.
 pub struct Processor {}
 impl Processor {
@@ -49,6 +61,11 @@ impl Processor {
             return Err(TokenError::NotRentExempt.into());
         }
 
+        // Only `PauseMint` can set the freeze authority of a paused mint.
+        if freeze_authority == COption::Some(PAUSED_MINT_FREEZE_AUTHORITY) {
+            return Err(ProgramError::InvalidArgument);
+        }
+
         mint.mint_authority = COption::Some(mint_authority);
         mint.decimals = decimals;
         mint.is_initialized = true;
@@ -265,16 +282,37 @@ impl Processor {
             if expected_decimals != mint.decimals {
                 return Err(TokenError::MintDecimalsMismatch.into());
             }
+            if mint.is_paused() {
+                return Err(TokenError::MintPaused.into());
+            }
         }
 
         let self_transfer =
             Self::cmp_pubkeys(source_account_info.key, destination_account_info.key);
 
+        if !self_transfer && is_memo_required_account(&destination_account_info.data.borrow()) {
+            Self::check_memo(program_id, account_info_iter.as_slice(), 1)?;
+        }
+
         match source_account.delegate {
+            COption::Some(ref delegate)
+                if Self::cmp_pubkeys(authority_info.key, delegate)
+                    && Self::is_spending_limit(program_id, authority_info) =>
+            {
+                // Self-transfers do not consume the spending limit.
+                Self::spend_allowance(
+                    program_id,
+                    source_account_info.key,
+                    authority_info,
+                    account_info_iter.as_slice(),
+                    amount,
+                    if self_transfer { 0 } else { amount },
+                )?;
+            }
             COption::Some(ref delegate) if Self::cmp_pubkeys(authority_info.key, delegate) => {
-                Self::validate_owner(
+                Self::validate_delegate(
                     program_id,
-                    delegate,
+                    source_account_info.key,
                     authority_info,
                     account_info_iter.as_slice(),
                 )?;
@@ -374,6 +412,9 @@ impl Processor {
             if expected_decimals != mint.decimals {
                 return Err(TokenError::MintDecimalsMismatch.into());
             }
+            if mint.is_paused() {
+                return Err(TokenError::MintPaused.into());
+            }
         }
 
         Self::validate_owner(
@@ -503,6 +544,10 @@ impl Processor {
                         authority_info,
                         account_info_iter.as_slice(),
                     )?;
+                    // Only `PauseMint` can set the freeze authority of a paused mint.
+                    if new_authority == COption::Some(PAUSED_MINT_FREEZE_AUTHORITY) {
+                        return Err(ProgramError::InvalidArgument);
+                    }
                     mint.freeze_authority = new_authority;
                 }
                 _ => {
@@ -547,6 +592,9 @@ impl Processor {
                 return Err(TokenError::MintDecimalsMismatch.into());
             }
         }
+        if mint.is_paused() {
+            return Err(TokenError::MintPaused.into());
+        }
 
         match mint.mint_authority {
             COption::Some(mint_authority) => Self::validate_owner(
@@ -616,13 +664,29 @@ impl Processor {
                 return Err(TokenError::MintDecimalsMismatch.into());
             }
         }
+        if mint.is_paused() {
+            return Err(TokenError::MintPaused.into());
+        }
 
         if !source_account.is_owned_by_system_program_or_incinerator() {
             match source_account.delegate {
+                COption::Some(ref delegate)
+                    if Self::cmp_pubkeys(authority_info.key, delegate)
+                        && Self::is_spending_limit(program_id, authority_info) =>
+                {
+                    Self::spend_allowance(
+                        program_id,
+                        source_account_info.key,
+                        authority_info,
+                        account_info_iter.as_slice(),
+                        amount,
+                        amount,
+                    )?;
+                }
                 COption::Some(ref delegate) if Self::cmp_pubkeys(authority_info.key, delegate) => {
-                    Self::validate_owner(
+                    Self::validate_delegate(
                         program_id,
-                        delegate,
+                        source_account_info.key,
                         authority_info,
                         account_info_iter.as_slice(),
                     )?;
@@ -753,6 +817,96 @@ impl Processor {
         Ok(())
     }
 
+    /// Processes a [`PauseMint`](enum.TokenInstruction.html) instruction.
+    pub fn process_pause_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let mint_info = next_account_info(account_info_iter)?;
+        let mint_pause_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        Self::check_account_owner(program_id, mint_info)?;
+        Self::check_account_owner(program_id, mint_pause_info)?;
+
+        let mut mint = Mint::unpack(&mint_info.data.borrow())?;
+        if mint.is_paused() {
+            return Err(TokenError::InvalidState.into());
+        }
+        let freeze_authority = mint
+            .freeze_authority
+            .ok_or(Into::<ProgramError>::into(TokenError::MintCannotFreeze))?;
+        Self::validate_owner(
+            program_id,
+            &freeze_authority,
+            authority_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        let mint_pause = MintPause::unpack_unchecked(&mint_pause_info.data.borrow())?;
+        if mint_pause.is_initialized {
+            return Err(TokenError::AlreadyInUse.into());
+        }
+        if !Rent::get()?.is_exempt(mint_pause_info.lamports(), mint_pause_info.data_len()) {
+            return Err(TokenError::NotRentExempt.into());
+        }
+
+        let mint_pause = MintPause {
+            is_initialized: true,
+            mint: *mint_info.key,
+            freeze_authority,
+        };
+        MintPause::pack(mint_pause, &mut mint_pause_info.data.borrow_mut())?;
+
+        mint.freeze_authority = COption::Some(PAUSED_MINT_FREEZE_AUTHORITY);
+        Mint::pack(mint, &mut mint_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`ResumeMint`](enum.TokenInstruction.html) instruction.
+    pub fn process_resume_mint(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let mint_info = next_account_info(account_info_iter)?;
+        let mint_pause_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        Self::check_account_owner(program_id, mint_info)?;
+        Self::check_account_owner(program_id, mint_pause_info)?;
+
+        if Self::cmp_pubkeys(mint_pause_info.key, destination_account_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut mint = Mint::unpack(&mint_info.data.borrow())?;
+        if !mint.is_paused() {
+            return Err(TokenError::InvalidState.into());
+        }
+
+        let mint_pause = MintPause::unpack(&mint_pause_info.data.borrow())?;
+        if !Self::cmp_pubkeys(&mint_pause.mint, mint_info.key) {
+            return Err(TokenError::MintMismatch.into());
+        }
+        Self::validate_owner(
+            program_id,
+            &mint_pause.freeze_authority,
+            authority_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        mint.freeze_authority = COption::Some(mint_pause.freeze_authority);
+        Mint::pack(mint, &mut mint_info.data.borrow_mut())?;
+
+        let destination_starting_lamports = destination_account_info.lamports();
+        **destination_account_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(mint_pause_info.lamports())
+            .ok_or(TokenError::Overflow)?;
+
+        **mint_pause_info.lamports.borrow_mut() = 0;
+        delete_account(mint_pause_info)?;
+
+        Ok(())
+    }
+
     /// Processes a [`SyncNative`](enum.TokenInstruction.html) instruction
     pub fn process_sync_native(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
         let account_info_iter = &mut accounts.iter();
@@ -845,156 +999,919 @@ impl Processor {
         Ok(())
     }
 
-    /// Processes an [`Instruction`](enum.Instruction.html).
-    pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
-        let instruction = TokenInstruction::unpack(input)?;
+    /// Processes a [`WithdrawExcessLamports`](enum.TokenInstruction.html)
+    /// instruction
+    pub fn process_withdraw_excess_lamports(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
 
-        match instruction {
-            TokenInstruction::InitializeMint {
-                decimals,
-                mint_authority,
-                freeze_authority,
-            } => {
-                msg!("Instruction: InitializeMint");
-                Self::process_initialize_mint(accounts, decimals, mint_authority, freeze_authority)
-            }
-            TokenInstruction::InitializeMint2 {
-                decimals,
-                mint_authority,
-                freeze_authority,
-            } => {
-                msg!("Instruction: InitializeMint2");
-                Self::process_initialize_mint2(accounts, decimals, mint_authority, freeze_authority)
-            }
-            TokenInstruction::InitializeAccount => {
-                msg!("Instruction: InitializeAccount");
-                Self::process_initialize_account(program_id, accounts)
-            }
-            TokenInstruction::InitializeAccount2 { owner } => {
-                msg!("Instruction: InitializeAccount2");
-                Self::process_initialize_account2(program_id, accounts, owner)
-            }
-            TokenInstruction::InitializeAccount3 { owner } => {
-                msg!("Instruction: InitializeAccount3");
-                Self::process_initialize_account3(program_id, accounts, owner)
-            }
-            TokenInstruction::InitializeMultisig { m } => {
-                msg!("Instruction: InitializeMultisig");
-                Self::process_initialize_multisig(accounts, m)
-            }
-            TokenInstruction::InitializeMultisig2 { m } => {
-                msg!("Instruction: InitializeMultisig2");
-                Self::process_initialize_multisig2(accounts, m)
-            }
-            TokenInstruction::Transfer { amount } => {
-                msg!("Instruction: Transfer");
-                Self::process_transfer(program_id, accounts, amount, None)
-            }
-            TokenInstruction::Approve { amount } => {
-                msg!("Instruction: Approve");
-                Self::process_approve(program_id, accounts, amount, None)
-            }
-            TokenInstruction::Revoke => {
-                msg!("Instruction: Revoke");
-                Self::process_revoke(program_id, accounts)
-            }
-            TokenInstruction::SetAuthority {
-                authority_type,
-                new_authority,
-            } => {
-                msg!("Instruction: SetAuthority");
-                Self::process_set_authority(program_id, accounts, authority_type, new_authority)
-            }
-            TokenInstruction::MintTo { amount } => {
-                msg!("Instruction: MintTo");
-                Self::process_mint_to(program_id, accounts, amount, None)
-            }
-            TokenInstruction::Burn { amount } => {
-                msg!("Instruction: Burn");
-                Self::process_burn(program_id, accounts, amount, None)
-            }
-            TokenInstruction::CloseAccount => {
-                msg!("Instruction: CloseAccount");
-                Self::process_close_account(program_id, accounts)
-            }
-            TokenInstruction::FreezeAccount => {
-                msg!("Instruction: FreezeAccount");
-                Self::process_toggle_freeze_account(program_id, accounts, true)
-            }
-            TokenInstruction::ThawAccount => {
-                msg!("Instruction: ThawAccount");
-                Self::process_toggle_freeze_account(program_id, accounts, false)
-            }
-            TokenInstruction::TransferChecked { amount, decimals } => {
-                msg!("Instruction: TransferChecked");
-                Self::process_transfer(program_id, accounts, amount, Some(decimals))
-            }
-            TokenInstruction::ApproveChecked { amount, decimals } => {
-                msg!("Instruction: ApproveChecked");
-                Self::process_approve(program_id, accounts, amount, Some(decimals))
-            }
-            TokenInstruction::MintToChecked { amount, decimals } => {
-                msg!("Instruction: MintToChecked");
-                Self::process_mint_to(program_id, accounts, amount, Some(decimals))
-            }
-            TokenInstruction::BurnChecked { amount, decimals } => {
-                msg!("Instruction: BurnChecked");
-                Self::process_burn(program_id, accounts, amount, Some(decimals))
-            }
-            TokenInstruction::SyncNative => {
-                msg!("Instruction: SyncNative");
-                Self::process_sync_native(program_id, accounts)
-            }
-            TokenInstruction::GetAccountDataSize => {
-                msg!("Instruction: GetAccountDataSize");
-                Self::process_get_account_data_size(program_id, accounts)
-            }
-            TokenInstruction::InitializeImmutableOwner => {
-                msg!("Instruction: InitializeImmutableOwner");
-                Self::process_initialize_immutable_owner(accounts)
+        let source_data_len = source_account_info.data_len();
+
+        match source_data_len {
+            Account::LEN => {
+                let account = Account::unpack(&source_account_info.data.borrow())?;
+                if account.is_native() {
+                    return Err(TokenError::NativeNotSupported.into());
+                }
+                Self::validate_owner(
+                    program_id,
+                    &account.owner,
+                    authority_info,
+                    account_info_iter.as_slice(),
+                )?;
             }
-            TokenInstruction::AmountToUiAmount { amount } => {
-                msg!("Instruction: AmountToUiAmount");
-                Self::process_amount_to_ui_amount(program_id, accounts, amount)
+            Mint::LEN => {
+                let mint = Mint::unpack(&source_account_info.data.borrow())?;
+                match mint.mint_authority {
+                    COption::Some(mint_authority) => Self::validate_owner(
+                        program_id,
+                        &mint_authority,
+                        authority_info,
+                        account_info_iter.as_slice(),
+                    )?,
+                    // There is no mint authority set but the mint account is
+                    // the same as the authority account and, therefore, needs
+                    // to be a signer.
+                    COption::None
+                        if Self::cmp_pubkeys(source_account_info.key, authority_info.key) =>
+                    {
+                        if !authority_info.is_signer {
+                            return Err(ProgramError::MissingRequiredSignature);
+                        }
+                    }
+                    COption::None => {
+                        return Err(TokenError::AuthorityTypeNotSupported.into());
+                    }
+                }
             }
-            TokenInstruction::UiAmountToAmount { ui_amount } => {
-                msg!("Instruction: UiAmountToAmount");
-                Self::process_ui_amount_to_amount(program_id, accounts, ui_amount)
+            Multisig::LEN => {
+                Self::validate_owner(
+                    program_id,
+                    source_account_info.key,
+                    authority_info,
+                    account_info_iter.as_slice(),
+                )?;
             }
+            _ => return Err(TokenError::InvalidState.into()),
         }
-    }
 
-    /// Checks that the account is owned by the expected program
-    pub fn check_account_owner(program_id: &Pubkey, account_info: &AccountInfo) -> ProgramResult {
-        if !Self::cmp_pubkeys(program_id, account_info.owner) {
-            Err(ProgramError::IncorrectProgramId)
-        } else {
-            Ok(())
-        }
-    }
+        let source_rent_exempt_reserve = Rent::get()?.minimum_balance(source_data_len);
 
-    /// Checks two pubkeys for equality in a computationally cheap way using
-    /// `sol_memcmp`
-    pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
-        unsafe { sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0 }
+        let source_starting_lamports = source_account_info.lamports();
+        let transfer_amount = source_starting_lamports
+            .checked_sub(source_rent_exempt_reserve)
+            .ok_or(TokenError::NotRentExempt)?;
+
+        **source_account_info.lamports.borrow_mut() = source_starting_lamports
+            .checked_sub(transfer_amount)
+            .ok_or(TokenError::Overflow)?;
+
+        let destination_starting_lamports = destination_info.lamports();
+        **destination_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(transfer_amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Ok(())
     }
 
-    /// Validates owner(s) are present
-    pub fn validate_owner(
+    /// Processes an [`UnwrapLamports`](enum.TokenInstruction.html)
+    /// instruction
+    pub fn process_unwrap_lamports(
         program_id: &Pubkey,
-        expected_owner: &Pubkey,
-        owner_account_info: &AccountInfo,
-        signers: &[AccountInfo],
+        accounts: &[AccountInfo],
+        amount: Option<u64>,
     ) -> ProgramResult {
-        if !Self::cmp_pubkeys(expected_owner, owner_account_info.key) {
-            return Err(TokenError::OwnerMismatch.into());
-        }
-        if Self::cmp_pubkeys(program_id, owner_account_info.owner)
-            && owner_account_info.data_len() == Multisig::get_packed_len()
-        {
-            let multisig = Multisig::unpack(&owner_account_info.data.borrow())?;
-            let mut num_signers = 0;
-            let mut matched = [false; MAX_SIGNERS];
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        if !source_account.is_native() {
+            return Err(TokenError::NonNativeNotSupported.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            authority_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        // When an amount is not specified, the entire balance of the source
+        // account is unwrapped.
+        let amount = amount.unwrap_or(source_account.amount);
+
+        source_account.amount = source_account
+            .amount
+            .checked_sub(amount)
+            .ok_or(TokenError::InsufficientFunds)?;
+
+        if amount == 0 {
+            // The account is not written, so its owner needs to be validated
+            // explicitly.
+            return Self::check_account_owner(program_id, source_account_info);
+        }
+
+        if !Self::cmp_pubkeys(source_account_info.key, destination_account_info.key) {
+            let source_starting_lamports = source_account_info.lamports();
+            **source_account_info.lamports.borrow_mut() = source_starting_lamports
+                .checked_sub(amount)
+                .ok_or(TokenError::Overflow)?;
+
+            let destination_starting_lamports = destination_account_info.lamports();
+            **destination_account_info.lamports.borrow_mut() = destination_starting_lamports
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+        }
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`TransferMany`](enum.TokenInstruction.html) instruction.
+    pub fn process_transfer_many(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amounts: &[u64],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        if account_info_iter.len() < amounts.len() {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        }
+
+        let (destination_infos, signer_infos) =
+            account_info_iter.as_slice().split_at(amounts.len());
+
+        Self::check_account_owner(program_id, source_account_info)?;
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        let mut total_amount = 0u64;
+        // Amount of tokens leaving the source account, excluding the ones
+        // "transferred" to the source account itself.
+        let mut debited_amount = 0u64;
+        let mut self_transfer = true;
+
+        for (destination_account_info, &amount) in destination_infos.iter().zip(amounts) {
+            total_amount = total_amount
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+
+            if !Self::cmp_pubkeys(source_account_info.key, destination_account_info.key) {
+                debited_amount = debited_amount
+                    .checked_add(amount)
+                    .ok_or(TokenError::Overflow)?;
+                self_transfer = false;
+            }
+        }
+
+        if source_account.amount < total_amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+
+        // Number of transfers into accounts that require a memo.
+        let mut memos = 0usize;
+
+        for destination_account_info in destination_infos {
+            Self::check_account_owner(program_id, destination_account_info)?;
+
+            if !Self::cmp_pubkeys(source_account_info.key, destination_account_info.key) {
+                let destination_account = Account::unpack(&destination_account_info.data.borrow())?;
+
+                if destination_account.is_frozen() {
+                    return Err(TokenError::AccountFrozen.into());
+                }
+                if !Self::cmp_pubkeys(&source_account.mint, &destination_account.mint) {
+                    return Err(TokenError::MintMismatch.into());
+                }
+
+                if is_memo_required_account(&destination_account_info.data.borrow()) {
+                    memos = memos.checked_add(1).ok_or(TokenError::Overflow)?;
+                }
+            }
+        }
+
+        if memos > 0 {
+            Self::check_memo(program_id, signer_infos, memos)?;
+        }
+
+        match source_account.delegate {
+            COption::Some(ref delegate)
+                if Self::cmp_pubkeys(authority_info.key, delegate)
+                    && Self::is_spending_limit(program_id, authority_info) =>
+            {
+                Self::spend_allowance(
+                    program_id,
+                    source_account_info.key,
+                    authority_info,
+                    signer_infos,
+                    total_amount,
+                    debited_amount,
+                )?;
+            }
+            COption::Some(ref delegate) if Self::cmp_pubkeys(authority_info.key, delegate) => {
+                Self::validate_delegate(
+                    program_id,
+                    source_account_info.key,
+                    authority_info,
+                    signer_infos,
+                )?;
+                if source_account.delegated_amount < total_amount {
+                    return Err(TokenError::InsufficientFunds.into());
+                }
+                if !self_transfer {
+                    source_account.delegated_amount = source_account
+                        .delegated_amount
+                        .checked_sub(debited_amount)
+                        .ok_or(TokenError::Overflow)?;
+                    if source_account.delegated_amount == 0 {
+                        source_account.delegate = COption::None;
+                    }
+                }
+            }
+            _ => Self::validate_owner(
+                program_id,
+                &source_account.owner,
+                authority_info,
+                signer_infos,
+            )?,
+        };
+
+        source_account.amount = source_account
+            .amount
+            .checked_sub(debited_amount)
+            .ok_or(TokenError::Overflow)?;
+
+        for (destination_account_info, &amount) in destination_infos.iter().zip(amounts) {
+            if Self::cmp_pubkeys(source_account_info.key, destination_account_info.key)
+                || amount == 0
+            {
+                continue;
+            }
+
+            let mut destination_account = Account::unpack(&destination_account_info.data.borrow())?;
+            destination_account.amount = destination_account
+                .amount
+                .checked_add(amount)
+                .ok_or(TokenError::Overflow)?;
+
+            if source_account.is_native() {
+                let source_starting_lamports = source_account_info.lamports();
+                **source_account_info.lamports.borrow_mut() = source_starting_lamports
+                    .checked_sub(amount)
+                    .ok_or(TokenError::Overflow)?;
+
+                let destination_starting_lamports = destination_account_info.lamports();
+                **destination_account_info.lamports.borrow_mut() = destination_starting_lamports
+                    .checked_add(amount)
+                    .ok_or(TokenError::Overflow)?;
+            }
+
+            Account::pack(
+                destination_account,
+                &mut destination_account_info.data.borrow_mut(),
+            )?;
+        }
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`BurnAndClose`](enum.TokenInstruction.html) instruction.
+    pub fn process_burn_and_close(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        if Self::cmp_pubkeys(source_account_info.key, destination_account_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let source_account = Account::unpack(&source_account_info.data.borrow())?;
+        let mut mint = Mint::unpack(&mint_info.data.borrow())?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        if source_account.is_native() {
+            return Err(TokenError::NativeNotSupported.into());
+        }
+        if !Self::cmp_pubkeys(mint_info.key, &source_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+        if mint.is_paused() {
+            return Err(TokenError::MintPaused.into());
+        }
+
+        let amount = source_account.amount;
+
+        if !source_account.is_owned_by_system_program_or_incinerator() {
+            let authority = source_account
+                .close_authority
+                .unwrap_or(source_account.owner);
+
+            // Burning the remaining balance requires the owner, so a close
+            // authority different than the owner can only close empty accounts.
+            if amount != 0 && !Self::cmp_pubkeys(&authority, &source_account.owner) {
+                return Err(TokenError::OwnerMismatch.into());
+            }
+
+            Self::validate_owner(
+                program_id,
+                &authority,
+                authority_info,
+                account_info_iter.as_slice(),
+            )?;
+        } else if !solana_sdk_ids::incinerator::check_id(destination_account_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if amount == 0 {
+            Self::check_account_owner(program_id, mint_info)?;
+        }
+
+        mint.supply = mint
+            .supply
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+        Mint::pack(mint, &mut mint_info.data.borrow_mut())?;
+
+        let destination_starting_lamports = destination_account_info.lamports();
+        **destination_account_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(source_account_info.lamports())
+            .ok_or(TokenError::Overflow)?;
+
+        **source_account_info.lamports.borrow_mut() = 0;
+        delete_account(source_account_info)?;
+
+        Ok(())
+    }
+
+    /// Processes a [`SweepAndClose`](enum.TokenInstruction.html) instruction.
+    pub fn process_sweep_and_close(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let recipient_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+        let signer_infos = account_info_iter.as_slice();
+
+        if Self::cmp_pubkeys(source_account_info.key, destination_account_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let amount = Account::unpack(&source_account_info.data.borrow())?.amount;
+
+        Self::process_transfer(
+            program_id,
+            &[
+                &[
+                    source_account_info.clone(),
+                    destination_account_info.clone(),
+                    authority_info.clone(),
+                ],
+                signer_infos,
+            ]
+            .concat(),
+            amount,
+            None,
+        )?;
+
+        Self::process_close_account(
+            program_id,
+            &[
+                &[
+                    source_account_info.clone(),
+                    recipient_info.clone(),
+                    authority_info.clone(),
+                ],
+                signer_infos,
+            ]
+            .concat(),
+        )
+    }
+
+    /// Processes an [`ApproveWithExpiry`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_approve_with_expiry(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+        expiry: Expiry,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let delegation_info = next_account_info(account_info_iter)?;
+        let delegate_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_account_owner(program_id, delegation_info)?;
+
+        let delegation = Delegation::unpack_unchecked(&delegation_info.data.borrow())?;
+        if delegation.is_initialized {
+            // A delegation account can only be reused for the same token account.
+            if !Self::cmp_pubkeys(&delegation.account, source_account_info.key) {
+                return Err(TokenError::AlreadyInUse.into());
+            }
+        } else if !Rent::get()?.is_exempt(delegation_info.lamports(), delegation_info.data_len()) {
+            return Err(TokenError::NotRentExempt.into());
+        }
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        let delegation = Delegation {
+            is_initialized: true,
+            account: *source_account_info.key,
+            delegate: *delegate_info.key,
+            expiry,
+        };
+        Delegation::pack(delegation, &mut delegation_info.data.borrow_mut())?;
+
+        source_account.delegate = COption::Some(*delegation_info.key);
+        source_account.delegated_amount = amount;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes an [`ApproveWithSpendingLimit`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_approve_with_spending_limit(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        limit: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let spending_limit_info = next_account_info(account_info_iter)?;
+        let delegate_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_account_owner(program_id, spending_limit_info)?;
+
+        let spending_limit = SpendingLimit::unpack_unchecked(&spending_limit_info.data.borrow())?;
+        if spending_limit.is_initialized {
+            // A spending limit account can only be reused for the same token account.
+            if !Self::cmp_pubkeys(&spending_limit.account, source_account_info.key) {
+                return Err(TokenError::AlreadyInUse.into());
+            }
+        } else if !Rent::get()?.is_exempt(
+            spending_limit_info.lamports(),
+            spending_limit_info.data_len(),
+        ) {
+            return Err(TokenError::NotRentExempt.into());
+        }
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        let spending_limit = SpendingLimit {
+            is_initialized: true,
+            account: *source_account_info.key,
+            delegate: *delegate_info.key,
+            limit,
+            epoch: Clock::get()?.epoch,
+            spent: 0,
+        };
+        SpendingLimit::pack(spending_limit, &mut spending_limit_info.data.borrow_mut())?;
+
+        // The delegated amount is informational only, since the spending limit
+        // account tracks the amount spent.
+        source_account.delegate = COption::Some(*spending_limit_info.key);
+        source_account.delegated_amount = limit;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`CloseDelegation`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_close_delegation(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let delegation_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        Self::check_account_owner(program_id, source_account_info)?;
+        Self::check_account_owner(program_id, delegation_info)?;
+
+        if Self::cmp_pubkeys(delegation_info.key, destination_account_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let account = if delegation_info.data_len() == SpendingLimit::LEN {
+            SpendingLimit::unpack(&delegation_info.data.borrow())?.account
+        } else {
+            Delegation::unpack(&delegation_info.data.borrow())?.account
+        };
+        if !Self::cmp_pubkeys(&account, source_account_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let mut source_account = Account::unpack(&source_account_info.data.borrow())?;
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        // Revokes the delegation if it is still the delegate of the account.
+        if source_account.delegate == COption::Some(*delegation_info.key) {
+            if source_account.is_frozen() {
+                return Err(TokenError::AccountFrozen.into());
+            }
+
+            source_account.delegate = COption::None;
+            source_account.delegated_amount = 0;
+
+            Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+        }
+
+        let destination_starting_lamports = destination_account_info.lamports();
+        **destination_account_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(delegation_info.lamports())
+            .ok_or(TokenError::Overflow)?;
+
+        **delegation_info.lamports.borrow_mut() = 0;
+        delete_account(delegation_info)?;
+
+        Ok(())
+    }
+
+    /// Processes a [`SetMemoRequired`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_set_memo_required(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        required: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let account_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        let account = Account::unpack(&account_info.data.borrow())?;
+
+        if account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        // The memo-required flag is stored in the native amount of the account.
+        if account.is_native() {
+            return Err(TokenError::NativeNotSupported.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        account_info.data.borrow_mut()[ACCOUNT_MEMO_REQUIRED_INDEX] = required as u8;
+
+        Ok(())
+    }
+
+    /// Processes a [`Batch`](enum.TokenInstruction.html) instruction.
+    ///
+    /// Each instruction of the batch is processed in sequence using the slice
+    /// of accounts and instruction data described by its header. Nested
+    /// batches are not supported.
+    pub fn process_batch(
+        program_id: &Pubkey,
+        mut accounts: &[AccountInfo],
+        mut data: &[u8],
+    ) -> ProgramResult {
+        loop {
+            let [expected_accounts, data_len, ..] = *data else {
+                return Err(TokenError::InvalidInstruction.into());
+            };
+
+            let expected_accounts = expected_accounts as usize;
+            let data_offset = BATCH_IX_HEADER_SIZE + data_len as usize;
+
+            if data.len() < data_offset || data_offset == BATCH_IX_HEADER_SIZE {
+                return Err(TokenError::InvalidInstruction.into());
+            }
+
+            if accounts.len() < expected_accounts {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            }
+
+            let (ix_accounts, ix_data) = (
+                &accounts[..expected_accounts],
+                &data[BATCH_IX_HEADER_SIZE..data_offset],
+            );
+
+            // Ownership is only enforced by the runtime at the end of the
+            // batch, so instructions that modify accounts without otherwise
+            // validating their owner need an explicit check.
+            match ix_data[0] {
+                // 3 - Transfer
+                // 7 - MintTo
+                // 8 - Burn
+                // 14 - MintToChecked
+                // 15 - BurnChecked
+                // 47 - BurnAndClose
+                // 48 - SweepAndClose
+                3 | 7 | 8 | 14 | 15 | 47 | 48 => {
+                    let [a0, a1, ..] = ix_accounts else {
+                        return Err(ProgramError::NotEnoughAccountKeys);
+                    };
+                    Self::check_account_owner(program_id, a0)?;
+                    Self::check_account_owner(program_id, a1)?;
+                }
+                // 12 - TransferChecked
+                12 => {
+                    let [a0, _, a2, ..] = ix_accounts else {
+                        return Err(ProgramError::NotEnoughAccountKeys);
+                    };
+                    Self::check_account_owner(program_id, a0)?;
+                    Self::check_account_owner(program_id, a2)?;
+                }
+                // 4 - Approve
+                // 5 - Revoke
+                // 6 - SetAuthority
+                // 9 - CloseAccount
+                // 10 - FreezeAccount
+                // 11 - ThawAccount
+                // 13 - ApproveChecked
+                // 22 - InitializeImmutableOwner
+                // 38 - WithdrawExcessLamports
+                // 45 - UnwrapLamports
+                // 49 - ApproveWithExpiry
+                // 50 - ApproveWithSpendingLimit
+                // 51 - SetMemoRequired
+                // 52 - PauseMint
+                // 53 - ResumeMint
+                // 54 - CloseDelegation
+                4..=13 | 22 | 38 | 45 | 49..=54 => {
+                    let [a0, ..] = ix_accounts else {
+                        return Err(ProgramError::NotEnoughAccountKeys);
+                    };
+                    Self::check_account_owner(program_id, a0)?;
+                }
+                // 255 - Batch
+                255 => return Err(TokenError::InvalidInstruction.into()),
+                _ => {}
+            }
+
+            Self::process(program_id, ix_accounts, ix_data)?;
+
+            if data_offset == data.len() {
+                // The batch is complete.
+                return Ok(());
+            }
+
+            accounts = &accounts[expected_accounts..];
+            data = &data[data_offset..];
+        }
+    }
+
+    /// Processes an [`Instruction`](enum.Instruction.html).
+    pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+        let instruction = TokenInstruction::unpack(input)?;
+
+        match instruction {
+            TokenInstruction::InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                msg!("Instruction: InitializeMint");
+                Self::process_initialize_mint(accounts, decimals, mint_authority, freeze_authority)
+            }
+            TokenInstruction::InitializeMint2 {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                msg!("Instruction: InitializeMint2");
+                Self::process_initialize_mint2(accounts, decimals, mint_authority, freeze_authority)
+            }
+            TokenInstruction::InitializeAccount => {
+                msg!("Instruction: InitializeAccount");
+                Self::process_initialize_account(program_id, accounts)
+            }
+            TokenInstruction::InitializeAccount2 { owner } => {
+                msg!("Instruction: InitializeAccount2");
+                Self::process_initialize_account2(program_id, accounts, owner)
+            }
+            TokenInstruction::InitializeAccount3 { owner } => {
+                msg!("Instruction: InitializeAccount3");
+                Self::process_initialize_account3(program_id, accounts, owner)
+            }
+            TokenInstruction::InitializeMultisig { m } => {
+                msg!("Instruction: InitializeMultisig");
+                Self::process_initialize_multisig(accounts, m)
+            }
+            TokenInstruction::InitializeMultisig2 { m } => {
+                msg!("Instruction: InitializeMultisig2");
+                Self::process_initialize_multisig2(accounts, m)
+            }
+            TokenInstruction::Transfer { amount } => {
+                msg!("Instruction: Transfer");
+                Self::process_transfer(program_id, accounts, amount, None)
+            }
+            TokenInstruction::Approve { amount } => {
+                msg!("Instruction: Approve");
+                Self::process_approve(program_id, accounts, amount, None)
+            }
+            TokenInstruction::Revoke => {
+                msg!("Instruction: Revoke");
+                Self::process_revoke(program_id, accounts)
+            }
+            TokenInstruction::SetAuthority {
+                authority_type,
+                new_authority,
+            } => {
+                msg!("Instruction: SetAuthority");
+                Self::process_set_authority(program_id, accounts, authority_type, new_authority)
+            }
+            TokenInstruction::MintTo { amount } => {
+                msg!("Instruction: MintTo");
+                Self::process_mint_to(program_id, accounts, amount, None)
+            }
+            TokenInstruction::Burn { amount } => {
+                msg!("Instruction: Burn");
+                Self::process_burn(program_id, accounts, amount, None)
+            }
+            TokenInstruction::CloseAccount => {
+                msg!("Instruction: CloseAccount");
+                Self::process_close_account(program_id, accounts)
+            }
+            TokenInstruction::FreezeAccount => {
+                msg!("Instruction: FreezeAccount");
+                Self::process_toggle_freeze_account(program_id, accounts, true)
+            }
+            TokenInstruction::ThawAccount => {
+                msg!("Instruction: ThawAccount");
+                Self::process_toggle_freeze_account(program_id, accounts, false)
+            }
+            TokenInstruction::TransferChecked { amount, decimals } => {
+                msg!("Instruction: TransferChecked");
+                Self::process_transfer(program_id, accounts, amount, Some(decimals))
+            }
+            TokenInstruction::ApproveChecked { amount, decimals } => {
+                msg!("Instruction: ApproveChecked");
+                Self::process_approve(program_id, accounts, amount, Some(decimals))
+            }
+            TokenInstruction::MintToChecked { amount, decimals } => {
+                msg!("Instruction: MintToChecked");
+                Self::process_mint_to(program_id, accounts, amount, Some(decimals))
+            }
+            TokenInstruction::BurnChecked { amount, decimals } => {
+                msg!("Instruction: BurnChecked");
+                Self::process_burn(program_id, accounts, amount, Some(decimals))
+            }
+            TokenInstruction::SyncNative => {
+                msg!("Instruction: SyncNative");
+                Self::process_sync_native(program_id, accounts)
+            }
+            TokenInstruction::GetAccountDataSize => {
+                msg!("Instruction: GetAccountDataSize");
+                Self::process_get_account_data_size(program_id, accounts)
+            }
+            TokenInstruction::InitializeImmutableOwner => {
+                msg!("Instruction: InitializeImmutableOwner");
+                Self::process_initialize_immutable_owner(accounts)
+            }
+            TokenInstruction::AmountToUiAmount { amount } => {
+                msg!("Instruction: AmountToUiAmount");
+                Self::process_amount_to_ui_amount(program_id, accounts, amount)
+            }
+            TokenInstruction::UiAmountToAmount { ui_amount } => {
+                msg!("Instruction: UiAmountToAmount");
+                Self::process_ui_amount_to_amount(program_id, accounts, ui_amount)
+            }
+            TokenInstruction::WithdrawExcessLamports => {
+                msg!("Instruction: WithdrawExcessLamports");
+                Self::process_withdraw_excess_lamports(program_id, accounts)
+            }
+            TokenInstruction::UnwrapLamports { amount } => {
+                msg!("Instruction: UnwrapLamports");
+                Self::process_unwrap_lamports(program_id, accounts, amount)
+            }
+            TokenInstruction::TransferMany { amounts } => {
+                msg!("Instruction: TransferMany");
+                Self::process_transfer_many(program_id, accounts, &amounts)
+            }
+            TokenInstruction::BurnAndClose => {
+                msg!("Instruction: BurnAndClose");
+                Self::process_burn_and_close(program_id, accounts)
+            }
+            TokenInstruction::SweepAndClose => {
+                msg!("Instruction: SweepAndClose");
+                Self::process_sweep_and_close(program_id, accounts)
+            }
+            TokenInstruction::ApproveWithExpiry { amount, expiry } => {
+                msg!("Instruction: ApproveWithExpiry");
+                Self::process_approve_with_expiry(program_id, accounts, amount, expiry)
+            }
+            TokenInstruction::ApproveWithSpendingLimit { limit } => {
+                msg!("Instruction: ApproveWithSpendingLimit");
+                Self::process_approve_with_spending_limit(program_id, accounts, limit)
+            }
+            TokenInstruction::SetMemoRequired { required } => {
+                msg!("Instruction: SetMemoRequired");
+                Self::process_set_memo_required(program_id, accounts, required)
+            }
+            TokenInstruction::PauseMint => {
+                msg!("Instruction: PauseMint");
+                Self::process_pause_mint(program_id, accounts)
+            }
+            TokenInstruction::ResumeMint => {
+                msg!("Instruction: ResumeMint");
+                Self::process_resume_mint(program_id, accounts)
+            }
+            TokenInstruction::CloseDelegation => {
+                msg!("Instruction: CloseDelegation");
+                Self::process_close_delegation(program_id, accounts)
+            }
+            TokenInstruction::Batch { data } => {
+                msg!("Instruction: Batch");
+                Self::process_batch(program_id, accounts, data)
+            }
+        }
+    }
+
+    /// Checks that the account is owned by the expected program
+    pub fn check_account_owner(program_id: &Pubkey, account_info: &AccountInfo) -> ProgramResult {
+        if !Self::cmp_pubkeys(program_id, account_info.owner) {
+            Err(ProgramError::IncorrectProgramId)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks two pubkeys for equality in a computationally cheap way using
+    /// `sol_memcmp`
+    pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
+        unsafe { sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0 }
+    }
+
+    /// Validates owner(s) are present
+    pub fn validate_owner(
+        program_id: &Pubkey,
+        expected_owner: &Pubkey,
+        owner_account_info: &AccountInfo,
+        signers: &[AccountInfo],
+    ) -> ProgramResult {
+        if !Self::cmp_pubkeys(expected_owner, owner_account_info.key) {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+        if Self::cmp_pubkeys(program_id, owner_account_info.owner)
+            && owner_account_info.data_len() == Multisig::get_packed_len()
+        {
+            let multisig = Multisig::unpack(&owner_account_info.data.borrow())?;
+            let mut num_signers = 0;
+            let mut matched = [false; MAX_SIGNERS];
             for signer in signers.iter() {
                 for (position, key) in multisig.signers[0..multisig.n as usize].iter().enumerate() {
                     if Self::cmp_pubkeys(key, signer.key) && !matched[position] {
@@ -1015,6 +1932,135 @@ impl Processor {
         }
         Ok(())
     }
+
+    /// Validates delegate(s) are present
+    ///
+    /// When the delegate is a delegation account, the delegation must be of
+    /// the token `account` and not expired, and the signers are expected to
+    /// start with the actual delegate.
+    pub fn validate_delegate(
+        program_id: &Pubkey,
+        account: &Pubkey,
+        delegate_account_info: &AccountInfo,
+        signers: &[AccountInfo],
+    ) -> ProgramResult {
+        if Self::cmp_pubkeys(program_id, delegate_account_info.owner)
+            && delegate_account_info.data_len() == Delegation::get_packed_len()
+        {
+            let delegation = Delegation::unpack(&delegate_account_info.data.borrow())?;
+            if !Self::cmp_pubkeys(&delegation.account, account) {
+                return Err(TokenError::OwnerMismatch.into());
+            }
+            let clock = Clock::get()?;
+            if delegation
+                .expiry
+                .is_expired(clock.slot, clock.unix_timestamp)
+            {
+                return Err(TokenError::DelegationExpired.into());
+            }
+            let (delegate_info, signers) = signers
+                .split_first()
+                .ok_or(ProgramError::NotEnoughAccountKeys)?;
+            Self::validate_owner(program_id, &delegation.delegate, delegate_info, signers)
+        } else {
+            Self::validate_owner(
+                program_id,
+                delegate_account_info.key,
+                delegate_account_info,
+                signers,
+            )
+        }
+    }
+
+    /// Checks that the `memos` instructions preceding the current top-level
+    /// instruction in the transaction are memos.
+    ///
+    /// Transfers made through a cross-program invocation are attributed to
+    /// the memos preceding the top-level instruction of the calling program.
+    /// The check fails when the top-level instruction is a `Batch` of the
+    /// token program, since its memos could not be attributed to a single
+    /// transfer.
+    ///
+    /// The Instructions sysvar is expected to be the last of the
+    /// `account_infos`.
+    pub fn check_memo(
+        program_id: &Pubkey,
+        account_infos: &[AccountInfo],
+        memos: usize,
+    ) -> ProgramResult {
+        let instructions_info = account_infos
+            .last()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        let index = load_current_index_checked(instructions_info)? as usize;
+        let current = load_instruction_at_checked(index, instructions_info)?;
+        if Self::cmp_pubkeys(program_id, &current.program_id)
+            && matches!(
+                TokenInstruction::unpack(&current.data),
+                Ok(TokenInstruction::Batch { .. })
+            )
+        {
+            return Err(TokenError::NoMemo.into());
+        }
+        let first_index = index.checked_sub(memos).ok_or(TokenError::NoMemo)?;
+        for memo_index in first_index..index {
+            let instruction = load_instruction_at_checked(memo_index, instructions_info)?;
+            if !is_memo_program(&instruction.program_id) {
+                return Err(TokenError::NoMemo.into());
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks whether the delegate account is a spending limit account.
+    pub fn is_spending_limit(program_id: &Pubkey, delegate_account_info: &AccountInfo) -> bool {
+        Self::cmp_pubkeys(program_id, delegate_account_info.owner)
+            && delegate_account_info.data_len() == SpendingLimit::get_packed_len()
+    }
+
+    /// Validates the delegate signer(s) of a spending limit account and
+    /// records `debit` tokens as spent in the current epoch, after checking
+    /// that `amount` tokens are within the amount left to spend.
+    ///
+    /// The spending limit must be of the token `account`, and the signers are
+    /// expected to start with the actual delegate.
+    pub fn spend_allowance(
+        program_id: &Pubkey,
+        account: &Pubkey,
+        spending_limit_info: &AccountInfo,
+        signers: &[AccountInfo],
+        amount: u64,
+        debit: u64,
+    ) -> ProgramResult {
+        let mut spending_limit = SpendingLimit::unpack(&spending_limit_info.data.borrow())?;
+        if !Self::cmp_pubkeys(&spending_limit.account, account) {
+            return Err(TokenError::OwnerMismatch.into());
+        }
+        let (delegate_info, signers) = signers
+            .split_first()
+            .ok_or(ProgramError::NotEnoughAccountKeys)?;
+        Self::validate_owner(program_id, &spending_limit.delegate, delegate_info, signers)?;
+
+        let epoch = Clock::get()?.epoch;
+        if spending_limit.remaining(epoch) < amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+
+        // The spending limit is only updated when tokens are spent, so it does
+        // not need to be writable otherwise.
+        if debit > 0 {
+            if spending_limit.epoch != epoch {
+                spending_limit.epoch = epoch;
+                spending_limit.spent = 0;
+            }
+            spending_limit.spent = spending_limit
+                .spent
+                .checked_add(debit)
+                .ok_or(TokenError::Overflow)?;
+            SpendingLimit::pack(spending_limit, &mut spending_limit_info.data.borrow_mut())?;
+        }
+
+        Ok(())
+    }
 }
 
 /// Helper function to mostly delete an account in a test environment.  We could
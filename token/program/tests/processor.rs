@@ -5,7 +5,8 @@ use {
     serial_test::serial,
     solana_account::{create_account_for_test, Account as SolanaAccount, ReadableAccount},
     solana_account_info::{AccountInfo, IntoAccountInfo},
-    solana_instruction::Instruction,
+    solana_instruction::{AccountMeta, BorrowedAccountMeta, BorrowedInstruction, Instruction},
+    solana_instructions_sysvar::construct_instructions_data,
     solana_program_error::{ProgramError, ProgramResult},
     solana_program_option::COption,
     solana_program_pack::Pack,
@@ -15,14 +16,20 @@ use {
     spl_token_interface::{
         error::TokenError,
         instruction::{
-            amount_to_ui_amount, approve, approve_checked, burn, burn_checked, close_account,
-            freeze_account, get_account_data_size, initialize_account, initialize_account2,
-            initialize_account3, initialize_immutable_owner, initialize_mint, initialize_mint2,
-            initialize_multisig, initialize_multisig2, mint_to, mint_to_checked, revoke,
-            set_authority, sync_native, thaw_account, transfer, transfer_checked,
-            ui_amount_to_amount, AuthorityType, MAX_SIGNERS,
+            amount_to_ui_amount, approve, approve_checked, approve_with_expiry,
+            approve_with_spending_limit, batch, burn, burn_and_close, burn_checked, close_account,
+            close_delegation, freeze_account, get_account_data_size, initialize_account,
+            initialize_account2, initialize_account3, initialize_immutable_owner, initialize_mint,
+            initialize_mint2, initialize_multisig, initialize_multisig2, mint_to, mint_to_checked,
+            pause_mint, resume_mint, revoke, set_authority, set_memo_required, sweep_and_close,
+            sync_native, thaw_account, transfer, transfer_checked, transfer_many,
+            ui_amount_to_amount, unwrap_lamports, withdraw_excess_lamports, AuthorityType,
+            TokenInstruction, MAX_SIGNERS,
+        },
+        state::{
+            is_memo_required_account, is_paused_mint, Account, AccountState, Delegation, Expiry,
+            Mint, MintPause, Multisig, SpendingLimit, PAUSED_MINT_FREEZE_AUTHORITY,
         },
-        state::{Account, AccountState, Mint, Multisig},
     },
     std::collections::HashMap,
 };
@@ -115,6 +122,35 @@ fn rent_sysvar() -> SolanaAccount {
     create_account_for_test(&Rent::default())
 }
 
+fn instructions_sysvar(instructions: &[&Instruction], current_index: u16) -> SolanaAccount {
+    let instructions = instructions
+        .iter()
+        .map(|instruction| BorrowedInstruction {
+            program_id: &instruction.program_id,
+            accounts: instruction
+                .accounts
+                .iter()
+                .map(|meta| BorrowedAccountMeta {
+                    pubkey: &meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: &instruction.data,
+        })
+        .collect::<Vec<_>>();
+    let mut data = construct_instructions_data(&instructions);
+    let len = data.len();
+    data[len - 2..].copy_from_slice(&current_index.to_le_bytes());
+
+    SolanaAccount {
+        lamports: 1,
+        data,
+        owner: solana_sdk_ids::sysvar::id(),
+        ..SolanaAccount::default()
+    }
+}
+
 fn mint_minimum_balance() -> u64 {
     Rent::default().minimum_balance(Mint::get_packed_len())
 }
@@ -127,6 +163,18 @@ fn multisig_minimum_balance() -> u64 {
     Rent::default().minimum_balance(Multisig::get_packed_len())
 }
 
+fn delegation_minimum_balance() -> u64 {
+    Rent::default().minimum_balance(Delegation::get_packed_len())
+}
+
+fn spending_limit_minimum_balance() -> u64 {
+    Rent::default().minimum_balance(SpendingLimit::get_packed_len())
+}
+
+fn mint_pause_minimum_balance() -> u64 {
+    Rent::default().minimum_balance(MintPause::get_packed_len())
+}
+
 #[test]
 fn test_initialize_mint() {
     let program_id = spl_token_interface::id();
@@ -6273,3 +6321,2601 @@ fn test_ui_amount_to_amount() {
         )
     );
 }
+
+#[test]
+fn test_withdraw_excess_lamports() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account = SolanaAccount::new(
+        mint_minimum_balance() + 42,
+        Mint::get_packed_len(),
+        &program_id,
+    );
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance() + 42,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let native_account_key = Pubkey::new_unique();
+    let mut native_account = SolanaAccount::new(
+        account_minimum_balance() + 42,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(
+            &program_id,
+            &native_account_key,
+            &spl_token_interface::native_mint::id(),
+            &owner_key,
+        )
+        .unwrap(),
+        vec![
+            &mut native_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // fail native account
+    assert_eq!(
+        Err(TokenError::NativeNotSupported.into()),
+        do_process_instruction(
+            withdraw_excess_lamports(
+                &program_id,
+                &native_account_key,
+                &destination_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut native_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::NativeNotSupported.into())],
+        )
+    );
+
+    // fail wrong owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            withdraw_excess_lamports(
+                &program_id,
+                &account_key,
+                &destination_key,
+                &owner2_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut destination_account,
+                &mut owner2_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // withdraw from token account
+    do_process_instruction(
+        withdraw_excess_lamports(&program_id, &account_key, &destination_key, &owner_key, &[])
+            .unwrap(),
+        vec![
+            &mut account_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key)
+                .lamports(account_minimum_balance())
+                .build(),
+            Check::account(&destination_key).lamports(42).build(),
+        ],
+    )
+    .unwrap();
+
+    // withdraw from mint
+    do_process_instruction(
+        withdraw_excess_lamports(&program_id, &mint_key, &destination_key, &owner_key, &[])
+            .unwrap(),
+        vec![
+            &mut mint_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint_key)
+                .lamports(mint_minimum_balance())
+                .build(),
+            Check::account(&destination_key).lamports(84).build(),
+        ],
+    )
+    .unwrap();
+
+    // fail not rent exempt
+    account_account.lamports = account_minimum_balance() - 1;
+    assert_eq!(
+        Err(TokenError::NotRentExempt.into()),
+        do_process_instruction(
+            withdraw_excess_lamports(&program_id, &account_key, &destination_key, &owner_key, &[],)
+                .unwrap(),
+            vec![
+                &mut account_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::NotRentExempt.into())],
+        )
+    );
+}
+
+#[test]
+fn test_withdraw_excess_lamports_from_multisig() {
+    let program_id = spl_token_interface::id();
+    let multisig_key = Pubkey::new_unique();
+    let mut multisig_account = SolanaAccount::new(
+        multisig_minimum_balance() + 42,
+        Multisig::get_packed_len(),
+        &program_id,
+    );
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    let signer_keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let mut signer_accounts = [SolanaAccount::default(), SolanaAccount::default()];
+    let mut rent_sysvar = rent_sysvar();
+
+    do_process_instruction(
+        initialize_multisig(
+            &program_id,
+            &multisig_key,
+            &[&signer_keys[0], &signer_keys[1]],
+            2,
+        )
+        .unwrap(),
+        vec![
+            &mut multisig_account,
+            &mut rent_sysvar,
+            &mut signer_accounts[0].clone(),
+            &mut signer_accounts[1].clone(),
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    let multisig_info: AccountInfo = (&multisig_key, false, &mut multisig_account).into();
+    let destination_info: AccountInfo = (&destination_key, false, &mut destination_account).into();
+    let [signer0_account, signer1_account] = &mut signer_accounts;
+    let signer0_info: AccountInfo = (&signer_keys[0], true, signer0_account).into();
+    let signer1_info: AccountInfo = (&signer_keys[1], true, signer1_account).into();
+
+    // fail not enough signers
+    assert_eq!(
+        Err(ProgramError::MissingRequiredSignature),
+        do_process_instruction_dups(
+            withdraw_excess_lamports(
+                &program_id,
+                &multisig_key,
+                &destination_key,
+                &multisig_key,
+                &[&signer_keys[0]],
+            )
+            .unwrap(),
+            vec![
+                multisig_info.clone(),
+                destination_info.clone(),
+                signer0_info.clone(),
+            ],
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        )
+    );
+
+    // withdraw with all signers
+    do_process_instruction_dups(
+        withdraw_excess_lamports(
+            &program_id,
+            &multisig_key,
+            &destination_key,
+            &multisig_key,
+            &[&signer_keys[0], &signer_keys[1]],
+        )
+        .unwrap(),
+        vec![
+            multisig_info.clone(),
+            destination_info.clone(),
+            signer0_info.clone(),
+            signer1_info.clone(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&multisig_key)
+                .lamports(multisig_minimum_balance())
+                .build(),
+            Check::account(&destination_key).lamports(42).build(),
+        ],
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_unwrap_lamports() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let native_account_key = Pubkey::new_unique();
+    let mut native_account = SolanaAccount::new(
+        account_minimum_balance() + 100,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(
+            &program_id,
+            &native_account_key,
+            &spl_token_interface::native_mint::id(),
+            &owner_key,
+        )
+        .unwrap(),
+        vec![
+            &mut native_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // fail non-native account
+    assert_eq!(
+        Err(TokenError::NonNativeNotSupported.into()),
+        do_process_instruction(
+            unwrap_lamports(
+                &program_id,
+                &account_key,
+                &destination_key,
+                &owner_key,
+                &[],
+                None,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::NonNativeNotSupported.into())],
+        )
+    );
+
+    // fail wrong owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            unwrap_lamports(
+                &program_id,
+                &native_account_key,
+                &destination_key,
+                &owner2_key,
+                &[],
+                None,
+            )
+            .unwrap(),
+            vec![
+                &mut native_account,
+                &mut destination_account,
+                &mut owner2_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // fail insufficient funds
+    assert_eq!(
+        Err(TokenError::InsufficientFunds.into()),
+        do_process_instruction(
+            unwrap_lamports(
+                &program_id,
+                &native_account_key,
+                &destination_key,
+                &owner_key,
+                &[],
+                Some(101),
+            )
+            .unwrap(),
+            vec![
+                &mut native_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::InsufficientFunds.into())],
+        )
+    );
+
+    // unwrap partial amount
+    do_process_instruction(
+        unwrap_lamports(
+            &program_id,
+            &native_account_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            Some(40),
+        )
+        .unwrap(),
+        vec![
+            &mut native_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&native_account_key)
+                .lamports(account_minimum_balance() + 60)
+                .data_slice(64, &60u64.to_le_bytes())
+                .build(),
+            Check::account(&destination_key).lamports(40).build(),
+        ],
+    )
+    .unwrap();
+
+    // unwrap remaining amount
+    do_process_instruction(
+        unwrap_lamports(
+            &program_id,
+            &native_account_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            None,
+        )
+        .unwrap(),
+        vec![
+            &mut native_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&native_account_key)
+                .lamports(account_minimum_balance())
+                .data_slice(64, &0u64.to_le_bytes())
+                .build(),
+            Check::account(&destination_key).lamports(100).build(),
+        ],
+    )
+    .unwrap();
+
+    // fail zero amount, not owned by program
+    native_account.owner = Pubkey::new_unique();
+    assert_eq!(
+        Err(ProgramError::IncorrectProgramId),
+        do_process_instruction(
+            unwrap_lamports(
+                &program_id,
+                &native_account_key,
+                &destination_key,
+                &owner_key,
+                &[],
+                None,
+            )
+            .unwrap(),
+            vec![
+                &mut native_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(ProgramError::IncorrectProgramId)],
+        )
+    );
+}
+
+#[test]
+fn test_transfer_many() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let mint2_key = Pubkey::new_unique();
+    let mut mint2_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account3_key = Pubkey::new_unique();
+    let mut account3_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let mismatch_key = Pubkey::new_unique();
+    let mut mismatch_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mints and accounts
+    for (key, account) in [
+        (&mint_key, &mut mint_account),
+        (&mint2_key, &mut mint2_account),
+    ] {
+        do_process_instruction(
+            initialize_mint(&program_id, key, &owner_key, None, 2).unwrap(),
+            vec![account, &mut rent_sysvar],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+        (&account3_key, &mut account3_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        initialize_account(&program_id, &mismatch_key, &mint2_key, &owner_key).unwrap(),
+        vec![
+            &mut mismatch_account,
+            &mut mint2_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // missing destination
+    let mut instruction = transfer_many(
+        &program_id,
+        &account_key,
+        &[(&account2_key, 100), (&account3_key, 200)],
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+    instruction.accounts.truncate(3);
+    assert_eq!(
+        Err(ProgramError::NotEnoughAccountKeys),
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut account_account,
+                &mut owner_account,
+                &mut account2_account,
+            ],
+            &[Check::err(ProgramError::NotEnoughAccountKeys)],
+        )
+    );
+
+    // mismatch mint
+    assert_eq!(
+        Err(TokenError::MintMismatch.into()),
+        do_process_instruction(
+            transfer_many(
+                &program_id,
+                &account_key,
+                &[(&account2_key, 100), (&mismatch_key, 200)],
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut owner_account,
+                &mut account2_account,
+                &mut mismatch_account,
+            ],
+            &[Check::err(TokenError::MintMismatch.into())],
+        )
+    );
+
+    // missing owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            transfer_many(
+                &program_id,
+                &account_key,
+                &[(&account2_key, 100), (&account3_key, 200)],
+                &owner2_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut owner2_account,
+                &mut account2_account,
+                &mut account3_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // insufficient funds
+    assert_eq!(
+        Err(TokenError::InsufficientFunds.into()),
+        do_process_instruction(
+            transfer_many(
+                &program_id,
+                &account_key,
+                &[(&account2_key, 600), (&account3_key, 401)],
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut owner_account,
+                &mut account2_account,
+                &mut account3_account,
+            ],
+            &[Check::err(TokenError::InsufficientFunds.into())],
+        )
+    );
+
+    // transfer to both destinations
+    do_process_instruction(
+        transfer_many(
+            &program_id,
+            &account_key,
+            &[(&account2_key, 100), (&account3_key, 200)],
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut owner_account,
+            &mut account2_account,
+            &mut account3_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.amount, 700);
+    let account = Account::unpack_unchecked(&account2_account.data).unwrap();
+    assert_eq!(account.amount, 100);
+    let account = Account::unpack_unchecked(&account3_account.data).unwrap();
+    assert_eq!(account.amount, 200);
+
+    // approve delegate and transfer the full delegated amount
+    do_process_instruction(
+        approve(&program_id, &account_key, &owner2_key, &owner_key, &[], 300).unwrap(),
+        vec![
+            &mut account_account,
+            &mut owner2_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        transfer_many(
+            &program_id,
+            &account_key,
+            &[(&account2_key, 150), (&account3_key, 150)],
+            &owner2_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut owner2_account,
+            &mut account2_account,
+            &mut account3_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.amount, 400);
+    assert_eq!(account.delegated_amount, 0);
+    assert_eq!(account.delegate, COption::None);
+}
+
+#[test]
+fn test_burn_and_close() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // wrong owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            burn_and_close(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &destination_key,
+                &owner2_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mint_account,
+                &mut destination_account,
+                &mut owner2_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // close authority cannot burn the remaining balance
+    do_process_instruction(
+        set_authority(
+            &program_id,
+            &account_key,
+            Some(&owner2_key),
+            AuthorityType::CloseAccount,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![&mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            burn_and_close(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &destination_key,
+                &owner2_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mint_account,
+                &mut destination_account,
+                &mut owner2_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+    do_process_instruction(
+        set_authority(
+            &program_id,
+            &account_key,
+            None,
+            AuthorityType::CloseAccount,
+            &owner2_key,
+            &[],
+        )
+        .unwrap(),
+        vec![&mut account_account, &mut owner2_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // burn the remaining balance and close the account
+    do_process_instruction(
+        burn_and_close(
+            &program_id,
+            &account_key,
+            &mint_key,
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(account_account.lamports, 0);
+    assert_eq!(destination_account.lamports, account_minimum_balance());
+    let mint = Mint::unpack_unchecked(&mint_account.data).unwrap();
+    assert_eq!(mint.supply, 0);
+
+    // close authority can close an empty account
+    do_process_instruction(
+        set_authority(
+            &program_id,
+            &account2_key,
+            Some(&owner2_key),
+            AuthorityType::CloseAccount,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![&mut account2_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        burn_and_close(
+            &program_id,
+            &account2_key,
+            &mint_key,
+            &destination_key,
+            &owner2_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut account2_account,
+            &mut mint_account,
+            &mut destination_account,
+            &mut owner2_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(account2_account.lamports, 0);
+    assert_eq!(destination_account.lamports, 2 * account_minimum_balance());
+}
+
+#[test]
+fn test_sweep_and_close() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let mint2_key = Pubkey::new_unique();
+    let mut mint2_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let mismatch_key = Pubkey::new_unique();
+    let mut mismatch_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let recipient_key = Pubkey::new_unique();
+    let mut recipient_account = SolanaAccount::default();
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mints and accounts
+    for (key, account) in [
+        (&mint_key, &mut mint_account),
+        (&mint2_key, &mut mint2_account),
+    ] {
+        do_process_instruction(
+            initialize_mint(&program_id, key, &owner_key, None, 2).unwrap(),
+            vec![account, &mut rent_sysvar],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        initialize_account(&program_id, &mismatch_key, &mint2_key, &owner_key).unwrap(),
+        vec![
+            &mut mismatch_account,
+            &mut mint2_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // source and destination are the same account
+    assert_eq!(
+        Err(ProgramError::InvalidAccountData),
+        do_process_instruction(
+            sweep_and_close(
+                &program_id,
+                &account_key,
+                &account_key,
+                &recipient_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account.clone(),
+                &mut account_account,
+                &mut recipient_account,
+                &mut owner_account,
+            ],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        )
+    );
+
+    // mismatch mint
+    assert_eq!(
+        Err(TokenError::MintMismatch.into()),
+        do_process_instruction(
+            sweep_and_close(
+                &program_id,
+                &account_key,
+                &mismatch_key,
+                &recipient_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mismatch_account,
+                &mut recipient_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::MintMismatch.into())],
+        )
+    );
+
+    // missing owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            sweep_and_close(
+                &program_id,
+                &account_key,
+                &account2_key,
+                &recipient_key,
+                &owner2_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut recipient_account,
+                &mut owner2_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // sweep the balance and close the account
+    do_process_instruction(
+        sweep_and_close(
+            &program_id,
+            &account_key,
+            &account2_key,
+            &recipient_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut recipient_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(account_account.lamports, 0);
+    assert_eq!(recipient_account.lamports, account_minimum_balance());
+    let account = Account::unpack_unchecked(&account2_account.data).unwrap();
+    assert_eq!(account.amount, 1000);
+}
+
+#[test]
+fn test_approve_with_expiry() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let delegation_key = Pubkey::new_unique();
+    let mut delegation_account = SolanaAccount::new(
+        delegation_minimum_balance(),
+        Delegation::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // missing owner signature
+    let mut instruction = approve_with_expiry(
+        &program_id,
+        &account_key,
+        &delegation_key,
+        &delegate_key,
+        &owner_key,
+        &[],
+        100,
+        Expiry::Slot(u64::MAX),
+    )
+    .unwrap();
+    instruction.accounts[3].is_signer = false;
+    assert_eq!(
+        Err(ProgramError::MissingRequiredSignature),
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut account_account,
+                &mut delegation_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        )
+    );
+
+    // approve with an expiry in the future
+    do_process_instruction(
+        approve_with_expiry(
+            &program_id,
+            &account_key,
+            &delegation_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+            Expiry::Slot(u64::MAX),
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegation_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::Some(delegation_key));
+    assert_eq!(account.delegated_amount, 100);
+    let delegation = Delegation::unpack(&delegation_account.data).unwrap();
+    assert_eq!(delegation.account, account_key);
+    assert_eq!(delegation.delegate, delegate_key);
+    assert_eq!(delegation.expiry, Expiry::Slot(u64::MAX));
+
+    // delegation account cannot be used for another token account
+    assert_eq!(
+        Err(TokenError::AlreadyInUse.into()),
+        do_process_instruction(
+            approve_with_expiry(
+                &program_id,
+                &account2_key,
+                &delegation_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                100,
+                Expiry::Slot(u64::MAX),
+            )
+            .unwrap(),
+            vec![
+                &mut account2_account,
+                &mut delegation_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::AlreadyInUse.into())],
+        )
+    );
+
+    // missing delegate signer
+    let mut instruction = transfer(
+        &program_id,
+        &account_key,
+        &account2_key,
+        &delegation_key,
+        &[&delegate_key],
+        10,
+    )
+    .unwrap();
+    instruction.accounts[3].is_signer = false;
+    assert_eq!(
+        Err(ProgramError::MissingRequiredSignature),
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut delegation_account,
+                &mut delegate_account,
+            ],
+            &[Check::err(ProgramError::MissingRequiredSignature)],
+        )
+    );
+
+    // delegate transfers through the delegation
+    do_process_instruction(
+        transfer(
+            &program_id,
+            &account_key,
+            &account2_key,
+            &delegation_key,
+            &[&delegate_key],
+            10,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut delegation_account,
+            &mut delegate_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // delegate burns through the delegation
+    do_process_instruction(
+        burn(
+            &program_id,
+            &account_key,
+            &mint_key,
+            &delegation_key,
+            &[&delegate_key],
+            10,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut delegation_account,
+            &mut delegate_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.amount, 980);
+    assert_eq!(account.delegated_amount, 80);
+
+    // re-approve with an expired slot
+    do_process_instruction(
+        approve_with_expiry(
+            &program_id,
+            &account_key,
+            &delegation_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+            Expiry::Slot(0),
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegation_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // delegation has expired
+    assert_eq!(
+        Err(TokenError::DelegationExpired.into()),
+        do_process_instruction(
+            transfer(
+                &program_id,
+                &account_key,
+                &account2_key,
+                &delegation_key,
+                &[&delegate_key],
+                10,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut delegation_account,
+                &mut delegate_account,
+            ],
+            &[Check::err(TokenError::DelegationExpired.into())],
+        )
+    );
+    assert_eq!(
+        Err(TokenError::DelegationExpired.into()),
+        do_process_instruction(
+            burn(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &delegation_key,
+                &[&delegate_key],
+                10,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mint_account,
+                &mut delegation_account,
+                &mut delegate_account,
+            ],
+            &[Check::err(TokenError::DelegationExpired.into())],
+        )
+    );
+
+    // owner can still transfer
+    do_process_instruction(
+        transfer(
+            &program_id,
+            &account_key,
+            &account2_key,
+            &owner_key,
+            &[],
+            10,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // delegation account of another token account cannot be closed
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    assert_eq!(
+        Err(ProgramError::InvalidAccountData),
+        do_process_instruction(
+            close_delegation(
+                &program_id,
+                &account2_key,
+                &delegation_key,
+                &destination_key,
+                &owner_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account2_account,
+                &mut delegation_account,
+                &mut destination_account,
+                &mut owner_account,
+            ],
+            &[Check::err(ProgramError::InvalidAccountData)],
+        )
+    );
+
+    // delegate cannot close the delegation account
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            close_delegation(
+                &program_id,
+                &account_key,
+                &delegation_key,
+                &destination_key,
+                &delegate_key,
+                &[],
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut delegation_account,
+                &mut destination_account,
+                &mut delegate_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // owner closes the delegation account, revoking the delegation
+    do_process_instruction(
+        close_delegation(
+            &program_id,
+            &account_key,
+            &delegation_key,
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut delegation_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&delegation_key).closed().build(),
+            Check::account(&destination_key)
+                .lamports(delegation_minimum_balance())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::None);
+    assert_eq!(account.delegated_amount, 0);
+}
+
+#[test]
+fn test_approve_with_spending_limit() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let spending_limit_key = Pubkey::new_unique();
+    let mut spending_limit_account = SolanaAccount::new(
+        spending_limit_minimum_balance(),
+        SpendingLimit::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // approve a spending limit of 100 tokens per epoch
+    do_process_instruction(
+        approve_with_spending_limit(
+            &program_id,
+            &account_key,
+            &spending_limit_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut spending_limit_account,
+            &mut delegate_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::Some(spending_limit_key));
+    assert_eq!(account.delegated_amount, 100);
+    let spending_limit = SpendingLimit::unpack(&spending_limit_account.data).unwrap();
+    assert_eq!(spending_limit.account, account_key);
+    assert_eq!(spending_limit.delegate, delegate_key);
+    assert_eq!(spending_limit.limit, 100);
+    assert_eq!(spending_limit.spent, 0);
+
+    // spending limit account cannot be used for another token account
+    assert_eq!(
+        Err(TokenError::AlreadyInUse.into()),
+        do_process_instruction(
+            approve_with_spending_limit(
+                &program_id,
+                &account2_key,
+                &spending_limit_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                100,
+            )
+            .unwrap(),
+            vec![
+                &mut account2_account,
+                &mut spending_limit_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::AlreadyInUse.into())],
+        )
+    );
+
+    // delegate transfers within the spending limit
+    let mut instruction = transfer(
+        &program_id,
+        &account_key,
+        &account2_key,
+        &spending_limit_key,
+        &[&delegate_key],
+        60,
+    )
+    .unwrap();
+    instruction.accounts[2].is_writable = true;
+    do_process_instruction(
+        instruction,
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut spending_limit_account,
+            &mut delegate_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let spending_limit = SpendingLimit::unpack(&spending_limit_account.data).unwrap();
+    assert_eq!(spending_limit.spent, 60);
+
+    // self-transfer does not consume the spending limit
+    let mut instruction = transfer(
+        &program_id,
+        &account_key,
+        &account_key,
+        &spending_limit_key,
+        &[&delegate_key],
+        40,
+    )
+    .unwrap();
+    instruction.accounts[2].is_writable = true;
+    do_process_instruction(
+        instruction,
+        vec![
+            &mut account_account.clone(),
+            &mut account_account,
+            &mut spending_limit_account,
+            &mut delegate_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let spending_limit = SpendingLimit::unpack(&spending_limit_account.data).unwrap();
+    assert_eq!(spending_limit.spent, 60);
+
+    // delegate cannot exceed the spending limit
+    let mut instruction = transfer(
+        &program_id,
+        &account_key,
+        &account2_key,
+        &spending_limit_key,
+        &[&delegate_key],
+        50,
+    )
+    .unwrap();
+    instruction.accounts[2].is_writable = true;
+    assert_eq!(
+        Err(TokenError::InsufficientFunds.into()),
+        do_process_instruction(
+            instruction,
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut spending_limit_account,
+                &mut delegate_account,
+            ],
+            &[Check::err(TokenError::InsufficientFunds.into())],
+        )
+    );
+
+    // delegate burns the rest of the spending limit
+    let mut instruction = burn(
+        &program_id,
+        &account_key,
+        &mint_key,
+        &spending_limit_key,
+        &[&delegate_key],
+        40,
+    )
+    .unwrap();
+    instruction.accounts[2].is_writable = true;
+    do_process_instruction(
+        instruction,
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut spending_limit_account,
+            &mut delegate_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.amount, 900);
+    assert_eq!(account.delegate, COption::Some(spending_limit_key));
+    assert_eq!(account.delegated_amount, 100);
+    let spending_limit = SpendingLimit::unpack(&spending_limit_account.data).unwrap();
+    assert_eq!(spending_limit.spent, 100);
+
+    // owner closes the spending limit account, revoking the delegation
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    do_process_instruction(
+        close_delegation(
+            &program_id,
+            &account_key,
+            &spending_limit_key,
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut spending_limit_account,
+            &mut destination_account,
+            &mut owner_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&spending_limit_key).closed().build(),
+            Check::account(&destination_key)
+                .lamports(spending_limit_minimum_balance())
+                .build(),
+        ],
+    )
+    .unwrap();
+    let account = Account::unpack_unchecked(&account_account.data).unwrap();
+    assert_eq!(account.delegate, COption::None);
+    assert_eq!(account.delegated_amount, 0);
+}
+
+#[test]
+fn test_set_memo_required() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let owner2_key = Pubkey::new_unique();
+    let mut owner2_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mint and accounts
+    do_process_instruction(
+        initialize_mint(&program_id, &mint_key, &owner_key, Some(&owner_key), 2).unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        initialize_account(&program_id, &account2_key, &mint_key, &owner2_key).unwrap(),
+        vec![
+            &mut account2_account,
+            &mut mint_account,
+            &mut owner2_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // missing owner
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            set_memo_required(&program_id, &account2_key, &owner_key, &[], true).unwrap(),
+            vec![&mut account2_account, &mut owner_account],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // require memos on incoming transfers
+    do_process_instruction(
+        set_memo_required(&program_id, &account2_key, &owner2_key, &[], true).unwrap(),
+        vec![&mut account2_account, &mut owner2_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert!(is_memo_required_account(&account2_account.data));
+    // the flag does not change the unpacked account
+    let account = Account::unpack(&account2_account.data).unwrap();
+    assert_eq!(account.is_native, COption::None);
+
+    // transfer without the instructions sysvar
+    assert_eq!(
+        Err(ProgramError::NotEnoughAccountKeys),
+        do_process_instruction(
+            transfer(
+                &program_id,
+                &account_key,
+                &account2_key,
+                &owner_key,
+                &[],
+                100
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut owner_account
+            ],
+            &[Check::err(ProgramError::NotEnoughAccountKeys)],
+        )
+    );
+
+    // transfer not preceded by a memo
+    let mut instruction = transfer(
+        &program_id,
+        &account_key,
+        &account2_key,
+        &owner_key,
+        &[],
+        100,
+    )
+    .unwrap();
+    instruction.accounts.push(AccountMeta::new_readonly(
+        solana_instructions_sysvar::id(),
+        false,
+    ));
+    let mut sysvar_account = instructions_sysvar(&[&instruction], 0);
+    assert_eq!(
+        Err(TokenError::NoMemo.into()),
+        do_process_instruction(
+            instruction.clone(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut owner_account,
+                &mut sysvar_account,
+            ],
+            &[Check::err(TokenError::NoMemo.into())],
+        )
+    );
+
+    // transfer preceded by an instruction other than a memo
+    let other = Instruction::new_with_bytes(Pubkey::new_unique(), b"memo", vec![]);
+    let mut sysvar_account = instructions_sysvar(&[&other, &instruction], 1);
+    assert_eq!(
+        Err(TokenError::NoMemo.into()),
+        do_process_instruction(
+            instruction.clone(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut owner_account,
+                &mut sysvar_account,
+            ],
+            &[Check::err(TokenError::NoMemo.into())],
+        )
+    );
+
+    // transfer in a batch preceded by a memo
+    let memo = Instruction::new_with_bytes(spl_token_interface::memo::ID, b"memo", vec![]);
+    let batch_instruction = batch(vec![instruction.clone()]).unwrap();
+    let mut sysvar_account = instructions_sysvar(&[&memo, &batch_instruction], 1);
+    assert_eq!(
+        Err(TokenError::NoMemo.into()),
+        do_process_instruction(
+            batch_instruction,
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut owner_account,
+                &mut sysvar_account,
+            ],
+            &[Check::err(TokenError::NoMemo.into())],
+        )
+    );
+
+    // transfer invoked by another program not preceded by a memo
+    let caller = Instruction::new_with_bytes(Pubkey::new_unique(), b"deposit", vec![]);
+    let mut sysvar_account = instructions_sysvar(&[&other, &caller], 1);
+    assert_eq!(
+        Err(TokenError::NoMemo.into()),
+        do_process_instruction(
+            instruction.clone(),
+            vec![
+                &mut account_account,
+                &mut account2_account,
+                &mut owner_account,
+                &mut sysvar_account,
+            ],
+            &[Check::err(TokenError::NoMemo.into())],
+        )
+    );
+
+    // transfer invoked by another program preceded by a memo
+    let mut sysvar_account = instructions_sysvar(&[&memo, &caller], 1);
+    do_process_instruction(
+        instruction.clone(),
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut owner_account,
+            &mut sysvar_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // transfer preceded by a memo
+    let mut sysvar_account = instructions_sysvar(&[&memo, &instruction], 1);
+    do_process_instruction(
+        instruction,
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut owner_account,
+            &mut sysvar_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    let account = Account::unpack(&account2_account.data).unwrap();
+    assert_eq!(account.amount, 200);
+
+    // several transfers into the account preceded by a single memo
+    let mut instruction = transfer_many(
+        &program_id,
+        &account_key,
+        &[(&account2_key, 100), (&account2_key, 100)],
+        &owner_key,
+        &[],
+    )
+    .unwrap();
+    instruction.accounts.push(AccountMeta::new_readonly(
+        solana_instructions_sysvar::id(),
+        false,
+    ));
+    let sysvar_key = solana_instructions_sysvar::id();
+    let mut sysvar_account = instructions_sysvar(&[&memo, &instruction], 1);
+    {
+        let account_info: AccountInfo = (&account_key, false, &mut account_account).into();
+        let owner_info: AccountInfo = (&owner_key, true, &mut owner_account).into();
+        let account2_info: AccountInfo = (&account2_key, false, &mut account2_account).into();
+        let sysvar_info: AccountInfo = (&sysvar_key, false, &mut sysvar_account).into();
+        assert_eq!(
+            Err(TokenError::NoMemo.into()),
+            do_process_instruction_dups(
+                instruction.clone(),
+                vec![
+                    account_info,
+                    owner_info,
+                    account2_info.clone(),
+                    account2_info,
+                    sysvar_info,
+                ],
+                &[Check::err(TokenError::NoMemo.into())],
+            )
+        );
+    }
+
+    // several transfers into the account, each preceded by its own memo
+    let mut sysvar_account = instructions_sysvar(&[&memo, &memo, &instruction], 2);
+    {
+        let account_info: AccountInfo = (&account_key, false, &mut account_account).into();
+        let owner_info: AccountInfo = (&owner_key, true, &mut owner_account).into();
+        let account2_info: AccountInfo = (&account2_key, false, &mut account2_account).into();
+        let sysvar_info: AccountInfo = (&sysvar_key, false, &mut sysvar_account).into();
+        do_process_instruction_dups(
+            instruction,
+            vec![
+                account_info,
+                owner_info,
+                account2_info.clone(),
+                account2_info,
+                sysvar_info,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    let account = Account::unpack(&account2_account.data).unwrap();
+    assert_eq!(account.amount, 400);
+
+    // transfers from the account do not require a memo
+    do_process_instruction(
+        transfer(
+            &program_id,
+            &account2_key,
+            &account_key,
+            &owner2_key,
+            &[],
+            50,
+        )
+        .unwrap(),
+        vec![
+            &mut account2_account,
+            &mut account_account,
+            &mut owner2_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // no longer require memos
+    do_process_instruction(
+        set_memo_required(&program_id, &account2_key, &owner2_key, &[], false).unwrap(),
+        vec![&mut account2_account, &mut owner2_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert!(!is_memo_required_account(&account2_account.data));
+    do_process_instruction(
+        transfer(
+            &program_id,
+            &account_key,
+            &account2_key,
+            &owner_key,
+            &[],
+            50,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut account2_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // frozen account
+    do_process_instruction(
+        freeze_account(&program_id, &account2_key, &mint_key, &owner_key, &[]).unwrap(),
+        vec![&mut account2_account, &mut mint_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(
+        Err(TokenError::AccountFrozen.into()),
+        do_process_instruction(
+            set_memo_required(&program_id, &account2_key, &owner2_key, &[], true).unwrap(),
+            vec![&mut account2_account, &mut owner2_account],
+            &[Check::err(TokenError::AccountFrozen.into())],
+        )
+    );
+
+    // native account
+    let native_account_key = Pubkey::new_unique();
+    let mut native_account = SolanaAccount::new(
+        account_minimum_balance() + 40,
+        Account::get_packed_len(),
+        &program_id,
+    );
+    do_process_instruction(
+        initialize_account(
+            &program_id,
+            &native_account_key,
+            &spl_token_interface::native_mint::id(),
+            &owner_key,
+        )
+        .unwrap(),
+        vec![
+            &mut native_account,
+            &mut mint_account,
+            &mut owner_account,
+            &mut rent_sysvar,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert_eq!(
+        Err(TokenError::NativeNotSupported.into()),
+        do_process_instruction(
+            set_memo_required(&program_id, &native_account_key, &owner_key, &[], true).unwrap(),
+            vec![&mut native_account, &mut owner_account],
+            &[Check::err(TokenError::NativeNotSupported.into())],
+        )
+    );
+}
+
+#[test]
+fn test_pause_mint() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let freeze_authority_key = Pubkey::new_unique();
+    let mut freeze_authority_account = SolanaAccount::default();
+    let delegate_key = Pubkey::new_unique();
+    let mut delegate_account = SolanaAccount::default();
+    let mint_pause_key = Pubkey::new_unique();
+    let mut mint_pause_account = SolanaAccount::new(
+        mint_pause_minimum_balance(),
+        MintPause::get_packed_len(),
+        &program_id,
+    );
+    let destination_key = Pubkey::new_unique();
+    let mut destination_account = SolanaAccount::default();
+    let mut rent_sysvar = rent_sysvar();
+
+    // initialize mint and accounts
+    do_process_instruction(
+        initialize_mint(
+            &program_id,
+            &mint_key,
+            &owner_key,
+            Some(&freeze_authority_key),
+            2,
+        )
+        .unwrap(),
+        vec![&mut mint_account, &mut rent_sysvar],
+        &[Check::success()],
+    )
+    .unwrap();
+    for (key, account) in [
+        (&account_key, &mut account_account),
+        (&account2_key, &mut account2_account),
+    ] {
+        do_process_instruction(
+            initialize_account(&program_id, key, &mint_key, &owner_key).unwrap(),
+            vec![
+                account,
+                &mut mint_account,
+                &mut owner_account,
+                &mut rent_sysvar,
+            ],
+            &[Check::success()],
+        )
+        .unwrap();
+    }
+    do_process_instruction(
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 1000).unwrap(),
+        vec![&mut mint_account, &mut account_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // resume a mint that is not paused
+    assert_eq!(
+        Err(TokenError::InvalidState.into()),
+        do_process_instruction(
+            resume_mint(
+                &program_id,
+                &mint_key,
+                &mint_pause_key,
+                &destination_key,
+                &freeze_authority_key,
+                &[]
+            )
+            .unwrap(),
+            vec![
+                &mut mint_account,
+                &mut mint_pause_account,
+                &mut destination_account,
+                &mut freeze_authority_account,
+            ],
+            &[Check::err(TokenError::InvalidState.into())],
+        )
+    );
+
+    // missing freeze authority
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            pause_mint(&program_id, &mint_key, &mint_pause_key, &owner_key, &[]).unwrap(),
+            vec![
+                &mut mint_account,
+                &mut mint_pause_account,
+                &mut owner_account
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // the paused freeze authority can only be set by pausing the mint
+    assert_eq!(
+        Err(ProgramError::InvalidArgument),
+        do_process_instruction(
+            set_authority(
+                &program_id,
+                &mint_key,
+                Some(&PAUSED_MINT_FREEZE_AUTHORITY),
+                AuthorityType::FreezeAccount,
+                &freeze_authority_key,
+                &[],
+            )
+            .unwrap(),
+            vec![&mut mint_account, &mut freeze_authority_account],
+            &[Check::err(ProgramError::InvalidArgument)],
+        )
+    );
+
+    // pause mint
+    do_process_instruction(
+        pause_mint(
+            &program_id,
+            &mint_key,
+            &mint_pause_key,
+            &freeze_authority_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut mint_account,
+            &mut mint_pause_account,
+            &mut freeze_authority_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert!(is_paused_mint(&mint_account.data));
+    let mint = Mint::unpack(&mint_account.data).unwrap();
+    assert!(mint.is_paused());
+    assert_eq!(
+        mint.freeze_authority,
+        COption::Some(PAUSED_MINT_FREEZE_AUTHORITY)
+    );
+    let mint_pause = MintPause::unpack(&mint_pause_account.data).unwrap();
+    assert_eq!(mint_pause.mint, mint_key);
+    assert_eq!(mint_pause.freeze_authority, freeze_authority_key);
+
+    // pause a paused mint
+    assert_eq!(
+        Err(TokenError::InvalidState.into()),
+        do_process_instruction(
+            pause_mint(
+                &program_id,
+                &mint_key,
+                &mint_pause_key,
+                &freeze_authority_key,
+                &[]
+            )
+            .unwrap(),
+            vec![
+                &mut mint_account,
+                &mut mint_pause_account,
+                &mut freeze_authority_account,
+            ],
+            &[Check::err(TokenError::InvalidState.into())],
+        )
+    );
+
+    // the freeze authority cannot freeze accounts while the mint is paused
+    assert_eq!(
+        Err(TokenError::OwnerMismatch.into()),
+        do_process_instruction(
+            freeze_account(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &freeze_authority_key,
+                &[]
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mint_account,
+                &mut freeze_authority_account,
+            ],
+            &[Check::err(TokenError::OwnerMismatch.into())],
+        )
+    );
+
+    // instructions that take the mint fail while paused
+    assert_eq!(
+        Err(TokenError::MintPaused.into()),
+        do_process_instruction(
+            transfer_checked(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &account2_key,
+                &owner_key,
+                &[],
+                100,
+                2,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mint_account,
+                &mut account2_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::MintPaused.into())],
+        )
+    );
+    assert_eq!(
+        Err(TokenError::MintPaused.into()),
+        do_process_instruction(
+            mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 100).unwrap(),
+            vec![&mut mint_account, &mut account_account, &mut owner_account],
+            &[Check::err(TokenError::MintPaused.into())],
+        )
+    );
+    assert_eq!(
+        Err(TokenError::MintPaused.into()),
+        do_process_instruction(
+            mint_to_checked(
+                &program_id,
+                &mint_key,
+                &account_key,
+                &owner_key,
+                &[],
+                100,
+                2
+            )
+            .unwrap(),
+            vec![&mut mint_account, &mut account_account, &mut owner_account],
+            &[Check::err(TokenError::MintPaused.into())],
+        )
+    );
+    assert_eq!(
+        Err(TokenError::MintPaused.into()),
+        do_process_instruction(
+            burn(&program_id, &account_key, &mint_key, &owner_key, &[], 100).unwrap(),
+            vec![&mut account_account, &mut mint_account, &mut owner_account],
+            &[Check::err(TokenError::MintPaused.into())],
+        )
+    );
+    assert_eq!(
+        Err(TokenError::MintPaused.into()),
+        do_process_instruction(
+            burn_checked(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &owner_key,
+                &[],
+                100,
+                2
+            )
+            .unwrap(),
+            vec![&mut account_account, &mut mint_account, &mut owner_account],
+            &[Check::err(TokenError::MintPaused.into())],
+        )
+    );
+    assert_eq!(
+        Err(TokenError::MintPaused.into()),
+        do_process_instruction(
+            approve_checked(
+                &program_id,
+                &account_key,
+                &mint_key,
+                &delegate_key,
+                &owner_key,
+                &[],
+                100,
+                2,
+            )
+            .unwrap(),
+            vec![
+                &mut account_account,
+                &mut mint_account,
+                &mut delegate_account,
+                &mut owner_account,
+            ],
+            &[Check::err(TokenError::MintPaused.into())],
+        )
+    );
+
+    // the paused state is preserved when the mint is updated
+    do_process_instruction(
+        set_authority(
+            &program_id,
+            &mint_key,
+            Some(&owner_key),
+            AuthorityType::MintTokens,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        vec![&mut mint_account, &mut owner_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    assert!(is_paused_mint(&mint_account.data));
+
+    // resume mint with the recorded freeze authority, closing the mint pause
+    // account
+    do_process_instruction(
+        resume_mint(
+            &program_id,
+            &mint_key,
+            &mint_pause_key,
+            &destination_key,
+            &freeze_authority_key,
+            &[],
+        )
+        .unwrap(),
+        vec![
+            &mut mint_account,
+            &mut mint_pause_account,
+            &mut destination_account,
+            &mut freeze_authority_account,
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint_pause_key).closed().build(),
+            Check::account(&destination_key)
+                .lamports(mint_pause_minimum_balance())
+                .build(),
+        ],
+    )
+    .unwrap();
+    assert!(!is_paused_mint(&mint_account.data));
+    let mint = Mint::unpack(&mint_account.data).unwrap();
+    assert_eq!(mint.freeze_authority, COption::Some(freeze_authority_key));
+    do_process_instruction(
+        transfer_checked(
+            &program_id,
+            &account_key,
+            &mint_key,
+            &account2_key,
+            &owner_key,
+            &[],
+            100,
+            2,
+        )
+        .unwrap(),
+        vec![
+            &mut account_account,
+            &mut mint_account,
+            &mut account2_account,
+            &mut owner_account,
+        ],
+        &[Check::success()],
+    )
+    .unwrap();
+
+    // mint without a freeze authority cannot be paused
+    do_process_instruction(
+        set_authority(
+            &program_id,
+            &mint_key,
+            None,
+            AuthorityType::FreezeAccount,
+            &freeze_authority_key,
+            &[],
+        )
+        .unwrap(),
+        vec![&mut mint_account, &mut freeze_authority_account],
+        &[Check::success()],
+    )
+    .unwrap();
+    let mut mint_pause_account = SolanaAccount::new(
+        mint_pause_minimum_balance(),
+        MintPause::get_packed_len(),
+        &program_id,
+    );
+    assert_eq!(
+        Err(TokenError::MintCannotFreeze.into()),
+        do_process_instruction(
+            pause_mint(
+                &program_id,
+                &mint_key,
+                &mint_pause_key,
+                &freeze_authority_key,
+                &[]
+            )
+            .unwrap(),
+            vec![
+                &mut mint_account,
+                &mut mint_pause_account,
+                &mut freeze_authority_account,
+            ],
+            &[Check::err(TokenError::MintCannotFreeze.into())],
+        )
+    );
+}
+
+#[test]
+fn test_batch() {
+    let program_id = spl_token_interface::id();
+    let mint_key = Pubkey::new_unique();
+    let mut mint_account =
+        SolanaAccount::new(mint_minimum_balance(), Mint::get_packed_len(), &program_id);
+    let account_key = Pubkey::new_unique();
+    let mut account_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let account2_key = Pubkey::new_unique();
+    let mut account2_account = SolanaAccount::new(
+        account_minimum_balance(),
+        Account::get_packed_len(),
+        &program_id,
+    );
+    let owner_key = Pubkey::new_unique();
+    let mut owner_account = SolanaAccount::default();
+    let mint_info: AccountInfo = (&mint_key, false, &mut mint_account).into();
+    let account_info: AccountInfo = (&account_key, false, &mut account_account).into();
+    let account2_info: AccountInfo = (&account2_key, false, &mut account2_account).into();
+    let owner_info: AccountInfo = (&owner_key, true, &mut owner_account).into();
+
+    // initialize mint and accounts, mint and transfer in a single batch
+    let instruction = batch(vec![
+        initialize_mint2(&program_id, &mint_key, &owner_key, None, 2).unwrap(),
+        initialize_account3(&program_id, &account_key, &mint_key, &owner_key).unwrap(),
+        initialize_account3(&program_id, &account2_key, &mint_key, &owner_key).unwrap(),
+        mint_to(&program_id, &mint_key, &account_key, &owner_key, &[], 42).unwrap(),
+        transfer(
+            &program_id,
+            &account_key,
+            &account2_key,
+            &owner_key,
+            &[],
+            40,
+        )
+        .unwrap(),
+    ])
+    .unwrap();
+    do_process_instruction_dups(
+        instruction,
+        vec![
+            mint_info.clone(),
+            account_info.clone(),
+            account2_info.clone(),
+            owner_info.clone(),
+        ],
+        &[
+            Check::success(),
+            Check::account(&mint_key)
+                .data_slice(36, &42u64.to_le_bytes())
+                .build(),
+            Check::account(&account_key)
+                .data_slice(64, &2u64.to_le_bytes())
+                .build(),
+            Check::account(&account2_key)
+                .data_slice(64, &40u64.to_le_bytes())
+                .build(),
+        ],
+    )
+    .unwrap();
+
+    // fail nested batch
+    let nested = batch(vec![transfer(
+        &program_id,
+        &account_key,
+        &account2_key,
+        &owner_key,
+        &[],
+        1,
+    )
+    .unwrap()])
+    .unwrap();
+    assert_eq!(
+        Err(TokenError::InvalidInstruction.into()),
+        do_process_instruction_dups(
+            Instruction {
+                program_id,
+                accounts: nested.accounts.clone(),
+                data: [&[255, 3, nested.data.len() as u8][..], &nested.data].concat(),
+            },
+            vec![
+                account_info.clone(),
+                account2_info.clone(),
+                owner_info.clone()
+            ],
+            &[Check::err(TokenError::InvalidInstruction.into())],
+        )
+    );
+
+    // fail transfer from an account not owned by the program
+    account_info.assign(&Pubkey::new_unique());
+    assert_eq!(
+        Err(ProgramError::IncorrectProgramId),
+        do_process_instruction_dups(
+            batch(vec![transfer(
+                &program_id,
+                &account_key,
+                &account2_key,
+                &owner_key,
+                &[],
+                1
+            )
+            .unwrap()])
+            .unwrap(),
+            vec![
+                account_info.clone(),
+                account2_info.clone(),
+                owner_info.clone()
+            ],
+            &[Check::err(ProgramError::IncorrectProgramId)],
+        )
+    );
+    account_info.assign(&program_id);
+
+    // fail empty batch
+    assert_eq!(
+        Err(TokenError::InvalidInstruction.into()),
+        do_process_instruction_dups(
+            Instruction {
+                program_id,
+                accounts: vec![],
+                data: TokenInstruction::Batch { data: &[] }.pack(),
+            },
+            vec![],
+            &[Check::err(TokenError::InvalidInstruction.into())],
+        )
+    );
+}
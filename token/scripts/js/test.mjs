@@ -2,8 +2,8 @@
 import 'zx/globals';
 import { cliArguments, workingDirectory } from '../utils.mjs';
 
-// Start the local validator, or restart it if it is already running.
-await $`pnpm validator:restart`;
+// Start the local validator with p-token, or restart it if it is already running.
+await $`pnpm validator:restart --p-token`;
 
 // Build the client and run the tests.
 cd(path.join(workingDirectory, 'clients', 'js'));
@@ -0,0 +1,42 @@
+#!/usr/bin/env zx
+import 'zx/globals';
+import { cliArguments, workingDirectory } from '../utils.mjs';
+
+// Directory of the SPL Token program used as the reference.
+const SPL_TOKEN_DIR = path.join(workingDirectory, 'program');
+// Directory of the SBF programs.
+const SBF_OUTPUT_DIR = path.join(workingDirectory, 'target', 'deploy');
+// Directory of the MVP program.
+const MVP_DIR = path.join(workingDirectory, '..', 'token_mvp', 'program');
+// Directory of the MVP SBF program.
+const MVP_SBF_OUTPUT_DIR = path.join(MVP_DIR, '..', 'target', 'deploy');
+// Directory where the MVP tests load the reference program from.
+const MVP_FIXTURES_DIR = path.join(MVP_DIR, 'tests', 'fixtures');
+
+const [command, ...args] = cliArguments();
+
+switch (command) {
+  case 'build':
+    await build(args);
+    break;
+  case 'test':
+    await test(args);
+    break;
+  default:
+    throw new Error(`Unknown command: ${command}`);
+}
+
+async function build(args) {
+  await $`cargo-build-sbf --manifest-path ${path.join(MVP_DIR, 'Cargo.toml')} ${args}`;
+
+  // Both programs are named `spl_token`, so the reference program is copied
+  // under the name the MVP tests expect.
+  await $`cargo-build-sbf --manifest-path ${path.join(SPL_TOKEN_DIR, 'Cargo.toml')} ${args}`;
+  await $`mkdir -p ${MVP_FIXTURES_DIR}`;
+  await $`cp ${path.join(SBF_OUTPUT_DIR, 'spl_token.so')} ${path.join(MVP_FIXTURES_DIR, 'spl_token_reference.so')}`;
+}
+
+async function test(args) {
+  const manifestPath = path.join(MVP_DIR, 'Cargo.toml');
+  await $`RUST_LOG=error SBF_OUT_DIR=${MVP_SBF_OUTPUT_DIR} cargo test --manifest-path ${manifestPath} ${args}`;
+}
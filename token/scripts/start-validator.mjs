@@ -15,6 +15,7 @@ await $`pnpm solana:check`;
 
 // Options and arguments.
 const restart = argv['restart'];
+const pToken = argv['p-token'];
 
 // Keep the validator running when not using the restart flag.
 const isValidatorRunning = (await $`lsof -t -i:8899`.quiet().exitCode) === 0;
@@ -98,7 +99,11 @@ try {
 
 function getPrograms() {
   const binaryDir = path.join(__dirname, '..', 'target', 'deploy');
-  return getProgramFolders().map((folder) => {
+  // Load p-token in place of the token program when requested.
+  const folders = getProgramFolders().map((folder) =>
+    pToken && folder === 'program' ? 'pinocchio/program' : folder
+  );
+  return folders.map((folder) => {
     const cargo = getCargo(folder);
     const name = cargo.package.name.replace(/-/g, '_');
     return {
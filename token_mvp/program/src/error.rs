@@ -38,6 +38,34 @@ pub enum TokenError {
     /// Not rent exempt
     #[error("Not rent exempt")]
     NotRentExempt,
+    
+    /// Mint cannot freeze accounts
+    #[error("This token mint cannot freeze accounts")]
+    MintCannotFreeze,
+    
+    /// Account is frozen
+    #[error("Account is frozen")]
+    AccountFrozen,
+    
+    /// Account still holds tokens
+    #[error("Non-native account can only be closed if its balance is zero")]
+    NonNativeHasBalance,
+    
+    /// Invalid account state for operation
+    #[error("Invalid account state for operation")]
+    InvalidState,
+    
+    /// Invalid number of provided signers
+    #[error("Invalid number of provided signers")]
+    InvalidNumberOfProvidedSigners,
+    
+    /// Invalid number of required signers
+    #[error("Invalid number of required signers")]
+    InvalidNumberOfRequiredSigners,
+    
+    /// Fixed supply
+    #[error("Fixed supply")]
+    FixedSupply,
 }
 
 impl From<TokenError> for ProgramError {
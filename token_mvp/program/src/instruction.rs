@@ -1,42 +1,924 @@
-
 use {
-    solana_pubkey::Pubkey,
+    crate::check_id,
+    solana_instruction::{AccountMeta, Instruction},
+    solana_program_error::ProgramError,
+    solana_pubkey::{Pubkey, PUBKEY_BYTES},
+    solana_sysvar::rent,
 };
 
+/// Minimum number of multisignature signers (min N)
+pub const MIN_SIGNERS: usize = 1;
+/// Maximum number of multisignature signers (max N)
+pub const MAX_SIGNERS: usize = 11;
+
 /// Instructions supported by the token program.
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenInstruction {
     /// Initialize a new mint
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint to initialize.
+    ///   1. `[]` Rent sysvar
     InitializeMint {
         /// Number of base 10 digits to the right of the decimal place
         decimals: u8,
         /// Authority that can mint new tokens
         mint_authority: Pubkey,
+        /// Optional authority that can freeze token accounts
+        freeze_authority: Option<Pubkey>,
     },
     /// Initialize a new token account
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to initialize.
+    ///   1. `[]` The mint this account will be associated with.
+    ///   2. `[]` The new account's owner.
+    ///   3. `[]` Rent sysvar
     InitializeAccount,
+    /// Initialize a multisignature account with N provided signers
+    ///
+    /// Multisignature accounts can be used in place of the owner, delegate,
+    /// mint authority or freeze authority of any instruction that requires
+    /// one, as long as M of the N signers sign the transaction.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The multisignature account to initialize.
+    ///   1. `[]` Rent sysvar
+    ///   2. ..`2+N`. `[]` The signer accounts, where `1 <= N <= 11`.
+    InitializeMultisig {
+        /// The number of signers (M) required to validate this multisignature
+        /// account
+        m: u8,
+    },
     /// Transfer tokens from one account to another
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The source account.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The source account's owner.
+    ///   3. ..`3+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
     Transfer {
         /// Amount of tokens to transfer
         amount: u64,
     },
+    /// Approve a delegate to transfer or burn up to `amount` tokens
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The source account.
+    ///   1. `[]` The delegate.
+    ///   2. `[signer]` The source account's owner.
+    ///   3. ..`3+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
+    Approve {
+        /// Amount of tokens the delegate is approved for
+        amount: u64,
+    },
+    /// Revoke the delegate's authority
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The source account.
+    ///   1. `[signer]` The source account's owner.
+    ///   2. ..`2+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
+    Revoke,
     /// Mint new tokens to an account
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The mint.
+    ///   1. `[writable]` The account to mint tokens to.
+    ///   2. `[signer]` The mint's minting authority.
+    ///   3. ..`3+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
     MintTo {
         /// Amount of tokens to mint
         amount: u64,
     },
     /// Burn tokens from an account
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to burn from.
+    ///   1. `[writable]` The token mint.
+    ///   2. `[signer]` The account's owner.
+    ///   3. ..`3+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
     Burn {
         /// Amount of tokens to burn
         amount: u64,
     },
+    /// Close an account with a zero balance, transferring its lamports to
+    /// the destination
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to close.
+    ///   1. `[writable]` The destination account.
+    ///   2. `[signer]` The account's close authority, or owner if unset.
+    ///   3. ..`3+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
+    CloseAccount,
+    /// Freeze an account using the mint's freeze authority
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to freeze.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint freeze authority.
+    ///   3. ..`3+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
+    FreezeAccount,
+    /// Thaw a frozen account using the mint's freeze authority
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    ///   0. `[writable]` The account to thaw.
+    ///   1. `[]` The token mint.
+    ///   2. `[signer]` The mint freeze authority.
+    ///   3. ..`3+M`. `[signer]` M signer accounts, when the authority is a
+    ///      multisignature account.
+    ThawAccount,
 }
 
-
 impl TokenInstruction {
     /// Unpacks a byte buffer into a TokenInstruction
-    pub fn unpack(_input: &[u8]) -> Result<Self, solana_program_error::ProgramError> {
-        // TODO: Implement instruction unpacking
-        Err(solana_program_error::ProgramError::InvalidInstructionData)
+    ///
+    /// The instruction data follows the SPL Token encoding: a one byte
+    /// discriminator followed by the instruction arguments.
+    pub fn unpack(input: &[u8]) -> Result<Self, ProgramError> {
+        let (&tag, rest) = input
+            .split_first()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        Ok(match tag {
+            0 => {
+                let (&decimals, rest) = rest
+                    .split_first()
+                    .ok_or(ProgramError::InvalidInstructionData)?;
+                let (mint_authority, rest) = Self::unpack_pubkey(rest)?;
+                let freeze_authority = Self::unpack_pubkey_option(rest)?;
+                Self::InitializeMint {
+                    decimals,
+                    mint_authority,
+                    freeze_authority,
+                }
+            }
+            1 => Self::InitializeAccount,
+            2 => {
+                let &m = rest.first().ok_or(ProgramError::InvalidInstructionData)?;
+                Self::InitializeMultisig { m }
+            }
+            3 | 4 | 7 | 8 => {
+                let amount = Self::unpack_u64(rest)?;
+                match tag {
+                    3 => Self::Transfer { amount },
+                    4 => Self::Approve { amount },
+                    7 => Self::MintTo { amount },
+                    8 => Self::Burn { amount },
+                    _ => unreachable!(),
+                }
+            }
+            5 => Self::Revoke,
+            9 => Self::CloseAccount,
+            10 => Self::FreezeAccount,
+            11 => Self::ThawAccount,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        })
+    }
+
+    /// Packs a TokenInstruction into a byte buffer.
+    pub fn pack(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(size_of::<Self>());
+        match self {
+            &Self::InitializeMint {
+                decimals,
+                ref mint_authority,
+                ref freeze_authority,
+            } => {
+                buf.push(0);
+                buf.push(decimals);
+                buf.extend_from_slice(mint_authority.as_ref());
+                Self::pack_pubkey_option(freeze_authority, &mut buf);
+            }
+            Self::InitializeAccount => buf.push(1),
+            &Self::InitializeMultisig { m } => {
+                buf.push(2);
+                buf.push(m);
+            }
+            &Self::Transfer { amount } => {
+                buf.push(3);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::Approve { amount } => {
+                buf.push(4);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::Revoke => buf.push(5),
+            &Self::MintTo { amount } => {
+                buf.push(7);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            &Self::Burn { amount } => {
+                buf.push(8);
+                buf.extend_from_slice(&amount.to_le_bytes());
+            }
+            Self::CloseAccount => buf.push(9),
+            Self::FreezeAccount => buf.push(10),
+            Self::ThawAccount => buf.push(11),
+        };
+        buf
+    }
+
+    fn unpack_pubkey(input: &[u8]) -> Result<(Pubkey, &[u8]), ProgramError> {
+        if input.len() >= PUBKEY_BYTES {
+            let (key, rest) = input.split_at(PUBKEY_BYTES);
+            let pk = Pubkey::try_from(key).map_err(|_| ProgramError::InvalidInstructionData)?;
+            Ok((pk, rest))
+        } else {
+            Err(ProgramError::InvalidInstructionData)
+        }
+    }
+
+    fn unpack_pubkey_option(input: &[u8]) -> Result<Option<Pubkey>, ProgramError> {
+        match input.split_first() {
+            Some((&0, _)) => Ok(None),
+            Some((&1, rest)) => Self::unpack_pubkey(rest).map(|(key, _)| Some(key)),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+
+    fn pack_pubkey_option(value: &Option<Pubkey>, buf: &mut Vec<u8>) {
+        match value {
+            Some(key) => {
+                buf.push(1);
+                buf.extend_from_slice(key.as_ref());
+            }
+            None => buf.push(0),
+        }
+    }
+
+    fn unpack_u64(input: &[u8]) -> Result<u64, ProgramError> {
+        input
+            .get(..8)
+            .and_then(|slice| slice.try_into().ok())
+            .map(u64::from_le_bytes)
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
+
+fn check_program_account(token_program_id: &Pubkey) -> Result<(), ProgramError> {
+    if !check_id(token_program_id) {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    Ok(())
+}
+
+/// Creates an `InitializeMint` instruction.
+pub fn initialize_mint(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    mint_authority_pubkey: &Pubkey,
+    freeze_authority_pubkey: Option<&Pubkey>,
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::InitializeMint {
+        decimals,
+        mint_authority: *mint_authority_pubkey,
+        freeze_authority: freeze_authority_pubkey.cloned(),
+    }
+    .pack();
+
+    let accounts = vec![
+        AccountMeta::new(*mint_pubkey, false),
+        AccountMeta::new_readonly(rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitializeAccount` instruction.
+pub fn initialize_account(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::InitializeAccount.pack();
+
+    let accounts = vec![
+        AccountMeta::new(*account_pubkey, false),
+        AccountMeta::new_readonly(*mint_pubkey, false),
+        AccountMeta::new_readonly(*owner_pubkey, false),
+        AccountMeta::new_readonly(rent::id(), false),
+    ];
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `InitializeMultisig` instruction.
+pub fn initialize_multisig(
+    token_program_id: &Pubkey,
+    multisig_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    m: u8,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    if !is_valid_signer_index(m as usize)
+        || !is_valid_signer_index(signer_pubkeys.len())
+        || m as usize > signer_pubkeys.len()
+    {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    let data = TokenInstruction::InitializeMultisig { m }.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*multisig_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(rent::id(), false));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, false));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `Transfer` instruction.
+pub fn transfer(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::Transfer { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an `Approve` instruction.
+pub fn approve(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    delegate_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::Approve { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*delegate_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `Revoke` instruction.
+pub fn revoke(
+    token_program_id: &Pubkey,
+    source_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::Revoke.pack();
+
+    let mut accounts = Vec::with_capacity(2 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*source_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `MintTo` instruction.
+pub fn mint_to(
+    token_program_id: &Pubkey,
+    mint_pubkey: &Pubkey,
+    account_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::MintTo { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `Burn` instruction.
+pub fn burn(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    authority_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+    amount: u64,
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::Burn { amount }.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *authority_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
     }
-}
\ No newline at end of file
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `CloseAccount` instruction.
+pub fn close_account(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    destination_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::CloseAccount.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new(*destination_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `FreezeAccount` instruction.
+pub fn freeze_account(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::FreezeAccount.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Creates a `ThawAccount` instruction.
+pub fn thaw_account(
+    token_program_id: &Pubkey,
+    account_pubkey: &Pubkey,
+    mint_pubkey: &Pubkey,
+    owner_pubkey: &Pubkey,
+    signer_pubkeys: &[&Pubkey],
+) -> Result<Instruction, ProgramError> {
+    check_program_account(token_program_id)?;
+    let data = TokenInstruction::ThawAccount.pack();
+
+    let mut accounts = Vec::with_capacity(3 + signer_pubkeys.len());
+    accounts.push(AccountMeta::new(*account_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(*mint_pubkey, false));
+    accounts.push(AccountMeta::new_readonly(
+        *owner_pubkey,
+        signer_pubkeys.is_empty(),
+    ));
+    for signer_pubkey in signer_pubkeys.iter() {
+        accounts.push(AccountMeta::new_readonly(**signer_pubkey, true));
+    }
+
+    Ok(Instruction {
+        program_id: *token_program_id,
+        accounts,
+        data,
+    })
+}
+
+/// Utility function that checks index is between `MIN_SIGNERS` and
+/// `MAX_SIGNERS`
+pub fn is_valid_signer_index(index: usize) -> bool {
+    (MIN_SIGNERS..=MAX_SIGNERS).contains(&index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_instruction_unpack() {
+        let mut data = vec![0, 2];
+        data.extend_from_slice(&[1; 32]);
+        data.push(0);
+        assert_eq!(
+            TokenInstruction::unpack(&data).unwrap(),
+            TokenInstruction::InitializeMint {
+                decimals: 2,
+                mint_authority: Pubkey::new_from_array([1; 32]),
+                freeze_authority: None,
+            }
+        );
+
+        assert_eq!(
+            TokenInstruction::unpack(&[1]).unwrap(),
+            TokenInstruction::InitializeAccount
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[3, 1, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+            TokenInstruction::Transfer { amount: 1 }
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[7, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+            TokenInstruction::MintTo { amount: 2 }
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[8, 3, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+            TokenInstruction::Burn { amount: 3 }
+        );
+    }
+
+    #[test]
+    fn test_instruction_unpack_invalid() {
+        // Missing or unsupported discriminators.
+        assert_eq!(
+            TokenInstruction::unpack(&[]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(
+            TokenInstruction::unpack(&[6, 1, 0, 0, 0, 0, 0, 0, 0]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Truncated amount.
+        assert_eq!(
+            TokenInstruction::unpack(&[3, 1, 0, 0]),
+            Err(ProgramError::InvalidInstructionData)
+        );
+
+        // Invalid freeze authority option.
+        let mut data = vec![0, 2];
+        data.extend_from_slice(&[1; 32]);
+        data.push(2);
+        assert_eq!(
+            TokenInstruction::unpack(&data),
+            Err(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    #[test]
+    fn test_instruction_packing() {
+        let check = TokenInstruction::InitializeMint {
+            decimals: 2,
+            mint_authority: Pubkey::new_from_array([1u8; 32]),
+            freeze_authority: None,
+        };
+        let packed = check.pack();
+        let mut expect = vec![0u8, 2];
+        expect.extend_from_slice(&[1u8; 32]);
+        expect.push(0);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeMint {
+            decimals: 2,
+            mint_authority: Pubkey::new_from_array([2u8; 32]),
+            freeze_authority: Some(Pubkey::new_from_array([3u8; 32])),
+        };
+        let packed = check.pack();
+        let mut expect = vec![0u8, 2];
+        expect.extend_from_slice(&[2u8; 32]);
+        expect.push(1);
+        expect.extend_from_slice(&[3u8; 32]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeAccount;
+        let packed = check.pack();
+        let expect = Vec::from([1u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::InitializeMultisig { m: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([2u8, 1]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::Transfer { amount: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([3u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::Approve { amount: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([4u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::Revoke;
+        let packed = check.pack();
+        let expect = Vec::from([5u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::MintTo { amount: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([7u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::Burn { amount: 1 };
+        let packed = check.pack();
+        let expect = Vec::from([8u8, 1, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::CloseAccount;
+        let packed = check.pack();
+        let expect = Vec::from([9u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::FreezeAccount;
+        let packed = check.pack();
+        let expect = Vec::from([10u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+
+        let check = TokenInstruction::ThawAccount;
+        let packed = check.pack();
+        let expect = Vec::from([11u8]);
+        assert_eq!(packed, expect);
+        let unpacked = TokenInstruction::unpack(&expect).unwrap();
+        assert_eq!(unpacked, check);
+    }
+
+    #[test]
+    fn test_instruction_builders() {
+        let token_program_id = crate::id();
+        let mint = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let instruction = initialize_mint(&token_program_id, &mint, &authority, None, 2).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(mint, false),
+                AccountMeta::new_readonly(rent::id(), false),
+            ]
+        );
+
+        let instruction =
+            initialize_account(&token_program_id, &account, &mint, &authority).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(authority, false),
+                AccountMeta::new_readonly(rent::id(), false),
+            ]
+        );
+
+        let instruction =
+            transfer(&token_program_id, &account, &other, &authority, &[], 1).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new(other, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction = approve(&token_program_id, &account, &other, &authority, &[], 1).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(other, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction = revoke(&token_program_id, &account, &authority, &[]).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction = mint_to(&token_program_id, &mint, &account, &authority, &[], 1).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(mint, false),
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction = burn(&token_program_id, &account, &mint, &authority, &[], 1).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new(mint, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction =
+            close_account(&token_program_id, &account, &other, &authority, &[]).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new(other, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction =
+            freeze_account(&token_program_id, &account, &mint, &authority, &[]).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let instruction =
+            thaw_account(&token_program_id, &account, &mint, &authority, &[]).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new_readonly(mint, false),
+                AccountMeta::new_readonly(authority, true),
+            ]
+        );
+
+        let signer = Pubkey::new_unique();
+        let instruction = transfer(
+            &token_program_id,
+            &account,
+            &other,
+            &authority,
+            &[&signer],
+            1,
+        )
+        .unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new(other, false),
+                AccountMeta::new_readonly(authority, false),
+                AccountMeta::new_readonly(signer, true),
+            ]
+        );
+
+        let instruction =
+            initialize_multisig(&token_program_id, &authority, &[&signer, &other], 2).unwrap();
+        assert_eq!(
+            instruction.accounts,
+            vec![
+                AccountMeta::new(authority, false),
+                AccountMeta::new_readonly(rent::id(), false),
+                AccountMeta::new_readonly(signer, false),
+                AccountMeta::new_readonly(other, false),
+            ]
+        );
+
+        // The multisig threshold cannot exceed the number of signers.
+        assert_eq!(
+            initialize_multisig(&token_program_id, &authority, &[&signer], 2),
+            Err(ProgramError::MissingRequiredSignature)
+        );
+
+        // Builders only target the token program.
+        assert_eq!(
+            burn(&Pubkey::new_unique(), &account, &mint, &authority, &[], 1),
+            Err(ProgramError::IncorrectProgramId)
+        );
+    }
+}
@@ -1,43 +1,618 @@
 use {
-    crate::{error::TokenError, instruction::TokenInstruction},
-    solana_account_info::AccountInfo,
-    solana_program_error::ProgramResult,
-    solana_pubkey::Pubkey,
+    crate::{
+        error::TokenError,
+        instruction::{is_valid_signer_index, TokenInstruction, MAX_SIGNERS},
+        state::{Account, AccountState, Mint, Multisig},
+    },
+    solana_account_info::{next_account_info, AccountInfo},
+    solana_msg::msg,
+    solana_program_error::{ProgramError, ProgramResult},
+    solana_program_memory::sol_memcmp,
+    solana_program_pack::{IsInitialized, Pack},
+    solana_pubkey::{Pubkey, PUBKEY_BYTES},
+    solana_rent::Rent,
+    solana_sdk_ids::system_program,
+    solana_sysvar::SysvarSerialize,
 };
 
 /// Program state handler.
 pub struct Processor {}
 
 impl Processor {
-    /// Main instruction processing router
-    pub fn process(
-        _program_id: &Pubkey,
-        _accounts: &[AccountInfo],
-        instruction_data: &[u8],
+    /// Processes an [`InitializeMint`](enum.TokenInstruction.html) instruction.
+    pub fn process_initialize_mint(
+        accounts: &[AccountInfo],
+        decimals: u8,
+        mint_authority: Pubkey,
+        freeze_authority: Option<Pubkey>,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let mint_info = next_account_info(account_info_iter)?;
+        let mint_data_len = mint_info.data_len();
+        let rent = Rent::from_account_info(next_account_info(account_info_iter)?)?;
+
+        let mut mint = Mint::unpack_unchecked(&mint_info.data.borrow())?;
+        if mint.is_initialized {
+            return Err(TokenError::AlreadyInitialized.into());
+        }
+
+        if !rent.is_exempt(mint_info.lamports(), mint_data_len) {
+            return Err(TokenError::NotRentExempt.into());
+        }
+
+        mint.mint_authority = Some(mint_authority);
+        mint.decimals = decimals;
+        mint.is_initialized = true;
+        mint.freeze_authority = freeze_authority;
+
+        Mint::pack(mint, &mut mint_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes an [`InitializeAccount`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_initialize_account(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let new_account_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+        let new_account_info_data_len = new_account_info.data_len();
+        let rent = Rent::from_account_info(next_account_info(account_info_iter)?)?;
+
+        let mut account = Account::unpack_unchecked(&new_account_info.data.borrow())?;
+        if account.is_initialized() {
+            return Err(TokenError::AlreadyInitialized.into());
+        }
+
+        if !rent.is_exempt(new_account_info.lamports(), new_account_info_data_len) {
+            return Err(TokenError::NotRentExempt.into());
+        }
+
+        Self::check_account_owner(program_id, mint_info)?;
+        let _ = Self::unpack_initialized::<Mint>(mint_info)
+            .map_err(|_| Into::<ProgramError>::into(TokenError::InvalidMint))?;
+
+        account.mint = *mint_info.key;
+        account.owner = *owner_info.key;
+        account.amount = 0;
+        account.delegate = None;
+        account.state = AccountState::Initialized;
+        account.delegated_amount = 0;
+        account.close_authority = None;
+
+        Account::pack(account, &mut new_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes an [`InitializeMultisig`](enum.TokenInstruction.html)
+    /// instruction.
+    pub fn process_initialize_multisig(accounts: &[AccountInfo], m: u8) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let multisig_info = next_account_info(account_info_iter)?;
+        let multisig_info_data_len = multisig_info.data_len();
+        let rent = Rent::from_account_info(next_account_info(account_info_iter)?)?;
+
+        let mut multisig = Multisig::unpack_unchecked(&multisig_info.data.borrow())?;
+        if multisig.is_initialized {
+            return Err(TokenError::AlreadyInitialized.into());
+        }
+
+        if !rent.is_exempt(multisig_info.lamports(), multisig_info_data_len) {
+            return Err(TokenError::NotRentExempt.into());
+        }
+
+        let signer_infos = account_info_iter.as_slice();
+        multisig.m = m;
+        multisig.n = signer_infos.len() as u8;
+        if !is_valid_signer_index(multisig.n as usize) {
+            return Err(TokenError::InvalidNumberOfProvidedSigners.into());
+        }
+        if !is_valid_signer_index(multisig.m as usize) {
+            return Err(TokenError::InvalidNumberOfRequiredSigners.into());
+        }
+        for (i, signer_info) in signer_infos.iter().enumerate() {
+            multisig.signers[i] = *signer_info.key;
+        }
+        multisig.is_initialized = true;
+
+        Multisig::pack(multisig, &mut multisig_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`Transfer`](enum.TokenInstruction.html) instruction.
+    pub fn process_transfer(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
     ) -> ProgramResult {
-        let instruction = TokenInstruction::unpack(instruction_data)?;
-        
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Self::unpack_initialized::<Account>(source_account_info)?;
+        let mut destination_account =
+            Self::unpack_initialized::<Account>(destination_account_info)?;
+
+        if source_account.is_frozen() || destination_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        if source_account.amount < amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+        if !Self::cmp_pubkeys(&source_account.mint, &destination_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        let self_transfer =
+            Self::cmp_pubkeys(source_account_info.key, destination_account_info.key);
+
+        match source_account.delegate {
+            Some(ref delegate) if Self::cmp_pubkeys(authority_info.key, delegate) => {
+                Self::validate_owner(
+                    program_id,
+                    delegate,
+                    authority_info,
+                    account_info_iter.as_slice(),
+                )?;
+                if source_account.delegated_amount < amount {
+                    return Err(TokenError::InsufficientFunds.into());
+                }
+                if !self_transfer {
+                    source_account.delegated_amount = source_account
+                        .delegated_amount
+                        .checked_sub(amount)
+                        .ok_or(TokenError::Overflow)?;
+                    if source_account.delegated_amount == 0 {
+                        source_account.delegate = None;
+                    }
+                }
+            }
+            _ => Self::validate_owner(
+                program_id,
+                &source_account.owner,
+                authority_info,
+                account_info_iter.as_slice(),
+            )?,
+        }
+
+        if self_transfer || amount == 0 {
+            Self::check_account_owner(program_id, source_account_info)?;
+            Self::check_account_owner(program_id, destination_account_info)?;
+        }
+
+        // A self-transfer leaves the balance unchanged, so there is nothing
+        // to write back.
+        if self_transfer {
+            return Ok(());
+        }
+
+        source_account.amount = source_account
+            .amount
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+        destination_account.amount = destination_account
+            .amount
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+        Account::pack(
+            destination_account,
+            &mut destination_account_info.data.borrow_mut(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Processes a [`MintTo`](enum.TokenInstruction.html) instruction.
+    pub fn process_mint_to(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let mint_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        let mut destination_account =
+            Self::unpack_initialized::<Account>(destination_account_info)?;
+        if destination_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        if !Self::cmp_pubkeys(mint_info.key, &destination_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        let mut mint = Self::unpack_initialized::<Mint>(mint_info)?;
+        match mint.mint_authority {
+            Some(ref mint_authority) => Self::validate_owner(
+                program_id,
+                mint_authority,
+                owner_info,
+                account_info_iter.as_slice(),
+            )?,
+            None => return Err(TokenError::FixedSupply.into()),
+        }
+
+        if amount == 0 {
+            Self::check_account_owner(program_id, mint_info)?;
+            Self::check_account_owner(program_id, destination_account_info)?;
+        }
+
+        destination_account.amount = destination_account
+            .amount
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+        mint.supply = mint
+            .supply
+            .checked_add(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Account::pack(
+            destination_account,
+            &mut destination_account_info.data.borrow_mut(),
+        )?;
+        Mint::pack(mint, &mut mint_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`Burn`](enum.TokenInstruction.html) instruction.
+    pub fn process_burn(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Self::unpack_initialized::<Account>(source_account_info)?;
+        let mut mint = Self::unpack_initialized::<Mint>(mint_info)?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+        if source_account.amount < amount {
+            return Err(TokenError::InsufficientFunds.into());
+        }
+        if !Self::cmp_pubkeys(mint_info.key, &source_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        match source_account.delegate {
+            Some(ref delegate) if Self::cmp_pubkeys(authority_info.key, delegate) => {
+                Self::validate_owner(
+                    program_id,
+                    delegate,
+                    authority_info,
+                    account_info_iter.as_slice(),
+                )?;
+                if source_account.delegated_amount < amount {
+                    return Err(TokenError::InsufficientFunds.into());
+                }
+                source_account.delegated_amount = source_account
+                    .delegated_amount
+                    .checked_sub(amount)
+                    .ok_or(TokenError::Overflow)?;
+                if source_account.delegated_amount == 0 {
+                    source_account.delegate = None;
+                }
+            }
+            _ => Self::validate_owner(
+                program_id,
+                &source_account.owner,
+                authority_info,
+                account_info_iter.as_slice(),
+            )?,
+        }
+
+        if amount == 0 {
+            Self::check_account_owner(program_id, source_account_info)?;
+            Self::check_account_owner(program_id, mint_info)?;
+        }
+
+        source_account.amount = source_account
+            .amount
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+        mint.supply = mint
+            .supply
+            .checked_sub(amount)
+            .ok_or(TokenError::Overflow)?;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+        Mint::pack(mint, &mut mint_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes an [`Approve`](enum.TokenInstruction.html) instruction.
+    pub fn process_approve(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let delegate_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Self::unpack_initialized::<Account>(source_account_info)?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        source_account.delegate = Some(*delegate_info.key);
+        source_account.delegated_amount = amount;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`Revoke`](enum.TokenInstruction.html) instruction.
+    pub fn process_revoke(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let owner_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Self::unpack_initialized::<Account>(source_account_info)?;
+
+        if source_account.is_frozen() {
+            return Err(TokenError::AccountFrozen.into());
+        }
+
+        Self::validate_owner(
+            program_id,
+            &source_account.owner,
+            owner_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        source_account.delegate = None;
+        source_account.delegated_amount = 0;
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Processes a [`CloseAccount`](enum.TokenInstruction.html) instruction.
+    pub fn process_close_account(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let destination_account_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        if Self::cmp_pubkeys(source_account_info.key, destination_account_info.key) {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let source_account = Self::unpack_initialized::<Account>(source_account_info)?;
+        if source_account.amount != 0 {
+            return Err(TokenError::NonNativeHasBalance.into());
+        }
+
+        let authority = source_account
+            .close_authority
+            .unwrap_or(source_account.owner);
+        Self::validate_owner(
+            program_id,
+            &authority,
+            authority_info,
+            account_info_iter.as_slice(),
+        )?;
+
+        let destination_starting_lamports = destination_account_info.lamports();
+        **destination_account_info.lamports.borrow_mut() = destination_starting_lamports
+            .checked_add(source_account_info.lamports())
+            .ok_or(TokenError::Overflow)?;
+
+        **source_account_info.lamports.borrow_mut() = 0;
+        delete_account(source_account_info)?;
+
+        Ok(())
+    }
+
+    /// Processes a [`FreezeAccount`](enum.TokenInstruction.html) or a
+    /// [`ThawAccount`](enum.TokenInstruction.html) instruction.
+    pub fn process_toggle_freeze_account(
+        program_id: &Pubkey,
+        accounts: &[AccountInfo],
+        freeze: bool,
+    ) -> ProgramResult {
+        let account_info_iter = &mut accounts.iter();
+        let source_account_info = next_account_info(account_info_iter)?;
+        let mint_info = next_account_info(account_info_iter)?;
+        let authority_info = next_account_info(account_info_iter)?;
+
+        let mut source_account = Self::unpack_initialized::<Account>(source_account_info)?;
+        if freeze == source_account.is_frozen() {
+            return Err(TokenError::InvalidState.into());
+        }
+        if !Self::cmp_pubkeys(mint_info.key, &source_account.mint) {
+            return Err(TokenError::MintMismatch.into());
+        }
+
+        let mint = Self::unpack_initialized::<Mint>(mint_info)?;
+        match mint.freeze_authority {
+            Some(ref authority) => Self::validate_owner(
+                program_id,
+                authority,
+                authority_info,
+                account_info_iter.as_slice(),
+            )?,
+            None => return Err(TokenError::MintCannotFreeze.into()),
+        }
+
+        source_account.state = if freeze {
+            AccountState::Frozen
+        } else {
+            AccountState::Initialized
+        };
+
+        Account::pack(source_account, &mut source_account_info.data.borrow_mut())?;
+
+        Ok(())
+    }
+
+    /// Main instruction processing router
+    pub fn process(program_id: &Pubkey, accounts: &[AccountInfo], input: &[u8]) -> ProgramResult {
+        let instruction = TokenInstruction::unpack(input)?;
+
         match instruction {
-            TokenInstruction::InitializeMint { .. } => {
-                // TODO: Implement InitializeMint handler
-                Err(TokenError::NotInitialized.into())
+            TokenInstruction::InitializeMint {
+                decimals,
+                mint_authority,
+                freeze_authority,
+            } => {
+                msg!("Instruction: InitializeMint");
+                Self::process_initialize_mint(accounts, decimals, mint_authority, freeze_authority)
             }
             TokenInstruction::InitializeAccount => {
-                // TODO: Implement InitializeAccount handler
-                Err(TokenError::NotInitialized.into())
+                msg!("Instruction: InitializeAccount");
+                Self::process_initialize_account(program_id, accounts)
+            }
+            TokenInstruction::InitializeMultisig { m } => {
+                msg!("Instruction: InitializeMultisig");
+                Self::process_initialize_multisig(accounts, m)
+            }
+            TokenInstruction::Transfer { amount } => {
+                msg!("Instruction: Transfer");
+                Self::process_transfer(program_id, accounts, amount)
+            }
+            TokenInstruction::Approve { amount } => {
+                msg!("Instruction: Approve");
+                Self::process_approve(program_id, accounts, amount)
+            }
+            TokenInstruction::Revoke => {
+                msg!("Instruction: Revoke");
+                Self::process_revoke(program_id, accounts)
             }
-            TokenInstruction::Transfer { .. } => {
-                // TODO: Implement Transfer handler
-                Err(TokenError::NotInitialized.into())
+            TokenInstruction::MintTo { amount } => {
+                msg!("Instruction: MintTo");
+                Self::process_mint_to(program_id, accounts, amount)
             }
-            TokenInstruction::MintTo { .. } => {
-                // TODO: Implement MintTo handler
-                Err(TokenError::NotInitialized.into())
+            TokenInstruction::Burn { amount } => {
+                msg!("Instruction: Burn");
+                Self::process_burn(program_id, accounts, amount)
             }
-            TokenInstruction::Burn { .. } => {
-                // TODO: Implement Burn handler
-                Err(TokenError::NotInitialized.into())
+            TokenInstruction::CloseAccount => {
+                msg!("Instruction: CloseAccount");
+                Self::process_close_account(program_id, accounts)
+            }
+            TokenInstruction::FreezeAccount => {
+                msg!("Instruction: FreezeAccount");
+                Self::process_toggle_freeze_account(program_id, accounts, true)
+            }
+            TokenInstruction::ThawAccount => {
+                msg!("Instruction: ThawAccount");
+                Self::process_toggle_freeze_account(program_id, accounts, false)
             }
         }
     }
+
+    /// Unpacks the state of an account, which must be initialized.
+    fn unpack_initialized<T: Pack + IsInitialized>(
+        account_info: &AccountInfo,
+    ) -> Result<T, ProgramError> {
+        let value = T::unpack_unchecked(&account_info.data.borrow())?;
+        if value.is_initialized() {
+            Ok(value)
+        } else {
+            Err(TokenError::NotInitialized.into())
+        }
+    }
+
+    /// Checks that the account is owned by the expected program
+    pub fn check_account_owner(program_id: &Pubkey, account_info: &AccountInfo) -> ProgramResult {
+        if !Self::cmp_pubkeys(program_id, account_info.owner) {
+            Err(ProgramError::IncorrectProgramId)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Checks two pubkeys for equality in a computationally cheap way using
+    /// `sol_memcmp`
+    pub fn cmp_pubkeys(a: &Pubkey, b: &Pubkey) -> bool {
+        unsafe { sol_memcmp(a.as_ref(), b.as_ref(), PUBKEY_BYTES) == 0 }
+    }
+
+    /// Validates owner(s) are present and signed the transaction.
+    ///
+    /// When the owner is a multisignature account, at least `m` of its
+    /// signers must be among `signers`, each counted at most once.
+    pub fn validate_owner(
+        program_id: &Pubkey,
+        expected_owner: &Pubkey,
+        owner_account_info: &AccountInfo,
+        signers: &[AccountInfo],
+    ) -> ProgramResult {
+        if !Self::cmp_pubkeys(expected_owner, owner_account_info.key) {
+            return Err(TokenError::InvalidOwner.into());
+        }
+        if Self::cmp_pubkeys(program_id, owner_account_info.owner)
+            && owner_account_info.data_len() == Multisig::get_packed_len()
+        {
+            let multisig = Self::unpack_initialized::<Multisig>(owner_account_info)?;
+            let mut num_signers = 0;
+            let mut matched = [false; MAX_SIGNERS];
+            for signer in signers.iter() {
+                for (position, key) in multisig.signers[0..multisig.n as usize].iter().enumerate() {
+                    if Self::cmp_pubkeys(key, signer.key) && !matched[position] {
+                        if !signer.is_signer {
+                            return Err(ProgramError::MissingRequiredSignature);
+                        }
+                        matched[position] = true;
+                        num_signers += 1;
+                    }
+                }
+            }
+            if num_signers < multisig.m {
+                return Err(ProgramError::MissingRequiredSignature);
+            }
+            return Ok(());
+        } else if !owner_account_info.is_signer {
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+        Ok(())
+    }
+}
+
+/// Helper function to mostly delete an account in a test environment.
+#[cfg(not(target_os = "solana"))]
+fn delete_account(account_info: &AccountInfo) -> Result<(), ProgramError> {
+    account_info.assign(&system_program::id());
+    let mut account_data = account_info.data.borrow_mut();
+    let data_len = account_data.len();
+    unsafe {
+        solana_program_memory::sol_memset(*account_data, 0, data_len);
+    }
+    Ok(())
+}
+
+/// Helper function to totally delete an account on-chain
+#[cfg(target_os = "solana")]
+fn delete_account(account_info: &AccountInfo) -> Result<(), ProgramError> {
+    account_info.assign(&system_program::id());
+    account_info.resize(0)
 }
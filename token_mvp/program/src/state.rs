@@ -1,23 +1,35 @@
-
 use {
+    crate::instruction::MAX_SIGNERS,
+    arrayref::{array_mut_ref, array_ref, array_refs, mut_array_refs},
+    solana_program_error::ProgramError,
     solana_program_pack::{IsInitialized, Pack, Sealed},
     solana_pubkey::Pubkey,
 };
 
 /// Mint data.
+///
+/// Packed with the same 82-byte layout as the SPL Token mint.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Mint {
-    /// Authority that can mint new tokens
-    pub mint_authority: Pubkey,
+    /// Optional authority used to mint new tokens. The mint authority may only
+    /// be provided during mint creation. If no mint authority is present
+    /// then the mint has a fixed supply and no further tokens may be
+    /// minted.
+    pub mint_authority: Option<Pubkey>,
     /// Total supply of tokens
     pub supply: u64,
     /// Number of base 10 digits to the right of the decimal place
     pub decimals: u8,
     /// Is `true` if this structure has been initialized
     pub is_initialized: bool,
+    /// Optional authority to freeze token accounts
+    pub freeze_authority: Option<Pubkey>,
 }
 
 /// Account data.
+///
+/// Packed with the same 165-byte layout as the SPL Token account, where the
+/// native amount is always empty.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Account {
     /// The mint associated with this account
@@ -26,11 +38,53 @@ pub struct Account {
     pub owner: Pubkey,
     /// The amount of tokens this account holds
     pub amount: u64,
+    /// If `delegate` is `Some` then `delegated_amount` represents the amount
+    /// authorized by the delegate
+    pub delegate: Option<Pubkey>,
+    /// The account's state
+    pub state: AccountState,
+    /// The amount delegated
+    pub delegated_amount: u64,
+    /// Optional authority to close the account
+    pub close_authority: Option<Pubkey>,
+}
+
+impl Account {
+    /// Checks if account is frozen
+    pub fn is_frozen(&self) -> bool {
+        self.state == AccountState::Frozen
+    }
+}
+
+/// Account state.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum AccountState {
+    /// Account is not yet initialized
+    #[default]
+    Uninitialized,
+    /// Account is initialized; the account owner and/or delegate may perform
+    /// permitted operations on this account
+    Initialized,
+    /// Account has been frozen by the mint freeze authority. Neither the
+    /// account owner nor the delegate are able to perform operations on
+    /// this account.
+    Frozen,
+}
+
+/// Multisignature data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Multisig {
+    /// Number of signers required
+    pub m: u8,
+    /// Number of valid signers
+    pub n: u8,
     /// Is `true` if this structure has been initialized
     pub is_initialized: bool,
+    /// Signer public keys
+    pub signers: [Pubkey; MAX_SIGNERS],
 }
 
-
 impl IsInitialized for Mint {
     fn is_initialized(&self) -> bool {
         self.is_initialized
@@ -38,6 +92,12 @@ impl IsInitialized for Mint {
 }
 
 impl IsInitialized for Account {
+    fn is_initialized(&self) -> bool {
+        self.state != AccountState::Uninitialized
+    }
+}
+
+impl IsInitialized for Multisig {
     fn is_initialized(&self) -> bool {
         self.is_initialized
     }
@@ -46,27 +106,273 @@ impl IsInitialized for Account {
 impl Sealed for Mint {}
 impl Pack for Mint {
     const LEN: usize = 82;
-    
-    fn pack_into_slice(&self, _dst: &mut [u8]) {
-        // TODO: Implement packing
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 82];
+        let (
+            mint_authority_dst,
+            supply_dst,
+            decimals_dst,
+            is_initialized_dst,
+            freeze_authority_dst,
+        ) = mut_array_refs![dst, 36, 8, 1, 1, 36];
+        let &Mint {
+            ref mint_authority,
+            supply,
+            decimals,
+            is_initialized,
+            ref freeze_authority,
+        } = self;
+        pack_coption_key(mint_authority.as_ref(), mint_authority_dst);
+        *supply_dst = supply.to_le_bytes();
+        decimals_dst[0] = decimals;
+        is_initialized_dst[0] = is_initialized as u8;
+        pack_coption_key(freeze_authority.as_ref(), freeze_authority_dst);
     }
-    
-    fn unpack_from_slice(_src: &[u8]) -> Result<Self, solana_program_error::ProgramError> {
-        // TODO: Implement unpacking
-        Err(solana_program_error::ProgramError::InvalidAccountData)
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 82];
+        let (mint_authority, supply, decimals, is_initialized, freeze_authority) =
+            array_refs![src, 36, 8, 1, 1, 36];
+        let mint_authority = unpack_coption_key(mint_authority)?;
+        let supply = u64::from_le_bytes(*supply);
+        let decimals = decimals[0];
+        let is_initialized = unpack_bool(is_initialized)?;
+        let freeze_authority = unpack_coption_key(freeze_authority)?;
+        Ok(Mint {
+            mint_authority,
+            supply,
+            decimals,
+            is_initialized,
+            freeze_authority,
+        })
     }
 }
 
 impl Sealed for Account {}
 impl Pack for Account {
     const LEN: usize = 165;
-    
-    fn pack_into_slice(&self, _dst: &mut [u8]) {
-        // TODO: Implement packing
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 165];
+        let (
+            mint_dst,
+            owner_dst,
+            amount_dst,
+            delegate_dst,
+            state_dst,
+            is_native_dst,
+            delegated_amount_dst,
+            close_authority_dst,
+        ) = mut_array_refs![dst, 32, 32, 8, 36, 1, 12, 8, 36];
+        let &Account {
+            ref mint,
+            ref owner,
+            amount,
+            ref delegate,
+            state,
+            delegated_amount,
+            ref close_authority,
+        } = self;
+        mint_dst.copy_from_slice(mint.as_ref());
+        owner_dst.copy_from_slice(owner.as_ref());
+        *amount_dst = amount.to_le_bytes();
+        pack_coption_key(delegate.as_ref(), delegate_dst);
+        state_dst[0] = state as u8;
+        *is_native_dst = [0; 12];
+        *delegated_amount_dst = delegated_amount.to_le_bytes();
+        pack_coption_key(close_authority.as_ref(), close_authority_dst);
+    }
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 165];
+        let (mint, owner, amount, delegate, state, is_native, delegated_amount, close_authority) =
+            array_refs![src, 32, 32, 8, 36, 1, 12, 8, 36];
+        // Native accounts cannot be represented.
+        if is_native[..4] != [0; 4] {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        Ok(Account {
+            mint: Pubkey::new_from_array(*mint),
+            owner: Pubkey::new_from_array(*owner),
+            amount: u64::from_le_bytes(*amount),
+            delegate: unpack_coption_key(delegate)?,
+            state: match state {
+                [0] => AccountState::Uninitialized,
+                [1] => AccountState::Initialized,
+                [2] => AccountState::Frozen,
+                _ => return Err(ProgramError::InvalidAccountData),
+            },
+            delegated_amount: u64::from_le_bytes(*delegated_amount),
+            close_authority: unpack_coption_key(close_authority)?,
+        })
+    }
+}
+
+impl Sealed for Multisig {}
+impl Pack for Multisig {
+    const LEN: usize = 355;
+
+    fn pack_into_slice(&self, dst: &mut [u8]) {
+        let dst = array_mut_ref![dst, 0, 355];
+        let (m, n, is_initialized, signers_flat) = mut_array_refs![dst, 1, 1, 1, 32 * MAX_SIGNERS];
+        *m = [self.m];
+        *n = [self.n];
+        *is_initialized = [self.is_initialized as u8];
+        for (src, dst) in self.signers.iter().zip(signers_flat.chunks_mut(32)) {
+            dst.copy_from_slice(src.as_ref());
+        }
+    }
+
+    fn unpack_from_slice(src: &[u8]) -> Result<Self, ProgramError> {
+        let src = array_ref![src, 0, 355];
+        let (m, n, is_initialized, signers_flat) = array_refs![src, 1, 1, 1, 32 * MAX_SIGNERS];
+        let mut result = Multisig {
+            m: m[0],
+            n: n[0],
+            is_initialized: unpack_bool(is_initialized)?,
+            signers: [Pubkey::default(); MAX_SIGNERS],
+        };
+        for (src, dst) in signers_flat.chunks(32).zip(result.signers.iter_mut()) {
+            *dst = Pubkey::try_from(src).map_err(|_| ProgramError::InvalidAccountData)?;
+        }
+        Ok(result)
+    }
+}
+
+// Helpers
+fn pack_coption_key(src: Option<&Pubkey>, dst: &mut [u8; 36]) {
+    let (tag, body) = mut_array_refs![dst, 4, 32];
+    match src {
+        Some(key) => {
+            *tag = [1, 0, 0, 0];
+            body.copy_from_slice(key.as_ref());
+        }
+        None => {
+            *tag = [0; 4];
+            *body = [0; 32];
+        }
+    }
+}
+
+fn unpack_coption_key(src: &[u8; 36]) -> Result<Option<Pubkey>, ProgramError> {
+    let (tag, body) = array_refs![src, 4, 32];
+    match *tag {
+        [0, 0, 0, 0] => Ok(None),
+        [1, 0, 0, 0] => Ok(Some(Pubkey::new_from_array(*body))),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+fn unpack_bool(src: &[u8; 1]) -> Result<bool, ProgramError> {
+    match src {
+        [0] => Ok(false),
+        [1] => Ok(true),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mint_pack_unpack() {
+        let mint = Mint {
+            mint_authority: Some(Pubkey::new_from_array([1; 32])),
+            supply: 42,
+            decimals: 7,
+            is_initialized: true,
+            freeze_authority: Some(Pubkey::new_from_array([2; 32])),
+        };
+        let mut packed = vec![0; Mint::get_packed_len()];
+        Mint::pack(mint.clone(), &mut packed).unwrap();
+        let mut expected = vec![1, 0, 0, 0];
+        expected.extend_from_slice(&[1; 32]);
+        expected.extend_from_slice(&[42, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend_from_slice(&[7, 1]);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        expected.extend_from_slice(&[2; 32]);
+        assert_eq!(packed, expected);
+        assert_eq!(Mint::unpack(&packed).unwrap(), mint);
+
+        // Invalid option tags are rejected.
+        packed[46] = 2;
+        assert_eq!(Mint::unpack(&packed), Err(ProgramError::InvalidAccountData));
+    }
+
+    #[test]
+    fn test_mint_without_mint_authority_round_trip() {
+        let reference = spl_token_interface::state::Mint {
+            supply: 42,
+            decimals: 7,
+            is_initialized: true,
+            freeze_authority: Some(Pubkey::new_from_array([2; 32])).into(),
+            ..Default::default()
+        };
+        let mut expected = vec![0; Mint::get_packed_len()];
+        spl_token_interface::state::Mint::pack(reference, &mut expected).unwrap();
+
+        let mint = Mint::unpack(&expected).unwrap();
+        assert_eq!(mint.mint_authority, None);
+
+        let mut packed = vec![0; Mint::get_packed_len()];
+        Mint::pack(mint, &mut packed).unwrap();
+        assert_eq!(packed, expected);
+    }
+
+    #[test]
+    fn test_account_pack_unpack() {
+        let account = Account {
+            mint: Pubkey::new_from_array([1; 32]),
+            owner: Pubkey::new_from_array([2; 32]),
+            amount: 3,
+            delegate: Some(Pubkey::new_from_array([4; 32])),
+            state: AccountState::Frozen,
+            delegated_amount: 5,
+            close_authority: Some(Pubkey::new_from_array([6; 32])),
+        };
+        let mut packed = vec![0; Account::get_packed_len()];
+        Account::pack(account.clone(), &mut packed).unwrap();
+        let mut expected = vec![1; 32];
+        expected.extend_from_slice(&[2; 32]);
+        expected.extend_from_slice(&[3, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        expected.extend_from_slice(&[4; 32]);
+        expected.push(2);
+        expected.extend_from_slice(&[0; 12]);
+        expected.extend_from_slice(&[5, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend_from_slice(&[1, 0, 0, 0]);
+        expected.extend_from_slice(&[6; 32]);
+        assert_eq!(packed, expected);
+        assert_eq!(Account::unpack(&packed).unwrap(), account);
+
+        // Native accounts cannot be represented.
+        packed[109] = 1;
+        assert_eq!(
+            Account::unpack(&packed),
+            Err(ProgramError::InvalidAccountData)
+        );
     }
-    
-    fn unpack_from_slice(_src: &[u8]) -> Result<Self, solana_program_error::ProgramError> {
-        // TODO: Implement unpacking
-        Err(solana_program_error::ProgramError::InvalidAccountData)
+
+    #[test]
+    fn test_multisig_pack_unpack() {
+        let mut signers = [Pubkey::default(); MAX_SIGNERS];
+        signers[0] = Pubkey::new_from_array([1; 32]);
+        signers[1] = Pubkey::new_from_array([2; 32]);
+        let multisig = Multisig {
+            m: 1,
+            n: 2,
+            is_initialized: true,
+            signers,
+        };
+        let mut packed = vec![0; Multisig::get_packed_len()];
+        Multisig::pack(multisig.clone(), &mut packed).unwrap();
+        let mut expected = vec![1, 2, 1];
+        expected.extend_from_slice(&[1; 32]);
+        expected.extend_from_slice(&[2; 32]);
+        expected.extend_from_slice(&[0; 32 * (MAX_SIGNERS - 2)]);
+        assert_eq!(packed, expected);
+        assert_eq!(Multisig::unpack(&packed).unwrap(), multisig);
     }
-}
\ No newline at end of file
+}
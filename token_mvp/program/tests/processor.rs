@@ -1,9 +1,1199 @@
+mod setup;
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn test_placeholder() {
-        // TODO: Add tests
-        assert!(true);
+use {
+    crate::setup::{
+        mint_account, multisig_account, process_and_compare, rent_sysvar, token_account,
+        token_account_with, uninitialized_account,
+    },
+    mollusk_svm::result::Check,
+    solana_account::Account,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    spl_token::{
+        error::TokenError,
+        instruction::{
+            approve, burn, close_account, freeze_account, initialize_account, initialize_mint,
+            initialize_multisig, mint_to, revoke, thaw_account, transfer,
+        },
+        state::{Account as TokenAccount, AccountState, Mint, Multisig},
+    },
+};
+
+#[test]
+fn test_initialize_mint() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let instruction =
+        initialize_mint(&spl_token::id(), &mint_key, &mint_authority_key, None, 2).unwrap();
+
+    // It should initialize the mint.
+
+    let result = process_and_compare(
+        &instruction,
+        &[(mint_key, uninitialized_account(Mint::LEN)), rent_sysvar()],
+        &[Check::success()],
+    );
+
+    let mint = result.get_account(&mint_key).unwrap();
+    assert_eq!(
+        Mint::unpack(&mint.data).unwrap(),
+        Mint {
+            mint_authority: Some(mint_authority_key),
+            supply: 0,
+            decimals: 2,
+            is_initialized: true,
+            freeze_authority: None,
+        }
+    );
+
+    // It should fail to initialize the mint twice.
+
+    process_and_compare(
+        &instruction,
+        &[(mint_key, mint.clone()), rent_sysvar()],
+        &[Check::err(TokenError::AlreadyInitialized.into())],
+    );
+}
+
+#[test]
+fn test_initialize_mint_not_rent_exempt() {
+    let mint_key = Pubkey::new_unique();
+    let mut mint = uninitialized_account(Mint::LEN);
+    mint.lamports -= 1;
+
+    process_and_compare(
+        &initialize_mint(&spl_token::id(), &mint_key, &Pubkey::new_unique(), None, 2).unwrap(),
+        &[(mint_key, mint), rent_sysvar()],
+        &[Check::err(TokenError::NotRentExempt.into())],
+    );
+}
+
+#[test]
+fn test_initialize_account() {
+    let account_key = Pubkey::new_unique();
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let instruction =
+        initialize_account(&spl_token::id(), &account_key, &mint_key, &owner_key).unwrap();
+
+    // It should initialize the account.
+
+    let result = process_and_compare(
+        &instruction,
+        &[
+            (account_key, uninitialized_account(TokenAccount::LEN)),
+            (mint_key, mint_account(&Pubkey::new_unique(), None, 0, 2)),
+            (owner_key, Account::default()),
+            rent_sysvar(),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&account.data).unwrap(),
+        TokenAccount {
+            mint: mint_key,
+            owner: owner_key,
+            amount: 0,
+            delegate: None,
+            state: AccountState::Initialized,
+            delegated_amount: 0,
+            close_authority: None,
+        }
+    );
+
+    // It should fail to initialize the account twice.
+
+    process_and_compare(
+        &instruction,
+        &[
+            (account_key, account.clone()),
+            (mint_key, mint_account(&Pubkey::new_unique(), None, 0, 2)),
+            (owner_key, Account::default()),
+            rent_sysvar(),
+        ],
+        &[Check::err(TokenError::AlreadyInitialized.into())],
+    );
+}
+
+#[test]
+fn test_initialize_account_invalid_mint() {
+    let account_key = Pubkey::new_unique();
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+
+    process_and_compare(
+        &initialize_account(&spl_token::id(), &account_key, &mint_key, &owner_key).unwrap(),
+        &[
+            (account_key, uninitialized_account(TokenAccount::LEN)),
+            (mint_key, uninitialized_account(Mint::LEN)),
+            (owner_key, Account::default()),
+            rent_sysvar(),
+        ],
+        &[Check::err(TokenError::InvalidMint.into())],
+    );
+}
+
+#[test]
+fn test_transfer() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let source_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let accounts = [
+        (source_key, token_account(&mint_key, &owner_key, 1_000)),
+        (destination_key, token_account(&mint_key, &owner_key, 0)),
+        (owner_key, Account::default()),
+    ];
+
+    // It should transfer the tokens.
+
+    let result = process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            400,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::success()],
+    );
+
+    let source = result.get_account(&source_key).unwrap();
+    assert_eq!(TokenAccount::unpack(&source.data).unwrap().amount, 600);
+    let destination = result.get_account(&destination_key).unwrap();
+    assert_eq!(TokenAccount::unpack(&destination.data).unwrap().amount, 400);
+
+    // It should fail to transfer more than the balance.
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            1_001,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::err(TokenError::InsufficientFunds.into())],
+    );
+
+    // It should fail to transfer with the wrong owner.
+
+    let other_key = Pubkey::new_unique();
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &other_key,
+            &[],
+            400,
+        )
+        .unwrap(),
+        &[
+            accounts[0].clone(),
+            accounts[1].clone(),
+            (other_key, Account::default()),
+        ],
+        &[Check::err(TokenError::InvalidOwner.into())],
+    );
+
+    // It should fail to transfer without the owner signature.
+
+    let mut instruction = transfer(
+        &spl_token::id(),
+        &source_key,
+        &destination_key,
+        &owner_key,
+        &[],
+        400,
+    )
+    .unwrap();
+    instruction.accounts[2].is_signer = false;
+    process_and_compare(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn test_transfer_mint_mismatch() {
+    let owner_key = Pubkey::new_unique();
+    let source_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            400,
+        )
+        .unwrap(),
+        &[
+            (
+                source_key,
+                token_account(&Pubkey::new_unique(), &owner_key, 1_000),
+            ),
+            (
+                destination_key,
+                token_account(&Pubkey::new_unique(), &owner_key, 0),
+            ),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(TokenError::MintMismatch.into())],
+    );
+}
+
+#[test]
+fn test_transfer_uninitialized_account() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let source_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            400,
+        )
+        .unwrap(),
+        &[
+            (source_key, token_account(&mint_key, &owner_key, 1_000)),
+            (destination_key, uninitialized_account(TokenAccount::LEN)),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(TokenError::NotInitialized.into())],
+    );
+}
+
+#[test]
+fn test_self_transfer() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+
+    // It should leave the balance unchanged.
+
+    let result = process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &account_key,
+            &account_key,
+            &owner_key,
+            &[],
+            400,
+        )
+        .unwrap(),
+        &[
+            (account_key, token_account(&mint_key, &owner_key, 1_000)),
+            (owner_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    assert_eq!(TokenAccount::unpack(&account.data).unwrap().amount, 1_000);
+
+    // It should fail to self-transfer from an account owned by another program.
+
+    let mut account = token_account(&mint_key, &owner_key, 1_000);
+    account.owner = Pubkey::new_unique();
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &account_key,
+            &account_key,
+            &owner_key,
+            &[],
+            400,
+        )
+        .unwrap(),
+        &[(account_key, account), (owner_key, Account::default())],
+        &[Check::err(ProgramError::IncorrectProgramId)],
+    );
+}
+
+#[test]
+fn test_mint_to() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+
+    let accounts = [
+        (mint_key, mint_account(&mint_authority_key, None, 0, 2)),
+        (
+            account_key,
+            token_account(&mint_key, &Pubkey::new_unique(), 0),
+        ),
+        (mint_authority_key, Account::default()),
+    ];
+
+    // It should mint the tokens.
+
+    let result = process_and_compare(
+        &mint_to(
+            &spl_token::id(),
+            &mint_key,
+            &account_key,
+            &mint_authority_key,
+            &[],
+            42,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::success()],
+    );
+
+    let mint = result.get_account(&mint_key).unwrap();
+    assert_eq!(Mint::unpack(&mint.data).unwrap().supply, 42);
+    let account = result.get_account(&account_key).unwrap();
+    assert_eq!(TokenAccount::unpack(&account.data).unwrap().amount, 42);
+
+    // It should fail to mint with the wrong authority.
+
+    let other_key = Pubkey::new_unique();
+    process_and_compare(
+        &mint_to(
+            &spl_token::id(),
+            &mint_key,
+            &account_key,
+            &other_key,
+            &[],
+            42,
+        )
+        .unwrap(),
+        &[
+            accounts[0].clone(),
+            accounts[1].clone(),
+            (other_key, Account::default()),
+        ],
+        &[Check::err(TokenError::InvalidOwner.into())],
+    );
+
+    // It should fail to mint past the maximum supply.
+
+    process_and_compare(
+        &mint_to(
+            &spl_token::id(),
+            &mint_key,
+            &account_key,
+            &mint_authority_key,
+            &[],
+            u64::MAX,
+        )
+        .unwrap(),
+        &[
+            (mint_key, mint_account(&mint_authority_key, None, 1, 2)),
+            (
+                account_key,
+                token_account(&mint_key, &Pubkey::new_unique(), 1),
+            ),
+            (mint_authority_key, Account::default()),
+        ],
+        &[Check::err(TokenError::Overflow.into())],
+    );
+}
+
+#[test]
+fn test_mint_to_mint_mismatch() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+
+    process_and_compare(
+        &mint_to(
+            &spl_token::id(),
+            &mint_key,
+            &account_key,
+            &mint_authority_key,
+            &[],
+            42,
+        )
+        .unwrap(),
+        &[
+            (mint_key, mint_account(&mint_authority_key, None, 0, 2)),
+            (
+                account_key,
+                token_account(&Pubkey::new_unique(), &Pubkey::new_unique(), 0),
+            ),
+            (mint_authority_key, Account::default()),
+        ],
+        &[Check::err(TokenError::MintMismatch.into())],
+    );
+}
+
+#[test]
+fn test_mint_to_fixed_supply() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+
+    // mint without a mint authority
+    let mut fixed_supply_mint = uninitialized_account(Mint::LEN);
+    Mint {
+        mint_authority: None,
+        supply: 42,
+        decimals: 2,
+        is_initialized: true,
+        freeze_authority: None,
     }
-}
\ No newline at end of file
+    .pack_into_slice(&mut fixed_supply_mint.data);
+
+    // It should fail to mint more tokens.
+
+    process_and_compare(
+        &mint_to(
+            &spl_token::id(),
+            &mint_key,
+            &account_key,
+            &mint_authority_key,
+            &[],
+            42,
+        )
+        .unwrap(),
+        &[
+            (mint_key, fixed_supply_mint),
+            (
+                account_key,
+                token_account(&mint_key, &Pubkey::new_unique(), 0),
+            ),
+            (mint_authority_key, Account::default()),
+        ],
+        &[Check::err(TokenError::FixedSupply.into())],
+    );
+}
+
+#[test]
+fn test_burn() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+
+    let accounts = [
+        (account_key, token_account(&mint_key, &owner_key, 1_000)),
+        (
+            mint_key,
+            mint_account(&Pubkey::new_unique(), None, 1_000, 2),
+        ),
+        (owner_key, Account::default()),
+    ];
+
+    // It should burn the tokens.
+
+    let result = process_and_compare(
+        &burn(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &owner_key,
+            &[],
+            300,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    assert_eq!(TokenAccount::unpack(&account.data).unwrap().amount, 700);
+    let mint = result.get_account(&mint_key).unwrap();
+    assert_eq!(Mint::unpack(&mint.data).unwrap().supply, 700);
+
+    // It should fail to burn more than the balance.
+
+    process_and_compare(
+        &burn(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &owner_key,
+            &[],
+            1_001,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::err(TokenError::InsufficientFunds.into())],
+    );
+
+    // It should fail to burn with the wrong owner.
+
+    let other_key = Pubkey::new_unique();
+    process_and_compare(
+        &burn(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &other_key,
+            &[],
+            300,
+        )
+        .unwrap(),
+        &[
+            accounts[0].clone(),
+            accounts[1].clone(),
+            (other_key, Account::default()),
+        ],
+        &[Check::err(TokenError::InvalidOwner.into())],
+    );
+}
+
+#[test]
+fn test_initialize_mint_with_freeze_authority() {
+    let mint_key = Pubkey::new_unique();
+    let mint_authority_key = Pubkey::new_unique();
+    let freeze_authority_key = Pubkey::new_unique();
+
+    let result = process_and_compare(
+        &initialize_mint(
+            &spl_token::id(),
+            &mint_key,
+            &mint_authority_key,
+            Some(&freeze_authority_key),
+            2,
+        )
+        .unwrap(),
+        &[(mint_key, uninitialized_account(Mint::LEN)), rent_sysvar()],
+        &[Check::success()],
+    );
+
+    let mint = result.get_account(&mint_key).unwrap();
+    assert_eq!(
+        Mint::unpack(&mint.data).unwrap().freeze_authority,
+        Some(freeze_authority_key)
+    );
+}
+
+#[test]
+fn test_approve_and_revoke() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+
+    // It should approve the delegate.
+
+    let result = process_and_compare(
+        &approve(
+            &spl_token::id(),
+            &account_key,
+            &delegate_key,
+            &owner_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        &[
+            (account_key, token_account(&mint_key, &owner_key, 1_000)),
+            (delegate_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    let token_account = TokenAccount::unpack(&account.data).unwrap();
+    assert_eq!(token_account.delegate, Some(delegate_key));
+    assert_eq!(token_account.delegated_amount, 100);
+
+    // It should revoke the delegate.
+
+    let result = process_and_compare(
+        &revoke(&spl_token::id(), &account_key, &owner_key, &[]).unwrap(),
+        &[
+            (account_key, account.clone()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    let token_account = TokenAccount::unpack(&account.data).unwrap();
+    assert_eq!(token_account.delegate, None);
+    assert_eq!(token_account.delegated_amount, 0);
+
+    // It should fail to revoke without the owner.
+
+    process_and_compare(
+        &revoke(&spl_token::id(), &account_key, &delegate_key, &[]).unwrap(),
+        &[
+            (account_key, account.clone()),
+            (delegate_key, Account::default()),
+        ],
+        &[Check::err(TokenError::InvalidOwner.into())],
+    );
+}
+
+#[test]
+fn test_delegated_transfer_and_burn() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let delegate_key = Pubkey::new_unique();
+    let source_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let source = token_account_with(TokenAccount {
+        mint: mint_key,
+        owner: owner_key,
+        amount: 1_000,
+        delegate: Some(delegate_key),
+        state: AccountState::Initialized,
+        delegated_amount: 100,
+        close_authority: None,
+    });
+    let accounts = [
+        (source_key, source.clone()),
+        (destination_key, token_account(&mint_key, &owner_key, 0)),
+        (delegate_key, Account::default()),
+    ];
+
+    // It should transfer part of the delegated amount.
+
+    let result = process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &delegate_key,
+            &[],
+            60,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&source_key).unwrap();
+    let token_account = TokenAccount::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 940);
+    assert_eq!(token_account.delegated_amount, 40);
+
+    // It should fail to transfer more than the delegated amount.
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &delegate_key,
+            &[],
+            101,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::err(TokenError::InsufficientFunds.into())],
+    );
+
+    // It should clear the delegate once the delegated amount is burned.
+
+    let result = process_and_compare(
+        &burn(
+            &spl_token::id(),
+            &source_key,
+            &mint_key,
+            &delegate_key,
+            &[],
+            100,
+        )
+        .unwrap(),
+        &[
+            (source_key, source),
+            (
+                mint_key,
+                mint_account(&Pubkey::new_unique(), None, 1_000, 2),
+            ),
+            (delegate_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&source_key).unwrap();
+    let token_account = TokenAccount::unpack(&account.data).unwrap();
+    assert_eq!(token_account.amount, 900);
+    assert_eq!(token_account.delegate, None);
+    assert_eq!(token_account.delegated_amount, 0);
+}
+
+#[test]
+fn test_freeze_and_thaw_account() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let freeze_authority_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let mint = mint_account(&Pubkey::new_unique(), Some(&freeze_authority_key), 1_000, 2);
+
+    // It should freeze the account.
+
+    let result = process_and_compare(
+        &freeze_account(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &freeze_authority_key,
+            &[],
+        )
+        .unwrap(),
+        &[
+            (account_key, token_account(&mint_key, &owner_key, 1_000)),
+            (mint_key, mint.clone()),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let frozen = result.get_account(&account_key).unwrap().clone();
+    assert_eq!(
+        TokenAccount::unpack(&frozen.data).unwrap().state,
+        AccountState::Frozen
+    );
+
+    // It should fail to freeze the account twice.
+
+    process_and_compare(
+        &freeze_account(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &freeze_authority_key,
+            &[],
+        )
+        .unwrap(),
+        &[
+            (account_key, frozen.clone()),
+            (mint_key, mint.clone()),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[Check::err(TokenError::InvalidState.into())],
+    );
+
+    // It should fail to transfer from the frozen account.
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &account_key,
+            &destination_key,
+            &owner_key,
+            &[],
+            1,
+        )
+        .unwrap(),
+        &[
+            (account_key, frozen.clone()),
+            (destination_key, token_account(&mint_key, &owner_key, 0)),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(TokenError::AccountFrozen.into())],
+    );
+
+    // It should thaw the account.
+
+    let result = process_and_compare(
+        &thaw_account(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &freeze_authority_key,
+            &[],
+        )
+        .unwrap(),
+        &[
+            (account_key, frozen),
+            (mint_key, mint),
+            (freeze_authority_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    assert_eq!(
+        TokenAccount::unpack(&account.data).unwrap().state,
+        AccountState::Initialized
+    );
+}
+
+#[test]
+fn test_freeze_account_without_freeze_authority() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+
+    process_and_compare(
+        &freeze_account(&spl_token::id(), &account_key, &mint_key, &owner_key, &[]).unwrap(),
+        &[
+            (account_key, token_account(&mint_key, &owner_key, 1_000)),
+            (mint_key, mint_account(&owner_key, None, 1_000, 2)),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(TokenError::MintCannotFreeze.into())],
+    );
+}
+
+#[test]
+fn test_close_account() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let account = token_account(&mint_key, &owner_key, 0);
+    let lamports = account.lamports;
+
+    // It should close the account.
+
+    let result = process_and_compare(
+        &close_account(
+            &spl_token::id(),
+            &account_key,
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        &[
+            (account_key, account),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[
+            Check::success(),
+            Check::account(&account_key).lamports(0).build(),
+            Check::account(&destination_key).lamports(lamports).build(),
+        ],
+    );
+
+    let account = result.get_account(&account_key).unwrap();
+    assert_eq!(account.owner, solana_sdk_ids::system_program::id());
+
+    // It should fail to close an account with a balance.
+
+    process_and_compare(
+        &close_account(
+            &spl_token::id(),
+            &account_key,
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        &[
+            (account_key, token_account(&mint_key, &owner_key, 1)),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(TokenError::NonNativeHasBalance.into())],
+    );
+}
+
+#[test]
+fn test_close_account_with_close_authority() {
+    let mint_key = Pubkey::new_unique();
+    let owner_key = Pubkey::new_unique();
+    let close_authority_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+
+    let account = token_account_with(TokenAccount {
+        mint: mint_key,
+        owner: owner_key,
+        amount: 0,
+        delegate: None,
+        state: AccountState::Initialized,
+        delegated_amount: 0,
+        close_authority: Some(close_authority_key),
+    });
+
+    // It should fail to close the account with the owner.
+
+    process_and_compare(
+        &close_account(
+            &spl_token::id(),
+            &account_key,
+            &destination_key,
+            &owner_key,
+            &[],
+        )
+        .unwrap(),
+        &[
+            (account_key, account.clone()),
+            (destination_key, Account::default()),
+            (owner_key, Account::default()),
+        ],
+        &[Check::err(TokenError::InvalidOwner.into())],
+    );
+
+    // It should close the account with the close authority.
+
+    process_and_compare(
+        &close_account(
+            &spl_token::id(),
+            &account_key,
+            &destination_key,
+            &close_authority_key,
+            &[],
+        )
+        .unwrap(),
+        &[
+            (account_key, account),
+            (destination_key, Account::default()),
+            (close_authority_key, Account::default()),
+        ],
+        &[Check::success()],
+    );
+}
+
+#[test]
+fn test_initialize_multisig() {
+    let multisig_key = Pubkey::new_unique();
+    let signer_keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let instruction = initialize_multisig(
+        &spl_token::id(),
+        &multisig_key,
+        &[&signer_keys[0], &signer_keys[1]],
+        2,
+    )
+    .unwrap();
+    let accounts = [
+        (multisig_key, uninitialized_account(Multisig::LEN)),
+        rent_sysvar(),
+        (signer_keys[0], Account::default()),
+        (signer_keys[1], Account::default()),
+    ];
+
+    // It should initialize the multisig.
+
+    let result = process_and_compare(&instruction, &accounts, &[Check::success()]);
+
+    let multisig = result.get_account(&multisig_key).unwrap();
+    let state = Multisig::unpack(&multisig.data).unwrap();
+    assert_eq!((state.m, state.n), (2, 2));
+    assert_eq!(state.signers[..2], signer_keys);
+
+    // It should fail to initialize the multisig twice.
+
+    process_and_compare(
+        &instruction,
+        &[
+            (multisig_key, multisig.clone()),
+            rent_sysvar(),
+            (signer_keys[0], Account::default()),
+            (signer_keys[1], Account::default()),
+        ],
+        &[Check::err(TokenError::AlreadyInitialized.into())],
+    );
+
+    // It should fail to require no signers.
+
+    let mut no_required_signers = instruction.clone();
+    no_required_signers.data[1] = 0;
+    process_and_compare(
+        &no_required_signers,
+        &accounts,
+        &[Check::err(
+            TokenError::InvalidNumberOfRequiredSigners.into(),
+        )],
+    );
+
+    // It should fail to initialize without signers.
+
+    let mut no_signers = instruction;
+    no_signers.accounts.truncate(2);
+    process_and_compare(
+        &no_signers,
+        &accounts[..2],
+        &[Check::err(
+            TokenError::InvalidNumberOfProvidedSigners.into(),
+        )],
+    );
+}
+
+#[test]
+fn test_multisig_transfer() {
+    let mint_key = Pubkey::new_unique();
+    let multisig_key = Pubkey::new_unique();
+    let source_key = Pubkey::new_unique();
+    let destination_key = Pubkey::new_unique();
+    let signer_keys = [
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+    ];
+    let accounts = [
+        (source_key, token_account(&mint_key, &multisig_key, 1_000)),
+        (destination_key, token_account(&mint_key, &multisig_key, 0)),
+        (
+            multisig_key,
+            multisig_account(2, &[&signer_keys[0], &signer_keys[1], &signer_keys[2]]),
+        ),
+        (signer_keys[0], Account::default()),
+        (signer_keys[1], Account::default()),
+        (signer_keys[2], Account::default()),
+    ];
+
+    // It should transfer with M of N signers.
+
+    let result = process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &multisig_key,
+            &[&signer_keys[0], &signer_keys[2]],
+            400,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::success()],
+    );
+
+    let source = result.get_account(&source_key).unwrap();
+    assert_eq!(TokenAccount::unpack(&source.data).unwrap().amount, 600);
+    let destination = result.get_account(&destination_key).unwrap();
+    assert_eq!(TokenAccount::unpack(&destination.data).unwrap().amount, 400);
+
+    // It should fail to transfer with fewer than M signers.
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &multisig_key,
+            &[&signer_keys[0]],
+            400,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+
+    // It should fail to count the same signer twice.
+
+    process_and_compare(
+        &transfer(
+            &spl_token::id(),
+            &source_key,
+            &destination_key,
+            &multisig_key,
+            &[&signer_keys[1], &signer_keys[1]],
+            400,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+
+    // It should fail when a listed signer did not sign.
+
+    let mut instruction = transfer(
+        &spl_token::id(),
+        &source_key,
+        &destination_key,
+        &multisig_key,
+        &[&signer_keys[0], &signer_keys[1]],
+        400,
+    )
+    .unwrap();
+    instruction.accounts[4].is_signer = false;
+    process_and_compare(
+        &instruction,
+        &accounts,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+}
+
+#[test]
+fn test_multisig_mint_to_and_burn() {
+    let mint_key = Pubkey::new_unique();
+    let multisig_key = Pubkey::new_unique();
+    let account_key = Pubkey::new_unique();
+    let signer_keys = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let multisig = multisig_account(2, &[&signer_keys[0], &signer_keys[1]]);
+
+    // It should mint with every required signer.
+
+    let result = process_and_compare(
+        &mint_to(
+            &spl_token::id(),
+            &mint_key,
+            &account_key,
+            &multisig_key,
+            &[&signer_keys[0], &signer_keys[1]],
+            42,
+        )
+        .unwrap(),
+        &[
+            (mint_key, mint_account(&multisig_key, None, 0, 2)),
+            (account_key, token_account(&mint_key, &multisig_key, 0)),
+            (multisig_key, multisig.clone()),
+            (signer_keys[0], Account::default()),
+            (signer_keys[1], Account::default()),
+        ],
+        &[Check::success()],
+    );
+
+    let mint = result.get_account(&mint_key).unwrap().clone();
+    let account = result.get_account(&account_key).unwrap().clone();
+    assert_eq!(Mint::unpack(&mint.data).unwrap().supply, 42);
+
+    // It should fail to burn with fewer than M signers.
+
+    let accounts = [
+        (account_key, account),
+        (mint_key, mint),
+        (multisig_key, multisig),
+        (signer_keys[0], Account::default()),
+        (signer_keys[1], Account::default()),
+    ];
+    process_and_compare(
+        &burn(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &multisig_key,
+            &[&signer_keys[1]],
+            42,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::err(ProgramError::MissingRequiredSignature)],
+    );
+
+    // It should burn with every required signer.
+
+    let result = process_and_compare(
+        &burn(
+            &spl_token::id(),
+            &account_key,
+            &mint_key,
+            &multisig_key,
+            &[&signer_keys[1], &signer_keys[0]],
+            42,
+        )
+        .unwrap(),
+        &accounts,
+        &[Check::success()],
+    );
+
+    let mint = result.get_account(&mint_key).unwrap();
+    assert_eq!(Mint::unpack(&mint.data).unwrap().supply, 0);
+}
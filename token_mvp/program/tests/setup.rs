@@ -0,0 +1,167 @@
+use {
+    mollusk_svm::{
+        file::load_program_elf,
+        result::{Check, InstructionResult, ProgramResult},
+        Mollusk,
+    },
+    solana_account::Account,
+    solana_instruction::Instruction,
+    solana_program_error::ProgramError,
+    solana_program_pack::Pack,
+    solana_pubkey::Pubkey,
+    solana_rent::Rent,
+    solana_sdk_ids::bpf_loader_upgradeable,
+    spl_token::{
+        error::TokenError,
+        instruction::MAX_SIGNERS,
+        state::{Account as TokenAccount, AccountState, Mint, Multisig},
+    },
+};
+
+/// Name of the reference SPL Token program shared object.
+///
+/// The reference program is built from `token/program` with `cargo build-sbf`
+/// and its `spl_token.so` copied to `tests/fixtures/spl_token_reference.so`,
+/// since both programs produce a shared object with the same name. Running
+/// `pnpm mvp:build` from `token` builds both programs and copies the reference.
+const REFERENCE_PROGRAM_NAME: &str = "spl_token_reference";
+
+/// Creates a Mollusk instance running the MVP program.
+pub fn mvp() -> Mollusk {
+    Mollusk::new(&spl_token::id(), "spl_token")
+}
+
+/// Creates a Mollusk instance running the reference SPL Token program.
+pub fn reference() -> Mollusk {
+    let mut mollusk = Mollusk::default();
+    mollusk.add_program_with_loader_and_elf(
+        &spl_token::id(),
+        &bpf_loader_upgradeable::id(),
+        &load_program_elf(REFERENCE_PROGRAM_NAME),
+    );
+    mollusk
+}
+
+/// Maps an error returned by the reference program to the MVP error for the
+/// same failure.
+pub fn to_mvp_error(error: ProgramError) -> ProgramError {
+    match error {
+        ProgramError::Custom(code) => match code {
+            0 => TokenError::NotRentExempt.into(),
+            1 => TokenError::InsufficientFunds.into(),
+            2 => TokenError::InvalidMint.into(),
+            3 => TokenError::MintMismatch.into(),
+            4 => TokenError::InvalidOwner.into(),
+            5 => TokenError::FixedSupply.into(),
+            6 => TokenError::AlreadyInitialized.into(),
+            7 => TokenError::InvalidNumberOfProvidedSigners.into(),
+            8 => TokenError::InvalidNumberOfRequiredSigners.into(),
+            11 => TokenError::NonNativeHasBalance.into(),
+            13 => TokenError::InvalidState.into(),
+            14 => TokenError::Overflow.into(),
+            16 => TokenError::MintCannotFreeze.into(),
+            17 => TokenError::AccountFrozen.into(),
+            _ => panic!("reference error {code} has no MVP equivalent"),
+        },
+        ProgramError::UninitializedAccount => TokenError::NotInitialized.into(),
+        error => error,
+    }
+}
+
+/// Processes the instruction with both programs, validating the MVP result
+/// against `checks`.
+///
+/// The reference program must fail with the mapped MVP error or, when both
+/// succeed, leave every account byte-identical to the MVP.
+pub fn process_and_compare(
+    instruction: &Instruction,
+    accounts: &[(Pubkey, Account)],
+    checks: &[Check],
+) -> InstructionResult {
+    let result = mvp().process_and_validate_instruction(instruction, accounts, checks);
+    let expected = reference().process_instruction(instruction, accounts);
+
+    match (&result.program_result, expected.program_result) {
+        (ProgramResult::Success, ProgramResult::Success) => {
+            assert_eq!(result.resulting_accounts, expected.resulting_accounts);
+        }
+        (ProgramResult::Failure(error), ProgramResult::Failure(expected)) => {
+            assert_eq!(*error, to_mvp_error(expected));
+        }
+        (result, expected) => {
+            panic!("MVP returned {result:?} but the reference returned {expected:?}")
+        }
+    }
+
+    result
+}
+
+/// Returns the keyed Rent sysvar account.
+pub fn rent_sysvar() -> (Pubkey, Account) {
+    Mollusk::default().sysvars.keyed_account_for_rent_sysvar()
+}
+
+/// Creates a rent-exempt, zero-initialized account owned by the program.
+pub fn uninitialized_account(space: usize) -> Account {
+    Account {
+        lamports: Rent::default().minimum_balance(space),
+        data: vec![0; space],
+        owner: spl_token::id(),
+        ..Default::default()
+    }
+}
+
+/// Creates an initialized mint account.
+pub fn mint_account(
+    mint_authority: &Pubkey,
+    freeze_authority: Option<&Pubkey>,
+    supply: u64,
+    decimals: u8,
+) -> Account {
+    let mut account = uninitialized_account(Mint::LEN);
+    Mint {
+        mint_authority: Some(*mint_authority),
+        supply,
+        decimals,
+        is_initialized: true,
+        freeze_authority: freeze_authority.cloned(),
+    }
+    .pack_into_slice(&mut account.data);
+    account
+}
+
+/// Creates an initialized token account.
+pub fn token_account(mint: &Pubkey, owner: &Pubkey, amount: u64) -> Account {
+    token_account_with(TokenAccount {
+        mint: *mint,
+        owner: *owner,
+        amount,
+        delegate: None,
+        state: AccountState::Initialized,
+        delegated_amount: 0,
+        close_authority: None,
+    })
+}
+
+/// Creates a token account with the given state.
+pub fn token_account_with(state: TokenAccount) -> Account {
+    let mut account = uninitialized_account(TokenAccount::LEN);
+    state.pack_into_slice(&mut account.data);
+    account
+}
+
+/// Creates an initialized multisignature account requiring `m` of `signers`.
+pub fn multisig_account(m: u8, signers: &[&Pubkey]) -> Account {
+    let mut account = uninitialized_account(Multisig::LEN);
+    let mut multisig = Multisig {
+        m,
+        n: signers.len() as u8,
+        is_initialized: true,
+        signers: [Pubkey::default(); MAX_SIGNERS],
+    };
+    for (key, signer) in multisig.signers.iter_mut().zip(signers) {
+        *key = **signer;
+    }
+    multisig.pack_into_slice(&mut account.data);
+    account
+}